@@ -1,24 +1,42 @@
 use crate::ocpp_csms_server::api_server::Api;
 use crate::ocpp_csms_server::ocpp_client::OcppClient;
+use crate::ocpp_csms_server::upload_firmware_image_request;
 use crate::ocpp_csms_server::{
-    CancelOutletReservationRequest, CancelOutletReservationResponse,
+    CancelOutletReservationRequest, CancelOutletReservationResponse, CertificateSignedRequest, CertificateSignedResponse, InstallCertificateRequest, InstallCertificateResponse, GetInstalledCertificateIdsRequest, GetInstalledCertificateIdsResponse, DeleteCertificateRequest, DeleteCertificateResponse, ExtendedTriggerMessageRequest, ExtendedTriggerMessageResponse, GetLogRequest, GetLogResponse,
     ChangeChargerAvailabilityRequest, ChangeChargerAvailabilityResponse,
     ChangeConnectorAvailabilityRequest, ChangeConnectorAvailabilityResponse,
     ChangeEvseAvailabilityRequest, ChangeEvseAvailabilityResponse,
     ChangeOcpp16configurationValueRequest, ChangeOcpp16configurationValueResponse, Charger,
-    ChargerSummary, ClearChargerCacheRequest, ClearChargerCacheResponse, CreateChargerRequest,
-    CreateChargerResponse, CreateRfidScanSessionRequest, CreateRfidScanSessionResponse, Evse,
-    GetChargerRequest, GetChargerResponse, GetChargersRequest, GetChargersResponse,
-    GetOngoingTransactionRequest, GetOngoingTransactionResponse, GetRfidScanSessionRequest,
-    GetRfidScanSessionResponse, Ocpp16configuration, RebootChargerRequest, RebootChargerResponse,
-    RfidScanSessionStatus, StartTransactionRequest, StartTransactionResponse,
-    StopTransactionRequest, StopTransactionResponse,
+    ChargerSummary, ClearChargerCacheRequest, ClearChargerCacheResponse,
+    ClearChargingProfileRequest, ClearChargingProfileResponse, CreateChargerRequest,
+    CreateChargerResponse, CreateRfidScanSessionRequest, CreateRfidScanSessionResponse,
+    CreateSiteRequest, CreateSiteResponse, DataTransferRequest, DataTransferResponse,
+    DeleteSiteRequest, DeleteSiteResponse, Evse, FirmwareImageMetadata, GetChargerRequest,
+    GetChargerResponse, GetChargersRequest, GetChargersResponse, GetCompositeScheduleRequest,
+    GetCompositeScheduleResponse, GetConfigurationDriftRequest, GetConfigurationDriftResponse,
+    GetFirmwareDownloadsRequest, GetFirmwareDownloadsResponse, GetFirmwareImagesRequest,
+    GetFirmwareImagesResponse, GetFirmwareUpdatesRequest, GetFirmwareUpdatesResponse,
+    GetLocalListVersionRequest, GetLocalListVersionResponse, GetOngoingTransactionRequest,
+    GetOngoingTransactionResponse, GetRfidScanSessionRequest, GetRfidScanSessionResponse,
+    GetSiteRequest, GetSiteResponse, GetSitesRequest, GetSitesResponse,
+    GetTransactionMeterValuesRequest, GetTransactionMeterValuesResponse, Ocpp16configuration,
+    RebootChargerRequest, RebootChargerResponse, RfidScanSessionStatus, SendLocalListRequest,
+    SendLocalListResponse, SetChargingProfileRequest, SetChargingProfileResponse,
+    SetEvseLimitRequest, SetEvseLimitResponse, StartTransactionRequest, StartTransactionResponse,
+    StopTransactionRequest, StopTransactionResponse, TriggerMessageRequest, TriggerMessageResponse,
+    UnlockConnectorRequest, UnlockConnectorResponse, UpdateChargerSettingsRequest,
+    UpdateChargerSettingsResponse, UpdateFirmwareRequest, UpdateFirmwareResponse,
+    UpdateSiteRequest, UpdateSiteResponse, UploadFirmwareImageRequest, UploadFirmwareImageResponse,
 };
+use md5::Md5;
+use sha2::{Digest, Sha256};
+use shared::data::downsample_meter_values;
 use shared::{ChargerConnectionInfo, DataStore};
 use std::str::FromStr;
+use std::time::Duration;
 use tokio::try_join;
 use tonic::transport::Channel;
-use tonic::{Request, Response, Status};
+use tonic::{Code, Request, Response, Status, Streaming};
 use tracing::{error, instrument, warn};
 use uuid::Uuid;
 
@@ -61,6 +79,175 @@ impl ApiService {
             })?;
         Ok(grpc_client)
     }
+
+    async fn find_site(&self, site_id: Uuid) -> Result<Option<shared::data::Site>, Status> {
+        self.data_store.get_site(site_id).await.map_err(|error| {
+            error!(error_message = error.to_string(), "could not get site");
+            Status::internal("Could not get site")
+        })
+    }
+
+    /// Writes the chunks of an upload to the data store as they arrive, returning the size and
+    /// the hex encoded SHA-256 and MD5 checksums of the image
+    async fn save_firmware_image_data(
+        &self,
+        image_id: Uuid,
+        stream: &mut Streaming<UploadFirmwareImageRequest>,
+    ) -> Result<(u64, String, String), Status> {
+        let mut size = 0;
+        let mut sha256 = Sha256::new();
+        let mut md5 = Md5::new();
+        while let Some(message) = stream.message().await? {
+            match message.payload {
+                Some(upload_firmware_image_request::Payload::Chunk(chunk)) => {
+                    if chunk.is_empty() {
+                        continue;
+                    }
+                    sha256.update(&chunk);
+                    md5.update(&chunk);
+                    self.data_store
+                        .save_firmware_image_data(image_id, size, &chunk)
+                        .await
+                        .map_err(|error| {
+                            error!(
+                                error_message = error.to_string(),
+                                "could not save firmware image data"
+                            );
+                            Status::internal("Could not save firmware image")
+                        })?;
+                    size += chunk.len() as u64;
+                }
+                Some(upload_firmware_image_request::Payload::Metadata(_)) => {
+                    return Err(Status::invalid_argument(
+                        "The metadata can only be sent once",
+                    ));
+                }
+                None => {}
+            }
+        }
+        if size == 0 {
+            return Err(Status::invalid_argument("The firmware image is empty"));
+        }
+
+        Ok((
+            size,
+            hex::encode(sha256.finalize()),
+            hex::encode(md5.finalize()),
+        ))
+    }
+
+    async fn delete_firmware_image_data(&self, image_id: Uuid) {
+        if let Err(error) = self.data_store.delete_firmware_image_data(image_id).await {
+            error!(
+                error_message = error.to_string(),
+                "could not delete data of aborted firmware image upload"
+            );
+        }
+    }
+
+    async fn save_site(&self, site: &shared::data::Site) -> Result<(), Status> {
+        self.data_store.save_site(site).await.map_err(|error| {
+            error!(error_message = error.to_string(), "could not save site");
+            Status::internal("Could not save site")
+        })
+    }
+
+    /// Removes the load balancing limits from a charger that is no longer part of a site. Chargers
+    /// that can't be reached keep their limits, which can still be cleared with SetEvseLimit.
+    async fn clear_evse_limits(&self, charger_id: &str) {
+        let charger = match self.data_store.get_charger_data_by_id(charger_id).await {
+            Ok(Some(charger)) => charger,
+            Ok(None) => return,
+            Err(error) => {
+                error!(
+                    error_message = error.to_string(),
+                    charger_id = charger_id,
+                    "could not get charger to clear its evse limits"
+                );
+                return;
+            }
+        };
+        let Ok(mut client) = self.get_client(charger_id).await else {
+            warn!(
+                charger_id = charger_id,
+                "could not reach charger to clear its evse limits"
+            );
+            return;
+        };
+
+        for evse in charger.evses {
+            if let Err(status) = client
+                .set_evse_limit(SetEvseLimitRequest {
+                    charger_id: charger_id.to_string(),
+                    evse_id: evse.id.to_string(),
+                    limit: None,
+                })
+                .await
+                && status.code() != Code::NotFound
+            {
+                warn!(
+                    charger_id = charger_id,
+                    evse_id = evse.id.to_string(),
+                    error_message = status.message(),
+                    "could not clear evse limit"
+                );
+            }
+        }
+    }
+
+    /// A charger can only be part of a single site, otherwise its current would be allocated twice
+    async fn validate_site(&self, site: &shared::data::Site) -> Result<(), Status> {
+        if !site.max_current_per_phase.is_finite()
+            || !site.min_current_per_evse.is_finite()
+            || site
+                .max_phase_imbalance
+                .is_some_and(|max_phase_imbalance| !max_phase_imbalance.is_finite())
+        {
+            return Err(Status::invalid_argument("Currents must be finite numbers"));
+        }
+        if site.max_current_per_phase <= 0.0 {
+            return Err(Status::invalid_argument(
+                "Max current per phase must be positive",
+            ));
+        }
+        if site.min_current_per_evse < 0.0 {
+            return Err(Status::invalid_argument(
+                "Min current per evse can't be negative",
+            ));
+        }
+        // Otherwise a single phase car could never be given its minimum
+        if site
+            .max_phase_imbalance
+            .is_some_and(|max_phase_imbalance| max_phase_imbalance < site.min_current_per_evse)
+        {
+            return Err(Status::invalid_argument(
+                "Max phase imbalance can't be lower than the min current per evse",
+            ));
+        }
+
+        for charger_id in &site.charger_ids {
+            let other_site = self
+                .data_store
+                .get_site_by_charger_id(charger_id)
+                .await
+                .map_err(|error| {
+                    error!(
+                        error_message = error.to_string(),
+                        charger_id = charger_id,
+                        "could not get site of charger"
+                    );
+                    Status::internal("Could not get site of charger")
+                })?;
+            if other_site.is_some_and(|other_site| other_site.id != site.id) {
+                return Err(Status::failed_precondition(format!(
+                    "Charger {} is already part of another site",
+                    charger_id
+                )));
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[tonic::async_trait]
@@ -298,6 +485,36 @@ impl Api for ApiService {
         client.create_rfid_scan_session(payload).await
     }
 
+    #[instrument]
+    async fn set_charging_profile(
+        &self,
+        request: Request<SetChargingProfileRequest>,
+    ) -> Result<Response<SetChargingProfileResponse>, Status> {
+        let payload = request.into_inner();
+        let mut client = self.get_client(&payload.charger_id).await?;
+        client.set_charging_profile(payload).await
+    }
+
+    #[instrument]
+    async fn clear_charging_profile(
+        &self,
+        request: Request<ClearChargingProfileRequest>,
+    ) -> Result<Response<ClearChargingProfileResponse>, Status> {
+        let payload = request.into_inner();
+        let mut client = self.get_client(&payload.charger_id).await?;
+        client.clear_charging_profile(payload).await
+    }
+
+    #[instrument]
+    async fn get_composite_schedule(
+        &self,
+        request: Request<GetCompositeScheduleRequest>,
+    ) -> Result<Response<GetCompositeScheduleResponse>, Status> {
+        let payload = request.into_inner();
+        let mut client = self.get_client(&payload.charger_id).await?;
+        client.get_composite_schedule(payload).await
+    }
+
     async fn get_rfid_scan_session(
         &self,
         request: Request<GetRfidScanSessionRequest>,
@@ -325,6 +542,481 @@ impl Api for ApiService {
             None => Err(Status::not_found("RFID scan session not found")),
         }
     }
+
+    #[instrument]
+    async fn set_evse_limit(
+        &self,
+        request: Request<SetEvseLimitRequest>,
+    ) -> Result<Response<SetEvseLimitResponse>, Status> {
+        let payload = request.into_inner();
+        let mut client = self.get_client(&payload.charger_id).await?;
+        client.set_evse_limit(payload).await
+    }
+
+    #[instrument]
+    async fn create_site(
+        &self,
+        request: Request<CreateSiteRequest>,
+    ) -> Result<Response<CreateSiteResponse>, Status> {
+        let payload = request.into_inner();
+
+        let mut site = shared::data::Site::new(&payload.name, payload.max_current_per_phase);
+        if let Some(min_current_per_evse) = payload.min_current_per_evse {
+            site.min_current_per_evse = min_current_per_evse;
+        }
+        site.charger_ids = payload.charger_ids;
+        site.max_phase_imbalance = payload.max_phase_imbalance;
+
+        self.validate_site(&site).await?;
+        self.save_site(&site).await?;
+
+        Ok(Response::new(CreateSiteResponse {
+            site: Some(site.into()),
+        }))
+    }
+
+    #[instrument]
+    async fn get_site(
+        &self,
+        request: Request<GetSiteRequest>,
+    ) -> Result<Response<GetSiteResponse>, Status> {
+        let payload = request.into_inner();
+        let site_id = Uuid::from_str(&payload.site_id)
+            .map_err(|_| Status::invalid_argument("Invalid site id"))?;
+
+        match self.find_site(site_id).await? {
+            Some(site) => Ok(Response::new(GetSiteResponse {
+                site: Some(site.into()),
+            })),
+            None => Err(Status::not_found("Site not found")),
+        }
+    }
+
+    #[instrument]
+    async fn get_sites(
+        &self,
+        _request: Request<GetSitesRequest>,
+    ) -> Result<Response<GetSitesResponse>, Status> {
+        let sites = self.data_store.get_sites().await.map_err(|error| {
+            error!(error_message = error.to_string(), "could not get sites");
+            Status::internal("Could not get sites")
+        })?;
+
+        Ok(Response::new(GetSitesResponse {
+            sites: sites.into_iter().map(|site| site.into()).collect(),
+        }))
+    }
+
+    #[instrument]
+    async fn update_site(
+        &self,
+        request: Request<UpdateSiteRequest>,
+    ) -> Result<Response<UpdateSiteResponse>, Status> {
+        let payload = request.into_inner();
+        let site: shared::data::Site = payload
+            .site
+            .ok_or_else(|| Status::invalid_argument("Site is required"))?
+            .try_into()?;
+
+        let Some(previous_site) = self.find_site(site.id).await? else {
+            return Err(Status::not_found("Site not found"));
+        };
+
+        self.validate_site(&site).await?;
+        self.save_site(&site).await?;
+
+        for charger_id in previous_site
+            .charger_ids
+            .iter()
+            .filter(|charger_id| !site.charger_ids.contains(charger_id))
+        {
+            self.clear_evse_limits(charger_id).await;
+        }
+
+        Ok(Response::new(UpdateSiteResponse {
+            site: Some(site.into()),
+        }))
+    }
+
+    #[instrument]
+    async fn delete_site(
+        &self,
+        request: Request<DeleteSiteRequest>,
+    ) -> Result<Response<DeleteSiteResponse>, Status> {
+        let payload = request.into_inner();
+        let site_id = Uuid::from_str(&payload.site_id)
+            .map_err(|_| Status::invalid_argument("Invalid site id"))?;
+
+        let Some(site) = self.find_site(site_id).await? else {
+            return Err(Status::not_found("Site not found"));
+        };
+
+        self.data_store
+            .delete_site(site_id)
+            .await
+            .map_err(|error| {
+                error!(error_message = error.to_string(), "could not delete site");
+                Status::internal("Could not delete site")
+            })?;
+
+        for charger_id in &site.charger_ids {
+            self.clear_evse_limits(charger_id).await;
+        }
+
+        Ok(Response::new(DeleteSiteResponse {}))
+    }
+
+    #[instrument]
+    async fn send_local_list(
+        &self,
+        request: Request<SendLocalListRequest>,
+    ) -> Result<Response<SendLocalListResponse>, Status> {
+        let payload = request.into_inner();
+        let mut client = self.get_client(&payload.charger_id).await?;
+        client.send_local_list(payload).await
+    }
+
+    #[instrument]
+    async fn get_local_list_version(
+        &self,
+        request: Request<GetLocalListVersionRequest>,
+    ) -> Result<Response<GetLocalListVersionResponse>, Status> {
+        let payload = request.into_inner();
+        let mut client = self.get_client(&payload.charger_id).await?;
+        client.get_local_list_version(payload).await
+    }
+
+    #[instrument]
+    async fn update_firmware(
+        &self,
+        request: Request<UpdateFirmwareRequest>,
+    ) -> Result<Response<UpdateFirmwareResponse>, Status> {
+        let payload = request.into_inner();
+        let mut client = self.get_client(&payload.charger_id).await?;
+        client.update_firmware(payload).await
+    }
+
+    #[instrument]
+    async fn get_firmware_updates(
+        &self,
+        request: Request<GetFirmwareUpdatesRequest>,
+    ) -> Result<Response<GetFirmwareUpdatesResponse>, Status> {
+        let payload = request.into_inner();
+
+        let firmware_updates = self
+            .data_store
+            .get_firmware_updates(&payload.charger_id)
+            .await
+            .map_err(|error| {
+                error!(
+                    error_message = error.to_string(),
+                    "could not get firmware updates"
+                );
+                Status::internal("Could not get firmware updates")
+            })?;
+
+        Ok(Response::new(GetFirmwareUpdatesResponse {
+            firmware_updates: firmware_updates
+                .into_iter()
+                .map(|firmware_update| firmware_update.into())
+                .collect(),
+        }))
+    }
+
+    #[instrument(skip_all)]
+    async fn upload_firmware_image(
+        &self,
+        request: Request<Streaming<UploadFirmwareImageRequest>>,
+    ) -> Result<Response<UploadFirmwareImageResponse>, Status> {
+        let mut stream = request.into_inner();
+
+        let metadata = match stream.message().await?.and_then(|message| message.payload) {
+            Some(upload_firmware_image_request::Payload::Metadata(metadata)) => metadata,
+            _ => {
+                return Err(Status::invalid_argument(
+                    "The first message must contain the metadata",
+                ));
+            }
+        };
+        validate_firmware_image_metadata(&metadata)?;
+
+        let image_id = Uuid::new_v4();
+        let result = self.save_firmware_image_data(image_id, &mut stream).await;
+        let (size, sha256, md5) = match result {
+            Ok(saved) => saved,
+            Err(status) => {
+                self.delete_firmware_image_data(image_id).await;
+                return Err(status);
+            }
+        };
+
+        if metadata
+            .sha256
+            .as_ref()
+            .is_some_and(|expected| !expected.eq_ignore_ascii_case(&sha256))
+        {
+            self.delete_firmware_image_data(image_id).await;
+            return Err(Status::data_loss(
+                "The SHA-256 checksum does not match the uploaded image",
+            ));
+        }
+        if metadata
+            .md5
+            .as_ref()
+            .is_some_and(|expected| !expected.eq_ignore_ascii_case(&md5))
+        {
+            self.delete_firmware_image_data(image_id).await;
+            return Err(Status::data_loss(
+                "The MD5 checksum does not match the uploaded image",
+            ));
+        }
+
+        let image = shared::data::FirmwareImage {
+            id: image_id,
+            vendor: metadata.vendor,
+            model: metadata.model,
+            version: metadata.version,
+            file_name: metadata.file_name,
+            size,
+            sha256,
+            md5,
+            created_at: chrono::Utc::now(),
+        };
+        self.data_store
+            .save_firmware_image(&image)
+            .await
+            .map_err(|error| {
+                error!(
+                    error_message = error.to_string(),
+                    "could not save firmware image"
+                );
+                Status::internal("Could not save firmware image")
+            })?;
+
+        Ok(Response::new(UploadFirmwareImageResponse {
+            firmware_image: Some(image.into()),
+        }))
+    }
+
+    async fn get_firmware_images(
+        &self,
+        request: Request<GetFirmwareImagesRequest>,
+    ) -> Result<Response<GetFirmwareImagesResponse>, Status> {
+        let payload = request.into_inner();
+
+        let firmware_images = self
+            .data_store
+            .get_firmware_images()
+            .await
+            .map_err(|error| {
+                error!(
+                    error_message = error.to_string(),
+                    "could not get firmware images"
+                );
+                Status::internal("Could not get firmware images")
+            })?;
+
+        Ok(Response::new(GetFirmwareImagesResponse {
+            firmware_images: firmware_images
+                .into_iter()
+                .filter(|image| image.supports(payload.vendor.as_deref(), payload.model.as_deref()))
+                .map(|image| image.into())
+                .collect(),
+        }))
+    }
+
+    async fn get_firmware_downloads(
+        &self,
+        request: Request<GetFirmwareDownloadsRequest>,
+    ) -> Result<Response<GetFirmwareDownloadsResponse>, Status> {
+        let payload = request.into_inner();
+        let image_id = Uuid::from_str(&payload.image_id)
+            .map_err(|_| Status::invalid_argument("Invalid image id"))?;
+
+        let firmware_downloads = self
+            .data_store
+            .get_firmware_downloads(image_id)
+            .await
+            .map_err(|error| {
+                error!(
+                    error_message = error.to_string(),
+                    "could not get firmware downloads"
+                );
+                Status::internal("Could not get firmware downloads")
+            })?;
+
+        Ok(Response::new(GetFirmwareDownloadsResponse {
+            firmware_downloads: firmware_downloads
+                .into_iter()
+                .map(|download| download.into())
+                .collect(),
+        }))
+    }
+
+    #[instrument]
+    async fn unlock_connector(
+        &self,
+        request: Request<UnlockConnectorRequest>,
+    ) -> Result<Response<UnlockConnectorResponse>, Status> {
+        let payload = request.into_inner();
+        let mut client = self.get_client(&payload.charger_id).await?;
+        client.unlock_connector(payload).await
+    }
+
+    #[instrument]
+    async fn trigger_message(
+        &self,
+        request: Request<TriggerMessageRequest>,
+    ) -> Result<Response<TriggerMessageResponse>, Status> {
+        let payload = request.into_inner();
+        let mut client = self.get_client(&payload.charger_id).await?;
+        client.trigger_message(payload).await
+    }
+
+    #[instrument]
+    async fn data_transfer(
+        &self,
+        request: Request<DataTransferRequest>,
+    ) -> Result<Response<DataTransferResponse>, Status> {
+        let payload = request.into_inner();
+        let mut client = self.get_client(&payload.charger_id).await?;
+        client.data_transfer(payload).await
+    }
+
+    #[instrument]
+    async fn certificate_signed(
+        &self,
+        request: Request<CertificateSignedRequest>,
+    ) -> Result<Response<CertificateSignedResponse>, Status> {
+        let payload = request.into_inner();
+        let mut client = self.get_client(&payload.charger_id).await?;
+        client.certificate_signed(payload).await
+    }
+
+    #[instrument]
+    async fn install_certificate(
+        &self,
+        request: Request<InstallCertificateRequest>,
+    ) -> Result<Response<InstallCertificateResponse>, Status> {
+        let payload = request.into_inner();
+        let mut client = self.get_client(&payload.charger_id).await?;
+        client.install_certificate(payload).await
+    }
+
+    #[instrument]
+    async fn get_installed_certificate_ids(
+        &self,
+        request: Request<GetInstalledCertificateIdsRequest>,
+    ) -> Result<Response<GetInstalledCertificateIdsResponse>, Status> {
+        let payload = request.into_inner();
+        let mut client = self.get_client(&payload.charger_id).await?;
+        client.get_installed_certificate_ids(payload).await
+    }
+
+    #[instrument]
+    async fn delete_certificate(
+        &self,
+        request: Request<DeleteCertificateRequest>,
+    ) -> Result<Response<DeleteCertificateResponse>, Status> {
+        let payload = request.into_inner();
+        let mut client = self.get_client(&payload.charger_id).await?;
+        client.delete_certificate(payload).await
+    }
+
+    #[instrument]
+    async fn extended_trigger_message(
+        &self,
+        request: Request<ExtendedTriggerMessageRequest>,
+    ) -> Result<Response<ExtendedTriggerMessageResponse>, Status> {
+        let payload = request.into_inner();
+        let mut client = self.get_client(&payload.charger_id).await?;
+        client.extended_trigger_message(payload).await
+    }
+
+    #[instrument]
+    async fn get_log(
+        &self,
+        request: Request<GetLogRequest>,
+    ) -> Result<Response<GetLogResponse>, Status> {
+        let payload = request.into_inner();
+        let mut client = self.get_client(&payload.charger_id).await?;
+        client.get_log(payload).await
+    }
+
+    async fn get_transaction_meter_values(
+        &self,
+        request: Request<GetTransactionMeterValuesRequest>,
+    ) -> Result<Response<GetTransactionMeterValuesResponse>, Status> {
+        let payload = request.into_inner();
+        let transaction_id = Uuid::from_str(&payload.transaction_id)
+            .map_err(|_| Status::invalid_argument("Invalid transaction id"))?;
+        let interval = payload
+            .interval_seconds
+            .map(|interval_seconds| match interval_seconds {
+                0 => Err(Status::invalid_argument("Invalid interval")),
+                interval_seconds => Ok(Duration::from_secs(interval_seconds)),
+            })
+            .transpose()?;
+
+        let meter_values = self
+            .data_store
+            .get_transaction_meter_values(transaction_id)
+            .await
+            .map_err(|error| {
+                error!(
+                    error_message = error.to_string(),
+                    "could not get transaction meter values"
+                );
+                Status::internal("Could not get transaction meter values")
+            })?;
+        let meter_values = match interval {
+            Some(interval) => downsample_meter_values(meter_values, interval),
+            None => meter_values,
+        };
+
+        Ok(Response::new(GetTransactionMeterValuesResponse {
+            meter_values: meter_values
+                .into_iter()
+                .map(|meter_value| meter_value.into())
+                .collect(),
+        }))
+    }
+
+    #[instrument]
+    async fn update_charger_settings(
+        &self,
+        request: Request<UpdateChargerSettingsRequest>,
+    ) -> Result<Response<UpdateChargerSettingsResponse>, Status> {
+        let payload = request.into_inner();
+        let mut client = self.get_client(&payload.charger_id).await?;
+        client.update_charger_settings(payload).await
+    }
+
+    #[instrument]
+    async fn get_configuration_drift(
+        &self,
+        request: Request<GetConfigurationDriftRequest>,
+    ) -> Result<Response<GetConfigurationDriftResponse>, Status> {
+        let payload = request.into_inner();
+        let snapshots = self
+            .data_store
+            .get_configuration_drift(payload.charger_id)
+            .await
+            .map_err(|error| {
+                error!(
+                    error_message = error.to_string(),
+                    "could not get configuration drift"
+                );
+                Status::internal("Could not get configuration drift")
+            })?;
+
+        Ok(Response::new(GetConfigurationDriftResponse {
+            chargers: snapshots
+                .into_iter()
+                .map(|snapshot| snapshot.into())
+                .collect(),
+        }))
+    }
 }
 
 impl From<shared::RfidScanSession> for crate::ocpp_csms_server::RfidScanSession {
@@ -357,6 +1049,15 @@ impl From<(shared::ChargerData, ChargerConnectionInfo)> for Charger {
             firmware_version: charger.firmware_version,
             iccid: charger.iccid,
             imsi: charger.imsi,
+            local_auth_list_version: charger.local_auth_list_version,
+            client_certificate_serial_number: charger
+                .client_certificate
+                .as_ref()
+                .map(|certificate| certificate.serial_number.clone()),
+            client_certificate_expires_at: charger
+                .client_certificate
+                .as_ref()
+                .map(|certificate| certificate.expires_at.timestamp_millis()),
             ocpp1_6_configuration_values: charger
                 .ocpp1_6configuration
                 .map(|values| {
@@ -460,3 +1161,182 @@ impl From<shared::Transaction> for crate::ocpp_csms_server::Transaction {
         }
     }
 }
+
+impl From<shared::data::Site> for crate::ocpp_csms_server::Site {
+    fn from(site: shared::data::Site) -> Self {
+        Self {
+            id: site.id.to_string(),
+            name: site.name,
+            max_current_per_phase: site.max_current_per_phase,
+            min_current_per_evse: site.min_current_per_evse,
+            max_phase_imbalance: site.max_phase_imbalance,
+            charger_ids: site.charger_ids,
+        }
+    }
+}
+
+impl TryFrom<crate::ocpp_csms_server::Site> for shared::data::Site {
+    type Error = Status;
+
+    fn try_from(site: crate::ocpp_csms_server::Site) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: Uuid::from_str(&site.id)
+                .map_err(|_| Status::invalid_argument("Invalid site id"))?,
+            name: site.name,
+            max_current_per_phase: site.max_current_per_phase,
+            min_current_per_evse: site.min_current_per_evse,
+            max_phase_imbalance: site.max_phase_imbalance,
+            charger_ids: site.charger_ids,
+        })
+    }
+}
+
+impl From<shared::data::FirmwareUpdateStatus> for crate::ocpp_csms_server::FirmwareUpdateStatus {
+    fn from(value: shared::data::FirmwareUpdateStatus) -> Self {
+        match value {
+            shared::data::FirmwareUpdateStatus::Requested => Self::Requested,
+            shared::data::FirmwareUpdateStatus::DownloadScheduled => Self::DownloadScheduled,
+            shared::data::FirmwareUpdateStatus::Downloading => Self::Downloading,
+            shared::data::FirmwareUpdateStatus::DownloadPaused => Self::DownloadPaused,
+            shared::data::FirmwareUpdateStatus::Downloaded => Self::Downloaded,
+            shared::data::FirmwareUpdateStatus::DownloadFailed => Self::DownloadFailed,
+            shared::data::FirmwareUpdateStatus::SignatureVerified => Self::SignatureVerified,
+            shared::data::FirmwareUpdateStatus::InvalidSignature => Self::InvalidSignature,
+            shared::data::FirmwareUpdateStatus::InstallScheduled => Self::InstallScheduled,
+            shared::data::FirmwareUpdateStatus::Installing => Self::Installing,
+            shared::data::FirmwareUpdateStatus::InstallRebooting => Self::InstallRebooting,
+            shared::data::FirmwareUpdateStatus::Installed => Self::Installed,
+            shared::data::FirmwareUpdateStatus::InstallationFailed => Self::InstallationFailed,
+            shared::data::FirmwareUpdateStatus::InstallVerificationFailed => {
+                Self::InstallVerificationFailed
+            }
+            shared::data::FirmwareUpdateStatus::Confirmed => Self::Confirmed,
+            shared::data::FirmwareUpdateStatus::Failed => Self::Failed,
+        }
+    }
+}
+
+impl From<shared::data::FirmwareUpdate> for crate::ocpp_csms_server::FirmwareUpdate {
+    fn from(value: shared::data::FirmwareUpdate) -> Self {
+        Self {
+            id: value.id.to_string(),
+            charger_id: value.charger_id,
+            location: value.location,
+            target_version: value.target_version,
+            previous_version: value.previous_version,
+            status: crate::ocpp_csms_server::FirmwareUpdateStatus::from(value.status).into(),
+            status_history: value
+                .status_history
+                .into_iter()
+                .map(
+                    |change| crate::ocpp_csms_server::FirmwareUpdateStatusChange {
+                        status: crate::ocpp_csms_server::FirmwareUpdateStatus::from(change.status)
+                            .into(),
+                        timestamp: change.timestamp.timestamp_millis(),
+                    },
+                )
+                .collect(),
+            created_at: value.created_at.timestamp_millis(),
+        }
+    }
+}
+
+impl From<shared::data::FirmwareImage> for crate::ocpp_csms_server::FirmwareImage {
+    fn from(value: shared::data::FirmwareImage) -> Self {
+        Self {
+            id: value.id.to_string(),
+            vendor: value.vendor,
+            model: value.model,
+            version: value.version,
+            file_name: value.file_name,
+            size: value.size,
+            sha256: value.sha256,
+            md5: value.md5,
+            created_at: value.created_at.timestamp_millis(),
+        }
+    }
+}
+
+impl From<shared::data::FirmwareDownload> for crate::ocpp_csms_server::FirmwareDownload {
+    fn from(value: shared::data::FirmwareDownload) -> Self {
+        Self {
+            id: value.id.to_string(),
+            image_id: value.image_id.to_string(),
+            charger_id: value.charger_id,
+            range_start: value.range_start,
+            range_end: value.range_end,
+            remote_address: value.remote_address,
+            downloaded_at: value.downloaded_at.timestamp_millis(),
+        }
+    }
+}
+
+impl From<shared::data::TransactionMeterValue> for crate::ocpp_csms_server::TransactionMeterValue {
+    fn from(value: shared::data::TransactionMeterValue) -> Self {
+        Self {
+            timestamp: value.timestamp.timestamp_millis(),
+            measurand: value.measurand,
+            phase: value.phase,
+            unit: value.unit,
+            context: value.context,
+            location: value.location,
+            value: value.value,
+        }
+    }
+}
+
+impl From<shared::data::ConfigurationDriftReason>
+    for crate::ocpp_csms_server::ConfigurationDriftReason
+{
+    fn from(value: shared::data::ConfigurationDriftReason) -> Self {
+        match value {
+            shared::data::ConfigurationDriftReason::Differs => Self::Differs,
+            shared::data::ConfigurationDriftReason::ChangedOnCharger => Self::ChangedOnCharger,
+            shared::data::ConfigurationDriftReason::Rejected => Self::Rejected,
+            shared::data::ConfigurationDriftReason::RebootRequired => Self::RebootRequired,
+            shared::data::ConfigurationDriftReason::NotSupported => Self::NotSupported,
+        }
+    }
+}
+
+impl From<shared::data::ConfigurationSnapshot>
+    for crate::ocpp_csms_server::ChargerConfigurationDrift
+{
+    fn from(value: shared::data::ConfigurationSnapshot) -> Self {
+        Self {
+            charger_id: value.charger_id,
+            version: value.version,
+            taken_at: value.taken_at.timestamp_millis(),
+            drift: value
+                .drift
+                .into_iter()
+                .map(|drift| crate::ocpp_csms_server::ConfigurationDrift {
+                    key: drift.key,
+                    desired_value: drift.desired_value,
+                    actual_value: drift.actual_value,
+                    reason: crate::ocpp_csms_server::ConfigurationDriftReason::from(drift.reason)
+                        .into(),
+                })
+                .collect(),
+        }
+    }
+}
+
+fn validate_firmware_image_metadata(metadata: &FirmwareImageMetadata) -> Result<(), Status> {
+    if metadata.vendor.is_empty() || metadata.model.is_empty() || metadata.version.is_empty() {
+        return Err(Status::invalid_argument(
+            "Vendor, model and version are required",
+        ));
+    }
+    // The file name is part of the download url, and some chargers check its extension
+    if metadata.file_name.is_empty()
+        || metadata.file_name.starts_with('.')
+        || !metadata
+            .file_name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_'))
+    {
+        return Err(Status::invalid_argument("Invalid file name"));
+    }
+    Ok(())
+}
@@ -1,27 +1,80 @@
-use crate::charger::charger_model::ChargerModel;
 use crate::charger::charger_ocpp1_6_request_receiver::CENTRAL_TAG;
-use crate::event::EventManager;
+use crate::charger::firmware::{
+    FirmwareUpdateOptions, check_update_firmware_status, confirm_firmware_update,
+};
+use crate::charger::ocpp1_6::{
+    charging_profile_to_ocpp_1_6, configuration_after_push, detect_configuration_drift,
+    find_missing_charging_profiles, invalid_configuration_key, resolve_configuration_drift,
+    select_charging_rate_unit, validate_configuration_overrides,
+};
+use crate::charger::ocpp2_0_1::create_external_constraints_profile;
+use crate::charger::smart_charging::{
+    EvseLimit, create_limit_charging_profile, evse_phase_count, evse_voltage,
+};
+use crate::charger::triggerable_message::TriggerableMessage;
+use crate::charger::vendor_profile::{VendorProfile, select_vendor_profile};
+use crate::data_transfer::{DataTransferRegistry, DataTransferReply};
+use crate::event::{ChargingProfileDriftCorrectedEvent, EventManager, EventPayload};
 use crate::network_interface::ProtocolHandle;
 use crate::ocpp_csms_server_client::csms_server_client_client::CsmsServerClientClient;
-use crate::server::map_ocpp1_6_error_to_status;
-use chrono::{DateTime, Utc};
+use crate::ocpp1_6_security::messages::certificate_signed::CertificateSignedRequest;
+use crate::ocpp1_6_security::messages::delete_certificate::DeleteCertificateRequest;
+use crate::ocpp1_6_security::messages::extended_trigger_message::ExtendedTriggerMessageRequest;
+use crate::ocpp1_6_security::messages::get_installed_certificate_ids::{
+    GetInstalledCertificateIdsRequest, GetInstalledCertificateIdsResponse,
+};
+use crate::ocpp1_6_security::messages::get_log::{GetLogRequest, GetLogResponse};
+use crate::ocpp1_6_security::messages::install_certificate::InstallCertificateRequest;
+use crate::ocpp1_6_security::messages::signed_update_firmware::SignedUpdateFirmwareRequest;
+use crate::ocpp1_6_security::types::{
+    CertificateHashData, CertificateSignedStatus, CertificateStatus, CertificateUse,
+    DeleteCertificateStatus, ExtendedMessageTrigger, SignedFirmware,
+};
+use crate::server::{map_ocpp1_6_error_to_status, map_ocpp2_0_1_error_to_status};
+use chrono::{DateTime, Duration, Utc};
 use rand::Rng;
+use rand::distr::Alphanumeric;
 use rust_ocpp::v1_6::messages::cancel_reservation::CancelReservationRequest;
 use rust_ocpp::v1_6::messages::change_availability::ChangeAvailabilityRequest;
 use rust_ocpp::v1_6::messages::change_configuration::ChangeConfigurationRequest;
+use rust_ocpp::v1_6::messages::clear_cache::ClearCacheRequest;
+use rust_ocpp::v1_6::messages::clear_charging_profile::ClearChargingProfileRequest;
+use rust_ocpp::v1_6::messages::data_transfer::DataTransferRequest;
+use rust_ocpp::v1_6::messages::get_composite_schedule::GetCompositeScheduleRequest;
+use rust_ocpp::v1_6::messages::get_local_list_version::GetLocalListVersionRequest;
 use rust_ocpp::v1_6::messages::remote_start_transaction::RemoteStartTransactionRequest;
 use rust_ocpp::v1_6::messages::remote_stop_transaction::RemoteStopTransactionRequest;
 use rust_ocpp::v1_6::messages::reset::ResetRequest;
+use rust_ocpp::v1_6::messages::send_local_list::SendLocalListRequest;
+use rust_ocpp::v1_6::messages::set_charging_profile::SetChargingProfileRequest;
+use rust_ocpp::v1_6::messages::trigger_message::TriggerMessageRequest;
+use rust_ocpp::v1_6::messages::unlock_connector::UnlockConnectorRequest;
+use rust_ocpp::v1_6::messages::update_firmware::UpdateFirmwareRequest;
 use rust_ocpp::v1_6::types::{
-    AvailabilityStatus, AvailabilityType, CancelReservationStatus, ConfigurationStatus,
-    RemoteStartStopStatus, ResetRequestStatus, ResetResponseStatus,
+    AvailabilityStatus, AvailabilityType, CancelReservationStatus, ChargingProfileStatus,
+    ClearCacheStatus, ClearChargingProfileStatus, ConfigurationStatus, GetCompositeScheduleStatus,
+    MessageTrigger, RemoteStartStopStatus, ResetRequestStatus, ResetResponseStatus,
+    TriggerMessageStatus, UnlockStatus, UpdateStatus,
 };
+use rust_ocpp::v2_0_1::datatypes::evse_type::EVSEType;
+use rust_ocpp::v2_0_1::datatypes::firmware_type::FirmwareType;
+use rust_ocpp::v2_0_1::enumerations::charging_profile_status_enum_type::ChargingProfileStatusEnumType;
+use rust_ocpp::v2_0_1::enumerations::clear_charging_profile_status_enum_type::ClearChargingProfileStatusEnumType;
+use rust_ocpp::v2_0_1::enumerations::message_trigger_enum_type::MessageTriggerEnumType;
+use rust_ocpp::v2_0_1::enumerations::trigger_message_status_enum_type::TriggerMessageStatusEnumType;
+use rust_ocpp::v2_0_1::enumerations::unlock_status_enum_type::UnlockStatusEnumType;
 use shared::Config;
-use shared::data::{ChargerData, Transaction};
+use shared::data::{
+    ChargerData, ChargerSettings, ChargingProfile, ChargingProfilePurpose, ChargingRateUnit,
+    ChargingSchedule, ConfigurationSnapshot, DesiredChargingProfile, FirmwareDownloadToken,
+    FirmwareUpdate, FirmwareUpdateStatus, LocalAuthListEntry, LocalListUpdateType,
+    Ocpp1_6Configuration, Transaction,
+};
 use shared::data_store::DataStore;
+use std::collections::BTreeMap;
 use std::sync::Arc;
-use tonic::Status;
 use tonic::transport::Channel;
+use tonic::{Code, Status};
 use tracing::{error, info, warn};
 use uuid::Uuid;
 
@@ -46,6 +99,10 @@ pub struct Charger {
 
     pub event_manager: EventManager,
     pub csms_server_client: Option<CsmsServerClientClient<Channel>>,
+    pub data_transfer_registry: DataTransferRegistry,
+
+    /// Selected from the vendor and model of the charger, see `select_vendor_profile`
+    pub vendor_profile: Box<dyn VendorProfile>,
 }
 
 impl Charger {
@@ -59,8 +116,16 @@ impl Charger {
         easee_master_password: Option<String>,
         event_manager: EventManager,
         csms_server_client: Option<CsmsServerClientClient<Channel>>,
+        data_transfer_registry: DataTransferRegistry,
     ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
-        let data = data_store.get_charger_data_by_id(id).await?;
+        let data = data_store
+            .get_charger_data_by_id(id)
+            .await?
+            .unwrap_or_else(|| ChargerData::new(id, &config));
+        let vendor_profile = select_vendor_profile(
+            data.vendor.as_deref().unwrap_or_default(),
+            data.model.as_deref().unwrap_or_default(),
+        );
 
         let authenticated = false;
 
@@ -70,12 +135,14 @@ impl Charger {
             handle,
             authenticated,
             config: config.clone(),
-            data: data.unwrap_or_else(|| ChargerData::new(id, &config)),
+            data,
             password: None,
             node_address: node_address.to_string(),
             easee_master_password,
             event_manager,
             csms_server_client,
+            data_transfer_registry,
+            vendor_profile,
         })
     }
 
@@ -99,21 +166,43 @@ impl Charger {
         Ok(())
     }
 
-    pub async fn start_transaction(&mut self, evse_id: Uuid) -> Result<Transaction, Status> {
+    pub async fn start_transaction(
+        &mut self,
+        evse_id: Uuid,
+        charging_profile: Option<ChargingProfile>,
+    ) -> Result<Transaction, Status> {
         let evse = self
             .data
             .evse(evse_id)
             .ok_or_else(|| Status::not_found("Evse not found"))?;
         let evse_id = evse.id;
 
+        if let Some(charging_profile) = &charging_profile {
+            if charging_profile.purpose != ChargingProfilePurpose::TxProfile {
+                return Err(Status::invalid_argument(
+                    "Only a TxProfile can be used when starting a transaction",
+                ));
+            }
+            if charging_profile.transaction_id.is_some() {
+                return Err(Status::invalid_argument(
+                    "The charging profile can not reference a transaction when starting a transaction",
+                ));
+            }
+        }
+
         match &self.handle {
             ProtocolHandle::Ocpp1_6(handle) => {
                 let ocpp_evse_id = evse.ocpp_evse_id;
+                let charging_profile = charging_profile
+                    .as_ref()
+                    .map(charging_profile_to_ocpp_1_6)
+                    .transpose()
+                    .map_err(|error| Status::invalid_argument(error.to_string()))?;
                 let response = handle
                     .send_remote_start_transaction(RemoteStartTransactionRequest {
                         connector_id: Some(ocpp_evse_id),
                         id_tag: "central".to_string(),
-                        charging_profile: None,
+                        charging_profile,
                     })
                     .await
                     .map_err(|error| {
@@ -143,15 +232,24 @@ impl Charger {
 
                         match transaction {
                             None => {
-                                let transaction_id: i32 = {
-                                    let mut rng = rand::rng();
-                                    rng.random::<i32>()
-                                };
+                                let transaction_id = self
+                                    .data_store
+                                    .next_ocpp_transaction_id(&self.id)
+                                    .await
+                                    .map_err(|error| {
+                                        error!(
+                                            error_message = error.to_string(),
+                                            "Failed to allocate transaction id, due to internal error"
+                                        );
+                                        Status::internal(
+                                            "Failed to allocate transaction id, due to internal error",
+                                        )
+                                    })?;
                                 let transaction = self
                                     .data_store
                                     .create_transaction(
                                         &self.id,
-                                        evse_id,
+                                        Some(evse_id),
                                         &transaction_id.to_string(),
                                         Utc::now(),
                                         true,
@@ -307,6 +405,166 @@ impl Charger {
         }
     }
 
+    /// Changes a configuration value of an ocpp 1.6 charger, returning the status reported by the
+    /// charger. Accepted values are stored so they are visible without reading the whole
+    /// configuration again
+    pub async fn change_ocpp_1_6_configuration_value(
+        &mut self,
+        key: &str,
+        value: &str,
+    ) -> Result<ConfigurationStatus, Status> {
+        match &self.handle {
+            ProtocolHandle::Ocpp1_6(handle) => {
+                if self
+                    .data
+                    .ocpp1_6configuration
+                    .as_ref()
+                    .and_then(|configuration| configuration.get_configuration(key))
+                    .is_some_and(|configuration_value| configuration_value.read_only)
+                {
+                    return Err(Status::failed_precondition(
+                        "The configuration key is read only",
+                    ));
+                }
+
+                let response = handle
+                    .send_change_configuration(ChangeConfigurationRequest {
+                        key: key.to_string(),
+                        value: value.to_string(),
+                    })
+                    .await
+                    .map_err(|error| {
+                        error!(
+                            error_message = error.to_string(),
+                            "Failed to change configuration due to internal error"
+                        );
+                        Status::internal("Failed to change configuration, due to internal error")
+                    })?
+                    .map_err(map_ocpp1_6_error_to_status)?;
+
+                if response.status == ConfigurationStatus::Accepted
+                    || response.status == ConfigurationStatus::RebootRequired
+                {
+                    info!(key = key, value = value, "configuration changed");
+                    self.data
+                        .ocpp1_6configuration
+                        .get_or_insert_with(Ocpp1_6Configuration::new)
+                        .set_value(key, value);
+                    self.sync_data().await.map_err(|error| {
+                        error!(
+                            error_message = error.to_string(),
+                            "Failed to save configuration due to internal error"
+                        );
+                        Status::internal("Failed to save configuration, due to internal error")
+                    })?;
+                }
+
+                Ok(response.status)
+            }
+            ProtocolHandle::Ocpp2_0_1(_handle) => {
+                Err(Status::internal("We can't handle ocpp 2.0.1 yet"))
+            }
+        }
+    }
+
+    /// Changes the settings of the charger, and pushes the resulting OCPP 1.6 configuration to
+    /// the charger right away. Overrides are only accepted for keys the charger reported as
+    /// writable and its vendor supports.
+    pub async fn update_settings(
+        &mut self,
+        authorize_transactions: Option<bool>,
+        permanently_lock_cable_to_charger: Option<bool>,
+        require_client_certificate: Option<bool>,
+        set_ocpp1_6_configuration_overrides: BTreeMap<String, String>,
+        remove_ocpp1_6_configuration_overrides: Vec<String>,
+    ) -> Result<ChargerSettings, Status> {
+        let changes_configuration = !set_ocpp1_6_configuration_overrides.is_empty()
+            || !remove_ocpp1_6_configuration_overrides.is_empty();
+        if changes_configuration && let ProtocolHandle::Ocpp2_0_1(_handle) = &self.handle {
+            return Err(Status::failed_precondition(
+                "OCPP 1.6 configuration overrides can't be set for an OCPP 2.0.1 charger",
+            ));
+        }
+        if !set_ocpp1_6_configuration_overrides.is_empty() {
+            let configuration = self.data.ocpp1_6configuration.as_ref().ok_or_else(|| {
+                Status::failed_precondition("The charger has not reported its configuration yet")
+            })?;
+            validate_configuration_overrides(
+                &set_ocpp1_6_configuration_overrides,
+                configuration,
+                self.vendor_profile().unsupported_configuration_keys(),
+            )
+            .map_err(Status::invalid_argument)?;
+        }
+
+        let settings = &mut self.data.settings;
+        if let Some(authorize_transactions) = authorize_transactions {
+            settings.authorize_transactions = authorize_transactions;
+        }
+        if let Some(permanently_lock_cable_to_charger) = permanently_lock_cable_to_charger {
+            settings.permanently_lock_cable_to_charger = permanently_lock_cable_to_charger;
+        }
+        if let Some(require_client_certificate) = require_client_certificate {
+            settings.require_client_certificate = require_client_certificate;
+        }
+        for key in &remove_ocpp1_6_configuration_overrides {
+            settings.ocpp1_6_configuration_overrides.remove(key);
+        }
+        settings
+            .ocpp1_6_configuration_overrides
+            .extend(set_ocpp1_6_configuration_overrides);
+
+        self.sync_data().await.map_err(|error| {
+            error!(
+                error_message = error.to_string(),
+                "Failed to save settings due to internal error"
+            );
+            Status::internal("Failed to save settings, due to internal error")
+        })?;
+
+        if let ProtocolHandle::Ocpp1_6(_handle) = &self.handle {
+            self.update_ocpp_1_6_charger_configuration()
+                .await
+                .map_err(|error| {
+                    error!(
+                        error_message = error.to_string(),
+                        "Failed to push configuration due to internal error"
+                    );
+                    Status::internal("Failed to push configuration, due to internal error")
+                })?;
+        }
+
+        Ok(self.data.settings.clone())
+    }
+
+    pub async fn clear_cache(&mut self) -> Result<(), Status> {
+        match &self.handle {
+            ProtocolHandle::Ocpp1_6(handle) => {
+                let response = handle
+                    .send_clear_cache(ClearCacheRequest {})
+                    .await
+                    .map_err(|error| {
+                        error!(
+                            error_message = error.to_string(),
+                            "Failed to clear cache due to internal error"
+                        );
+                        Status::internal("Failed to clear cache, due to internal error")
+                    })?
+                    .map_err(map_ocpp1_6_error_to_status)?;
+
+                match response.status {
+                    ClearCacheStatus::Accepted => Ok(()),
+                    ClearCacheStatus::Rejected => {
+                        Err(Status::cancelled("Charger could not clear its cache"))
+                    }
+                }
+            }
+            ProtocolHandle::Ocpp2_0_1(_handle) => {
+                Err(Status::internal("We can't handle ocpp 2.0.1 yet"))
+            }
+        }
+    }
+
     pub async fn cancel_outlet_reservation(&mut self, _outlet_id: &str) -> Result<(), Status> {
         match &self.handle {
             ProtocolHandle::Ocpp1_6(handle) => {
@@ -475,132 +733,1493 @@ impl Charger {
         }
     }
 
-    pub async fn reserve_charger(&mut self, expiry_date: DateTime<Utc>) -> Result<(), Status> {
+    pub async fn unlock_connector(
+        &mut self,
+        evse_id: Uuid,
+        connector_id: Uuid,
+        override_permanent_lock: bool,
+    ) -> Result<UnlockStatus, Status> {
+        if self.data.settings.permanently_lock_cable_to_charger && !override_permanent_lock {
+            return Err(Status::failed_precondition(
+                "The cable is permanently locked to this charger, set override_permanent_lock to unlock it anyway",
+            ));
+        }
+
+        let evse = self
+            .data
+            .evse(evse_id)
+            .ok_or_else(|| Status::not_found("Evse not found"))?;
+        let connector = evse
+            .connectors
+            .iter()
+            .find(|connector| connector.id == connector_id)
+            .ok_or_else(|| Status::not_found("Connector not found"))?;
+
         match &self.handle {
             ProtocolHandle::Ocpp1_6(handle) => {
                 let response = handle
-                    .send_reserve_now(rust_ocpp::v1_6::messages::reserve_now::ReserveNowRequest {
-                        connector_id: 0,
-                        expiry_date,
-                        id_tag: CENTRAL_TAG.to_string(),
-                        parent_id_tag: None,
-                        reservation_id: 0,
+                    .send_unlock_connector(UnlockConnectorRequest {
+                        connector_id: evse.ocpp_evse_id,
                     })
                     .await
                     .map_err(|error| {
                         error!(
                             error_message = error.to_string(),
-                            "Failed to reserve the charger due to internal error"
+                            "Failed to unlock connector due to internal error"
                         );
-                        Status::internal("Failed to reserve the charger, due to internal error")
+                        Status::internal("Failed to unlock connector, due to internal error")
                     })?
                     .map_err(map_ocpp1_6_error_to_status)?;
 
-                if response.status == rust_ocpp::v1_6::types::ReservationStatus::Accepted {
-                    Ok(())
-                } else {
-                    Err(Status::cancelled("Charger could not be reserved"))
+                Ok(response.status)
+            }
+            ProtocolHandle::Ocpp2_0_1(handle) => {
+                let response = handle
+                    .send_unlock_connector(
+                        rust_ocpp::v2_0_1::messages::unlock_connector::UnlockConnectorRequest {
+                            evse_id: evse.ocpp_evse_id as i32,
+                            connector_id: connector.ocpp_id as i32,
+                        },
+                    )
+                    .await
+                    .map_err(|error| {
+                        error!(
+                            error_message = error.to_string(),
+                            "Failed to unlock connector due to internal error"
+                        );
+                        Status::internal("Failed to unlock connector, due to internal error")
+                    })?
+                    .map_err(map_ocpp2_0_1_error_to_status)?;
+
+                match response.status {
+                    UnlockStatusEnumType::Unlocked => Ok(UnlockStatus::Unlocked),
+                    UnlockStatusEnumType::UnlockFailed => Ok(UnlockStatus::UnlockFailed),
+                    UnlockStatusEnumType::OngoingAuthorizedTransaction => {
+                        Err(Status::failed_precondition(
+                            "The connector has an ongoing authorized transaction",
+                        ))
+                    }
+                    UnlockStatusEnumType::UnknownConnector => Err(Status::not_found(
+                        "The charger does not know this connector",
+                    )),
                 }
             }
-            ProtocolHandle::Ocpp2_0_1(_) => Err(Status::internal("We can't handle ocpp 2.0.1 yet")),
         }
     }
 
-    pub fn model(&self) -> Option<ChargerModel> {
-        if let Some(vendor) = &self.data.vendor {
-            if let Some(model) = &self.data.model {
-                return Some(ChargerModel::from_vendor_and_model(vendor, model));
-            };
-        };
-        None
+    /// Asks the charger to send a message, for example to refresh a connector status that looks
+    /// stale. Without an evse the charger sends it for all of its evses, if applicable
+    pub async fn trigger_message(
+        &mut self,
+        message: TriggerableMessage,
+        evse_id: Option<Uuid>,
+    ) -> Result<TriggerMessageStatus, Status> {
+        let evse = evse_id
+            .map(|evse_id| {
+                self.data
+                    .evse(evse_id)
+                    .ok_or_else(|| Status::not_found("Evse not found"))
+            })
+            .transpose()?;
+
+        match &self.handle {
+            ProtocolHandle::Ocpp1_6(handle) => {
+                let response = handle
+                    .send_trigger_message(TriggerMessageRequest {
+                        requested_message: match message {
+                            TriggerableMessage::BootNotification => {
+                                MessageTrigger::BootNotification
+                            }
+                            TriggerableMessage::DiagnosticsStatusNotification => {
+                                MessageTrigger::DiagnosticsStatusNotification
+                            }
+                            TriggerableMessage::FirmwareStatusNotification => {
+                                MessageTrigger::FirmwareStatusNotification
+                            }
+                            TriggerableMessage::Heartbeat => MessageTrigger::Heartbeat,
+                            TriggerableMessage::MeterValues => MessageTrigger::MeterValues,
+                            TriggerableMessage::StatusNotification => {
+                                MessageTrigger::StatusNotification
+                            }
+                        },
+                        connector_id: evse.map(|evse| evse.ocpp_evse_id),
+                    })
+                    .await
+                    .map_err(|error| {
+                        error!(
+                            error_message = error.to_string(),
+                            "Failed to trigger message due to internal error"
+                        );
+                        Status::internal("Failed to trigger message, due to internal error")
+                    })?
+                    .map_err(map_ocpp1_6_error_to_status)?;
+
+                Ok(response.status)
+            }
+            ProtocolHandle::Ocpp2_0_1(handle) => {
+                let response = handle
+                    .send_trigger_message(
+                        rust_ocpp::v2_0_1::messages::trigger_message::TriggerMessageRequest {
+                            requested_message: match message {
+                                TriggerableMessage::BootNotification => {
+                                    MessageTriggerEnumType::BootNotification
+                                }
+                                TriggerableMessage::DiagnosticsStatusNotification => {
+                                    MessageTriggerEnumType::LogStatusNotification
+                                }
+                                TriggerableMessage::FirmwareStatusNotification => {
+                                    MessageTriggerEnumType::FirmwareStatusNotification
+                                }
+                                TriggerableMessage::Heartbeat => MessageTriggerEnumType::Heartbeat,
+                                TriggerableMessage::MeterValues => {
+                                    MessageTriggerEnumType::MeterValues
+                                }
+                                TriggerableMessage::StatusNotification => {
+                                    MessageTriggerEnumType::StatusNotification
+                                }
+                            },
+                            evse: evse.map(|evse| EVSEType {
+                                id: evse.ocpp_evse_id as i32,
+                                connector_id: None,
+                            }),
+                        },
+                    )
+                    .await
+                    .map_err(|error| {
+                        error!(
+                            error_message = error.to_string(),
+                            "Failed to trigger message due to internal error"
+                        );
+                        Status::internal("Failed to trigger message, due to internal error")
+                    })?
+                    .map_err(map_ocpp2_0_1_error_to_status)?;
+
+                Ok(match response.status {
+                    TriggerMessageStatusEnumType::Accepted => TriggerMessageStatus::Accepted,
+                    TriggerMessageStatusEnumType::Rejected => TriggerMessageStatus::Rejected,
+                    TriggerMessageStatusEnumType::NotImplemented => {
+                        TriggerMessageStatus::NotImplemented
+                    }
+                })
+            }
+        }
     }
 
-    pub(crate) async fn get_ongoing_transaction(
+    pub async fn data_transfer(
         &mut self,
-        evse_ocpp_id: u32,
-    ) -> Result<Option<Transaction>, Box<dyn std::error::Error + Send + Sync>> {
-        let evse = self.data.evse_by_ocpp_id_or_create(evse_ocpp_id);
-        self.data_store
-            .get_ongoing_transaction(&self.id, evse.id)
-            .await
+        vendor_id: &str,
+        message_id: Option<String>,
+        data: Option<String>,
+    ) -> Result<DataTransferReply, Status> {
+        match &self.handle {
+            ProtocolHandle::Ocpp1_6(handle) => {
+                let response = handle
+                    .send_data_transfer(DataTransferRequest {
+                        vendor_string: vendor_id.to_string(),
+                        message_id,
+                        data,
+                    })
+                    .await
+                    .map_err(|error| {
+                        error!(
+                            error_message = error.to_string(),
+                            "Failed to transfer data due to internal error"
+                        );
+                        Status::internal("Failed to transfer data, due to internal error")
+                    })?
+                    .map_err(map_ocpp1_6_error_to_status)?;
+
+                Ok(DataTransferReply {
+                    status: response.status.into(),
+                    data: response.data,
+                })
+            }
+            ProtocolHandle::Ocpp2_0_1(handle) => {
+                let response = handle
+                    .send_data_transfer(
+                        rust_ocpp::v2_0_1::messages::datatransfer::DataTransferRequest {
+                            vendor_id: vendor_id.to_string(),
+                            message_id,
+                            data,
+                        },
+                    )
+                    .await
+                    .map_err(|error| {
+                        error!(
+                            error_message = error.to_string(),
+                            "Failed to transfer data due to internal error"
+                        );
+                        Status::internal("Failed to transfer data, due to internal error")
+                    })?
+                    .map_err(map_ocpp2_0_1_error_to_status)?;
+
+                Ok(DataTransferReply {
+                    status: response.status.into(),
+                    data: response.data,
+                })
+            }
+        }
     }
 
-    pub(crate) async fn transaction_by_ocpp_id(
+    /// Sends the charger the certificate the backend signed for its SignCertificate request
+    pub async fn certificate_signed(
         &mut self,
-        transaction_ocpp_id: i32,
-    ) -> Result<Option<Transaction>, Box<dyn std::error::Error + Send + Sync>> {
-        self.data_store
-            .get_transaction_by_ocpp_id(&transaction_ocpp_id.to_string())
-            .await
+        certificate_chain: String,
+    ) -> Result<CertificateSignedStatus, Status> {
+        match &self.handle {
+            ProtocolHandle::Ocpp1_6(handle) => {
+                let response = handle
+                    .send_certificate_signed(CertificateSignedRequest { certificate_chain })
+                    .await
+                    .map_err(|error| {
+                        error!(
+                            error_message = error.to_string(),
+                            "Failed to send signed certificate due to internal error"
+                        );
+                        Status::internal("Failed to send signed certificate, due to internal error")
+                    })?
+                    .map_err(map_ocpp1_6_error_to_status)?;
+
+                Ok(response.status)
+            }
+            ProtocolHandle::Ocpp2_0_1(_) => Err(Status::internal("We can't handle ocpp 2.0.1 yet")),
+        }
     }
 
-    pub(crate) async fn end_ongoing_transaction(
+    pub async fn install_certificate(
         &mut self,
-        connector_id: u32,
-        end_time: &Option<chrono::DateTime<Utc>>,
-    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        if let Some(transaction) = self.get_ongoing_transaction(connector_id).await? {
-            info!(
-                "Ending transaction {} for connector {}",
-                transaction.ocpp_transaction_id, connector_id
-            );
-            self.data_store
-                .end_transaction(
-                    &self.id,
-                    &transaction.ocpp_transaction_id,
-                    end_time.unwrap_or_else(Utc::now),
-                )
-                .await?;
+        certificate_type: CertificateUse,
+        certificate: String,
+    ) -> Result<CertificateStatus, Status> {
+        match &self.handle {
+            ProtocolHandle::Ocpp1_6(handle) => {
+                let response = handle
+                    .send_install_certificate(InstallCertificateRequest {
+                        certificate_type,
+                        certificate,
+                    })
+                    .await
+                    .map_err(|error| {
+                        error!(
+                            error_message = error.to_string(),
+                            "Failed to install certificate due to internal error"
+                        );
+                        Status::internal("Failed to install certificate, due to internal error")
+                    })?
+                    .map_err(map_ocpp1_6_error_to_status)?;
+
+                Ok(response.status)
+            }
+            ProtocolHandle::Ocpp2_0_1(_) => Err(Status::internal("We can't handle ocpp 2.0.1 yet")),
         }
+    }
 
-        Ok(())
+    pub async fn get_installed_certificate_ids(
+        &mut self,
+        certificate_type: CertificateUse,
+    ) -> Result<GetInstalledCertificateIdsResponse, Status> {
+        match &self.handle {
+            ProtocolHandle::Ocpp1_6(handle) => handle
+                .send_get_installed_certificate_ids(GetInstalledCertificateIdsRequest {
+                    certificate_type,
+                })
+                .await
+                .map_err(|error| {
+                    error!(
+                        error_message = error.to_string(),
+                        "Failed to get installed certificates due to internal error"
+                    );
+                    Status::internal("Failed to get installed certificates, due to internal error")
+                })?
+                .map_err(map_ocpp1_6_error_to_status),
+            ProtocolHandle::Ocpp2_0_1(_) => Err(Status::internal("We can't handle ocpp 2.0.1 yet")),
+        }
     }
 
-    pub(crate) async fn update_ocpp_1_6_charger_configuration(
+    pub async fn delete_certificate(
+        &mut self,
+        certificate_hash_data: CertificateHashData,
+    ) -> Result<DeleteCertificateStatus, Status> {
+        match &self.handle {
+            ProtocolHandle::Ocpp1_6(handle) => {
+                let response = handle
+                    .send_delete_certificate(DeleteCertificateRequest {
+                        certificate_hash_data,
+                    })
+                    .await
+                    .map_err(|error| {
+                        error!(
+                            error_message = error.to_string(),
+                            "Failed to delete certificate due to internal error"
+                        );
+                        Status::internal("Failed to delete certificate, due to internal error")
+                    })?
+                    .map_err(map_ocpp1_6_error_to_status)?;
+
+                Ok(response.status)
+            }
+            ProtocolHandle::Ocpp2_0_1(_) => Err(Status::internal("We can't handle ocpp 2.0.1 yet")),
+        }
+    }
+
+    /// Like trigger_message, but can also ask for the messages of the security extension
+    pub async fn extended_trigger_message(
+        &mut self,
+        message: ExtendedMessageTrigger,
+        evse_id: Option<Uuid>,
+    ) -> Result<TriggerMessageStatus, Status> {
+        let evse = evse_id
+            .map(|evse_id| {
+                self.data
+                    .evse(evse_id)
+                    .ok_or_else(|| Status::not_found("Evse not found"))
+            })
+            .transpose()?;
+
+        match &self.handle {
+            ProtocolHandle::Ocpp1_6(handle) => {
+                let response = handle
+                    .send_extended_trigger_message(ExtendedTriggerMessageRequest {
+                        requested_message: message,
+                        connector_id: evse.map(|evse| evse.ocpp_evse_id),
+                    })
+                    .await
+                    .map_err(|error| {
+                        error!(
+                            error_message = error.to_string(),
+                            "Failed to trigger message due to internal error"
+                        );
+                        Status::internal("Failed to trigger message, due to internal error")
+                    })?
+                    .map_err(map_ocpp1_6_error_to_status)?;
+
+                Ok(response.status)
+            }
+            ProtocolHandle::Ocpp2_0_1(_) => Err(Status::internal("We can't handle ocpp 2.0.1 yet")),
+        }
+    }
+
+    /// Asks the charger to upload a log, its progress is reported with LogStatusNotifications
+    pub async fn get_log(&mut self, request: GetLogRequest) -> Result<GetLogResponse, Status> {
+        match &self.handle {
+            ProtocolHandle::Ocpp1_6(handle) => handle
+                .send_get_log(request)
+                .await
+                .map_err(|error| {
+                    error!(
+                        error_message = error.to_string(),
+                        "Failed to get log due to internal error"
+                    );
+                    Status::internal("Failed to get log, due to internal error")
+                })?
+                .map_err(map_ocpp1_6_error_to_status),
+            ProtocolHandle::Ocpp2_0_1(_) => Err(Status::internal("We can't handle ocpp 2.0.1 yet")),
+        }
+    }
+
+    pub async fn reserve_charger(&mut self, expiry_date: DateTime<Utc>) -> Result<(), Status> {
+        match &self.handle {
+            ProtocolHandle::Ocpp1_6(handle) => {
+                let response = handle
+                    .send_reserve_now(rust_ocpp::v1_6::messages::reserve_now::ReserveNowRequest {
+                        connector_id: 0,
+                        expiry_date,
+                        id_tag: CENTRAL_TAG.to_string(),
+                        parent_id_tag: None,
+                        reservation_id: 0,
+                    })
+                    .await
+                    .map_err(|error| {
+                        error!(
+                            error_message = error.to_string(),
+                            "Failed to reserve the charger due to internal error"
+                        );
+                        Status::internal("Failed to reserve the charger, due to internal error")
+                    })?
+                    .map_err(map_ocpp1_6_error_to_status)?;
+
+                if response.status == rust_ocpp::v1_6::types::ReservationStatus::Accepted {
+                    Ok(())
+                } else {
+                    Err(Status::cancelled("Charger could not be reserved"))
+                }
+            }
+            ProtocolHandle::Ocpp2_0_1(_) => Err(Status::internal("We can't handle ocpp 2.0.1 yet")),
+        }
+    }
+
+    pub async fn set_charging_profile(
+        &mut self,
+        evse_id: Option<Uuid>,
+        charging_profile: ChargingProfile,
+    ) -> Result<(), Status> {
+        let connector_id = self.ocpp_1_6_connector_id(evse_id)?;
+
+        match &self.handle {
+            ProtocolHandle::Ocpp1_6(handle) => {
+                let cs_charging_profiles = charging_profile_to_ocpp_1_6(&charging_profile)
+                    .map_err(|error| Status::invalid_argument(error.to_string()))?;
+                let response = handle
+                    .send_set_charging_profile(SetChargingProfileRequest {
+                        connector_id,
+                        cs_charging_profiles,
+                    })
+                    .await
+                    .map_err(|error| {
+                        error!(
+                            error_message = error.to_string(),
+                            "Failed to set charging profile due to internal error"
+                        );
+                        Status::internal("Failed to set charging profile, due to internal error")
+                    })?
+                    .map_err(map_ocpp1_6_error_to_status)?;
+
+                match response.status {
+                    ChargingProfileStatus::Accepted => {
+                        // Transaction profiles end with the transaction, so there is no need to restore them
+                        if charging_profile.purpose != ChargingProfilePurpose::TxProfile {
+                            self.data_store
+                                .save_desired_charging_profile(&DesiredChargingProfile {
+                                    charger_id: self.id.clone(),
+                                    evse_id,
+                                    profile: charging_profile,
+                                    updated_at: Utc::now(),
+                                })
+                                .await
+                                .map_err(|error| {
+                                    error!(
+                                        error_message = error.to_string(),
+                                        "Failed to save charging profile due to internal error"
+                                    );
+                                    Status::internal(
+                                        "Failed to save charging profile, due to internal error",
+                                    )
+                                })?;
+                        }
+                        Ok(())
+                    }
+                    ChargingProfileStatus::Rejected => {
+                        Err(Status::cancelled("Charger rejected the charging profile"))
+                    }
+                    ChargingProfileStatus::NotSupported => Err(Status::unimplemented(
+                        "Charger does not support smart charging",
+                    )),
+                }
+            }
+            ProtocolHandle::Ocpp2_0_1(_handle) => {
+                Err(Status::internal("We can't handle ocpp 2.0.1 yet"))
+            }
+        }
+    }
+
+    pub async fn clear_charging_profile(
+        &mut self,
+        charging_profile_id: Option<i32>,
+        evse_id: Option<Uuid>,
+        purpose: Option<ChargingProfilePurpose>,
+        stack_level: Option<i32>,
+    ) -> Result<(), Status> {
+        let connector_id = match evse_id {
+            Some(evse_id) => Some(self.ocpp_1_6_connector_id(Some(evse_id))?),
+            None => None,
+        };
+
+        match &self.handle {
+            ProtocolHandle::Ocpp1_6(handle) => {
+                let response = handle
+                    .send_clear_charging_profile(ClearChargingProfileRequest {
+                        id: charging_profile_id,
+                        connector_id,
+                        charging_profile_purpose: purpose.map(|purpose| purpose.into()),
+                        stack_level,
+                    })
+                    .await
+                    .map_err(|error| {
+                        error!(
+                            error_message = error.to_string(),
+                            "Failed to clear charging profile due to internal error"
+                        );
+                        Status::internal("Failed to clear charging profile, due to internal error")
+                    })?
+                    .map_err(map_ocpp1_6_error_to_status)?;
+
+                // The charger might have lost the profiles already, so we forget them either way
+                let desired_profiles = self
+                    .data_store
+                    .get_desired_charging_profiles(&self.id)
+                    .await
+                    .map_err(|error| {
+                        error!(
+                            error_message = error.to_string(),
+                            "Failed to get charging profiles due to internal error"
+                        );
+                        Status::internal("Failed to get charging profiles, due to internal error")
+                    })?;
+                for desired_profile in desired_profiles.iter().filter(|desired_profile| {
+                    desired_profile.matches_clear_criteria(
+                        charging_profile_id,
+                        evse_id,
+                        purpose,
+                        stack_level,
+                    )
+                }) {
+                    self.data_store
+                        .delete_desired_charging_profile(
+                            &self.id,
+                            desired_profile.evse_id,
+                            desired_profile.profile.id,
+                        )
+                        .await
+                        .map_err(|error| {
+                            error!(
+                                error_message = error.to_string(),
+                                "Failed to delete charging profile due to internal error"
+                            );
+                            Status::internal(
+                                "Failed to delete charging profile, due to internal error",
+                            )
+                        })?;
+                }
+
+                match response.status {
+                    ClearChargingProfileStatus::Accepted => Ok(()),
+                    ClearChargingProfileStatus::Unknown => Err(Status::not_found(
+                        "Charger has no charging profile matching the request",
+                    )),
+                }
+            }
+            ProtocolHandle::Ocpp2_0_1(_handle) => {
+                Err(Status::internal("We can't handle ocpp 2.0.1 yet"))
+            }
+        }
+    }
+
+    pub async fn get_composite_schedule(
+        &mut self,
+        evse_id: Option<Uuid>,
+        duration: i32,
+        charging_rate_unit: Option<ChargingRateUnit>,
+    ) -> Result<Option<ChargingSchedule>, Status> {
+        let connector_id = self.ocpp_1_6_connector_id(evse_id)?;
+
+        match &self.handle {
+            ProtocolHandle::Ocpp1_6(handle) => {
+                let response = handle
+                    .send_get_composite_schedule(GetCompositeScheduleRequest {
+                        connector_id,
+                        duration,
+                        charging_rate_unit: charging_rate_unit.map(|unit| unit.into()),
+                    })
+                    .await
+                    .map_err(|error| {
+                        error!(
+                            error_message = error.to_string(),
+                            "Failed to get composite schedule due to internal error"
+                        );
+                        Status::internal("Failed to get composite schedule, due to internal error")
+                    })?
+                    .map_err(map_ocpp1_6_error_to_status)?;
+
+                match response.status {
+                    GetCompositeScheduleStatus::Accepted => {
+                        Ok(response.charging_schedule.map(|schedule| {
+                            let mut schedule = ChargingSchedule::from(schedule);
+                            // The schedule start is reported next to the schedule itself
+                            if schedule.start_schedule.is_none() {
+                                schedule.start_schedule = response.schedule_start;
+                            }
+                            schedule
+                        }))
+                    }
+                    GetCompositeScheduleStatus::Rejected => Err(Status::cancelled(
+                        "Charger could not calculate the composite schedule",
+                    )),
+                }
+            }
+            ProtocolHandle::Ocpp2_0_1(_handle) => {
+                Err(Status::internal("We can't handle ocpp 2.0.1 yet"))
+            }
+        }
+    }
+
+    pub async fn set_evse_limit(
+        &mut self,
+        evse_id: Uuid,
+        limit: Option<EvseLimit>,
+    ) -> Result<(), Status> {
+        let evse = self
+            .data
+            .evse(evse_id)
+            .ok_or_else(|| Status::not_found("Evse not found"))?
+            .clone();
+        let phases = evse_phase_count(&evse);
+        let voltage = evse_voltage(&evse);
+        let tx_default_profile_id =
+            EvseLimit::TX_DEFAULT_PROFILE_ID_OFFSET + evse.ocpp_evse_id as i32;
+        let tx_profile_id = EvseLimit::TX_PROFILE_ID_OFFSET + evse.ocpp_evse_id as i32;
+
+        match self.handle.clone() {
+            ProtocolHandle::Ocpp1_6(_handle) => {
+                let Some(limit) = limit else {
+                    for charging_profile_id in [tx_default_profile_id, tx_profile_id] {
+                        match self
+                            .clear_charging_profile(Some(charging_profile_id), None, None, None)
+                            .await
+                        {
+                            Err(status) if status.code() != Code::NotFound => return Err(status),
+                            _ => {}
+                        }
+                    }
+                    return Ok(());
+                };
+
+                let charging_rate_unit = select_charging_rate_unit(
+                    self.data.ocpp1_6configuration.as_ref(),
+                    match limit {
+                        EvseLimit::Kilowatts(_) => ChargingRateUnit::Watt,
+                        EvseLimit::Amperes(_) => ChargingRateUnit::Ampere,
+                    },
+                );
+                let value = match charging_rate_unit {
+                    ChargingRateUnit::Ampere => limit.to_amperes(phases, voltage),
+                    ChargingRateUnit::Watt => limit.to_watts(phases, voltage),
+                };
+                // Our limit should win over any other profile of the same purpose
+                let stack_level = self
+                    .data
+                    .ocpp1_6configuration
+                    .as_ref()
+                    .and_then(|configuration| {
+                        configuration
+                            .get_configuration(Ocpp1_6Configuration::CHARGE_PROFILE_MAX_STACK_LEVEL)
+                    })
+                    .and_then(|value| value.value.as_ref())
+                    .and_then(|value| value.parse::<i32>().ok())
+                    .unwrap_or(1);
+
+                self.set_charging_profile(
+                    Some(evse_id),
+                    create_limit_charging_profile(
+                        tx_default_profile_id,
+                        ChargingProfilePurpose::TxDefaultProfile,
+                        stack_level,
+                        None,
+                        charging_rate_unit,
+                        value,
+                        phases,
+                    ),
+                )
+                .await?;
+
+                // A running transaction might have its own TxProfile, which takes precedence
+                let transaction = self
+                    .data_store
+                    .get_ongoing_transaction(&self.id, evse_id)
+                    .await
+                    .map_err(|error| {
+                        error!(
+                            error_message = error.to_string(),
+                            "Failed to get ongoing transaction, due to internal error"
+                        );
+                        Status::internal("Failed to get ongoing transaction, due to internal error")
+                    })?;
+                if let Some(transaction) = transaction {
+                    self.set_charging_profile(
+                        Some(evse_id),
+                        create_limit_charging_profile(
+                            tx_profile_id,
+                            ChargingProfilePurpose::TxProfile,
+                            stack_level,
+                            Some(transaction.ocpp_transaction_id),
+                            charging_rate_unit,
+                            value,
+                            phases,
+                        ),
+                    )
+                    .await?;
+                }
+
+                Ok(())
+            }
+            ProtocolHandle::Ocpp2_0_1(handle) => {
+                let Some(limit) = limit else {
+                    let response = handle
+                        .send_clear_charging_profile(
+                            rust_ocpp::v2_0_1::messages::clear_charging_profile::ClearChargingProfileRequest {
+                                charging_profile_id: Some(tx_default_profile_id),
+                                charging_profile_criteria: None,
+                            },
+                        )
+                        .await
+                        .map_err(|error| {
+                            error!(
+                                error_message = error.to_string(),
+                                "Failed to clear charging profile due to internal error"
+                            );
+                            Status::internal(
+                                "Failed to clear charging profile, due to internal error",
+                            )
+                        })?
+                        .map_err(map_ocpp2_0_1_error_to_status)?;
+                    return match response.status {
+                        ClearChargingProfileStatusEnumType::Accepted => {
+                            info!(charger_id = self.id, "Evse limit cleared");
+                            Ok(())
+                        }
+                        ClearChargingProfileStatusEnumType::Unknown => {
+                            Err(Status::not_found("Charger has no evse limit to clear"))
+                        }
+                    };
+                };
+
+                let (charging_rate_unit, value) = match limit {
+                    EvseLimit::Kilowatts(_) => {
+                        (ChargingRateUnit::Watt, limit.to_watts(phases, voltage))
+                    }
+                    EvseLimit::Amperes(_) => {
+                        (ChargingRateUnit::Ampere, limit.to_amperes(phases, voltage))
+                    }
+                };
+                let charging_profile = create_external_constraints_profile(
+                    tx_default_profile_id,
+                    charging_rate_unit,
+                    value,
+                    phases,
+                    Utc::now(),
+                )
+                .map_err(|error| Status::invalid_argument(error.to_string()))?;
+
+                let response = handle
+                    .send_set_charging_profile(
+                        rust_ocpp::v2_0_1::messages::set_charging_profile::SetChargingProfileRequest {
+                            evse_id: evse.ocpp_evse_id as i32,
+                            charging_profile,
+                        },
+                    )
+                    .await
+                    .map_err(|error| {
+                        error!(
+                            error_message = error.to_string(),
+                            "Failed to set charging profile due to internal error"
+                        );
+                        Status::internal("Failed to set charging profile, due to internal error")
+                    })?
+                    .map_err(map_ocpp2_0_1_error_to_status)?;
+
+                match response.status {
+                    ChargingProfileStatusEnumType::Accepted => Ok(()),
+                    ChargingProfileStatusEnumType::Rejected => {
+                        Err(Status::cancelled("Charger rejected the charging profile"))
+                    }
+                }
+            }
+        }
+    }
+
+    /// Sends id tags to the charger so it can authorize them while offline, returning the new list
+    /// version. Lists longer than the charger accepts in one message are sent in chunks.
+    pub async fn send_local_list(
+        &mut self,
+        update_type: LocalListUpdateType,
+        entries: Vec<LocalAuthListEntry>,
+    ) -> Result<i32, Status> {
+        match self.handle.clone() {
+            ProtocolHandle::Ocpp1_6(handle) => {
+                if update_type == LocalListUpdateType::Full
+                    && entries.iter().any(|entry| entry.status.is_none())
+                {
+                    return Err(Status::invalid_argument(
+                        "All entries of a full update must have a status",
+                    ));
+                }
+
+                let max_length = self
+                    .data
+                    .ocpp1_6configuration
+                    .as_ref()
+                    .and_then(|configuration| {
+                        configuration
+                            .get_configuration(Ocpp1_6Configuration::SEND_LOCAL_LIST_MAX_LENGTH)
+                    })
+                    .and_then(|value| value.value.as_ref())
+                    .and_then(|value| value.parse::<usize>().ok())
+                    .filter(|max_length| *max_length > 0);
+                let chunks: Vec<Vec<LocalAuthListEntry>> = match max_length {
+                    Some(max_length) if entries.len() > max_length => entries
+                        .chunks(max_length)
+                        .map(|chunk| chunk.to_vec())
+                        .collect(),
+                    _ => vec![entries],
+                };
+
+                let mut list_version = match self.data.local_auth_list_version {
+                    Some(list_version) => list_version,
+                    None => self.get_local_list_version().await?,
+                };
+
+                for (index, chunk) in chunks.into_iter().enumerate() {
+                    // Only the first chunk may replace the list, the rest is added to it
+                    let update_type = if index == 0 {
+                        update_type
+                    } else {
+                        LocalListUpdateType::Differential
+                    };
+                    let mut refreshed_version = false;
+
+                    loop {
+                        let response = handle
+                            .send_send_local_list(SendLocalListRequest {
+                                list_version: list_version + 1,
+                                local_authorization_list: Some(
+                                    chunk.iter().cloned().map(|entry| entry.into()).collect(),
+                                ),
+                                update_type: update_type.into(),
+                            })
+                            .await
+                            .map_err(|error| {
+                                error!(
+                                    error_message = error.to_string(),
+                                    "Failed to send local list due to internal error"
+                                );
+                                Status::internal("Failed to send local list, due to internal error")
+                            })?
+                            .map_err(map_ocpp1_6_error_to_status)?;
+
+                        match response.status {
+                            UpdateStatus::Accepted => {
+                                list_version += 1;
+                                break;
+                            }
+                            // Our version is out of date, e.g. the list was changed by someone else
+                            UpdateStatus::VersionMismatch if !refreshed_version => {
+                                refreshed_version = true;
+                                list_version = self.get_local_list_version().await?;
+                            }
+                            UpdateStatus::VersionMismatch => {
+                                return Err(Status::aborted(
+                                    "Charger reported a local list version mismatch",
+                                ));
+                            }
+                            UpdateStatus::Failed => {
+                                return Err(Status::cancelled(
+                                    "Charger failed to update the local list",
+                                ));
+                            }
+                            UpdateStatus::NotSupported => {
+                                return Err(Status::unimplemented(
+                                    "Charger does not support local authorization lists",
+                                ));
+                            }
+                        }
+                    }
+
+                    self.data.local_auth_list_version = Some(list_version);
+                    self.sync_data().await.map_err(|error| {
+                        error!(
+                            error_message = error.to_string(),
+                            "Failed to save local list version due to internal error"
+                        );
+                        Status::internal("Failed to save local list version, due to internal error")
+                    })?;
+                }
+
+                Ok(list_version)
+            }
+            ProtocolHandle::Ocpp2_0_1(_handle) => {
+                Err(Status::internal("We can't handle ocpp 2.0.1 yet"))
+            }
+        }
+    }
+
+    /// Reads the version of the local authorization list from the charger
+    pub async fn get_local_list_version(&mut self) -> Result<i32, Status> {
+        match &self.handle {
+            ProtocolHandle::Ocpp1_6(handle) => {
+                let response = handle
+                    .send_get_local_list_version(GetLocalListVersionRequest {})
+                    .await
+                    .map_err(|error| {
+                        error!(
+                            error_message = error.to_string(),
+                            "Failed to get local list version due to internal error"
+                        );
+                        Status::internal("Failed to get local list version, due to internal error")
+                    })?
+                    .map_err(map_ocpp1_6_error_to_status)?;
+
+                // A version of -1 means the charger doesn't support local lists
+                if response.list_version < 0 {
+                    return Err(Status::unimplemented(
+                        "Charger does not support local authorization lists",
+                    ));
+                }
+
+                self.data.local_auth_list_version = Some(response.list_version);
+                self.sync_data().await.map_err(|error| {
+                    error!(
+                        error_message = error.to_string(),
+                        "Failed to save local list version due to internal error"
+                    );
+                    Status::internal("Failed to save local list version, due to internal error")
+                })?;
+
+                Ok(response.list_version)
+            }
+            ProtocolHandle::Ocpp2_0_1(_handle) => {
+                Err(Status::internal("We can't handle ocpp 2.0.1 yet"))
+            }
+        }
+    }
+
+    /// Asks the charger to download and install new firmware, and starts tracking the rollout
+    pub async fn update_firmware(
+        &mut self,
+        location: &str,
+        target_version: Option<String>,
+        options: FirmwareUpdateOptions,
+    ) -> Result<FirmwareUpdate, Status> {
+        let retrieve_date = options.retrieve_date.unwrap_or_else(Utc::now);
+
+        let firmware_update = match &self.handle {
+            ProtocolHandle::Ocpp1_6(handle) => {
+                match (options.signing_certificate, options.signature) {
+                    // Signed firmware is part of the security extension
+                    (Some(signing_certificate), Some(signature)) => {
+                        let request_id = rand::rng().random_range(1..i32::MAX);
+                        let response = handle
+                            .send_signed_update_firmware(SignedUpdateFirmwareRequest {
+                                retries: options.retries,
+                                retry_interval: options.retry_interval,
+                                request_id,
+                                firmware: SignedFirmware {
+                                    location: location.to_string(),
+                                    retrieve_date_time: retrieve_date,
+                                    install_date_time: options.install_date,
+                                    signing_certificate,
+                                    signature,
+                                },
+                            })
+                            .await
+                            .map_err(|error| {
+                                error!(
+                                    error_message = error.to_string(),
+                                    "Failed to update firmware due to internal error"
+                                );
+                                Status::internal("Failed to update firmware, due to internal error")
+                            })?
+                            .map_err(map_ocpp1_6_error_to_status)?;
+                        check_update_firmware_status(response.status)?;
+
+                        FirmwareUpdate::new(
+                            &self.id,
+                            location,
+                            target_version,
+                            self.data.firmware_version.clone(),
+                            Some(request_id),
+                        )
+                    }
+                    (None, None) => {
+                        if options.install_date.is_some() {
+                            return Err(Status::invalid_argument(
+                                "Install dates are only supported for signed firmware by ocpp 1.6",
+                            ));
+                        }
+
+                        handle
+                            .send_update_firmware(UpdateFirmwareRequest {
+                                location: location.to_string(),
+                                retries: options.retries,
+                                retrieve_date,
+                                retry_interval: options.retry_interval,
+                            })
+                            .await
+                            .map_err(|error| {
+                                error!(
+                                    error_message = error.to_string(),
+                                    "Failed to update firmware due to internal error"
+                                );
+                                Status::internal("Failed to update firmware, due to internal error")
+                            })?
+                            .map_err(map_ocpp1_6_error_to_status)?;
+
+                        FirmwareUpdate::new(
+                            &self.id,
+                            location,
+                            target_version,
+                            self.data.firmware_version.clone(),
+                            None,
+                        )
+                    }
+                    _ => {
+                        return Err(Status::invalid_argument(
+                            "Signed firmware needs both a signing certificate and a signature",
+                        ));
+                    }
+                }
+            }
+            ProtocolHandle::Ocpp2_0_1(handle) => {
+                let request_id = rand::rng().random_range(1..i32::MAX);
+                let response = handle
+                    .send_update_firmware(
+                        rust_ocpp::v2_0_1::messages::update_firmware::UpdateFirmwareRequest {
+                            retries: options.retries,
+                            retry_interval: options.retry_interval,
+                            request_id,
+                            firmware: FirmwareType {
+                                location: location.to_string(),
+                                retrieve_date_time: retrieve_date,
+                                install_date_time: options.install_date,
+                                signing_certificate: options.signing_certificate,
+                                signature: options.signature,
+                            },
+                        },
+                    )
+                    .await
+                    .map_err(|error| {
+                        error!(
+                            error_message = error.to_string(),
+                            "Failed to update firmware due to internal error"
+                        );
+                        Status::internal("Failed to update firmware, due to internal error")
+                    })?
+                    .map_err(map_ocpp2_0_1_error_to_status)?;
+
+                check_update_firmware_status(response.status)?;
+
+                FirmwareUpdate::new(
+                    &self.id,
+                    location,
+                    target_version,
+                    self.data.firmware_version.clone(),
+                    Some(request_id),
+                )
+            }
+        };
+
+        self.data_store
+            .save_firmware_update(&firmware_update)
+            .await
+            .map_err(|error| {
+                error!(
+                    error_message = error.to_string(),
+                    "Failed to save firmware update due to internal error"
+                );
+                Status::internal("Failed to save firmware update, due to internal error")
+            })?;
+
+        Ok(firmware_update)
+    }
+
+    /// Updates the firmware with an image hosted by us, the charger is given a download url with
+    /// a token that only it can use until it expires
+    pub async fn update_firmware_from_image(
+        &mut self,
+        image_id: Uuid,
+        target_version: Option<String>,
+        options: FirmwareUpdateOptions,
+    ) -> Result<FirmwareUpdate, Status> {
+        let image = self
+            .data_store
+            .get_firmware_image(image_id)
+            .await
+            .map_err(|error| {
+                error!(
+                    error_message = error.to_string(),
+                    "Failed to get firmware image due to internal error"
+                );
+                Status::internal("Failed to get firmware image, due to internal error")
+            })?
+            .ok_or_else(|| Status::not_found("Firmware image not found"))?;
+
+        if !image.supports(self.data.vendor.as_deref(), self.data.model.as_deref()) {
+            return Err(Status::failed_precondition(
+                "The firmware image is not made for this charger",
+            ));
+        }
+
+        let public_url = self.config.firmware_public_url().ok_or_else(|| {
+            Status::failed_precondition("No public url is configured for hosted firmware")
+        })?;
+
+        let retrieve_date = options
+            .retrieve_date
+            .unwrap_or_else(Utc::now)
+            .max(Utc::now());
+        let download_token = FirmwareDownloadToken {
+            token: rand::rng()
+                .sample_iter(&Alphanumeric)
+                .take(32)
+                .map(char::from)
+                .collect(),
+            image_id: image.id,
+            charger_id: self.id.clone(),
+            expires_at: retrieve_date
+                + Duration::seconds(self.config.firmware_download_token_ttl_secs() as i64),
+        };
+        self.data_store
+            .save_firmware_download_token(&download_token)
+            .await
+            .map_err(|error| {
+                error!(
+                    error_message = error.to_string(),
+                    "Failed to save firmware download token due to internal error"
+                );
+                Status::internal("Failed to save firmware download token, due to internal error")
+            })?;
+
+        let location = format!(
+            "{}/firmware/{}/{}",
+            public_url.trim_end_matches('/'),
+            download_token.token,
+            image.file_name
+        );
+
+        self.update_firmware(&location, target_version.or(Some(image.version)), options)
+            .await
+    }
+
+    /// Resolves the ocpp 1.6 connector id for an evse, where no evse means the whole charger
+    fn ocpp_1_6_connector_id(&self, evse_id: Option<Uuid>) -> Result<i32, Status> {
+        match evse_id {
+            Some(evse_id) => {
+                let evse = self
+                    .data
+                    .evse(evse_id)
+                    .ok_or_else(|| Status::not_found("Evse not found"))?;
+                Ok(evse.ocpp_evse_id as i32)
+            }
+            None => Ok(0),
+        }
+    }
+
+    /// The vendor quirks of the charger, known once it has sent a BootNotification
+    pub fn vendor_profile(&self) -> &dyn VendorProfile {
+        self.vendor_profile.as_ref()
+    }
+
+    /// Selects the vendor profile again after the charger reported its vendor and model
+    pub(crate) fn update_vendor_profile(&mut self) {
+        self.vendor_profile = select_vendor_profile(
+            self.data.vendor.as_deref().unwrap_or_default(),
+            self.data.model.as_deref().unwrap_or_default(),
+        );
+        info!(
+            charger_id = self.id,
+            vendor_profile = self.vendor_profile.name(),
+            "selected vendor profile"
+        );
+    }
+
+    /// Records a status notification on the firmware update it belongs to. Without a request id
+    /// the latest update is assumed, as OCPP 1.6 doesn't relate the notifications to the request.
+    pub(crate) async fn record_firmware_update_status(
+        &mut self,
+        status: FirmwareUpdateStatus,
+        ocpp_request_id: Option<i32>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let firmware_updates = self.data_store.get_firmware_updates(&self.id).await?;
+        let firmware_update = match ocpp_request_id {
+            Some(ocpp_request_id) => firmware_updates
+                .into_iter()
+                .find(|update| update.ocpp_request_id == Some(ocpp_request_id)),
+            None => firmware_updates.into_iter().next(),
+        };
+
+        let Some(mut firmware_update) = firmware_update else {
+            warn!(
+                status = format!("{:?}", status),
+                "Received firmware status for an unknown firmware update"
+            );
+            return Ok(());
+        };
+        if firmware_update.status.is_final() {
+            return Ok(());
+        }
+
+        firmware_update.record_status(status, Utc::now());
+        self.data_store
+            .save_firmware_update(&firmware_update)
+            .await?;
+        Ok(())
+    }
+
+    /// Checks the firmware version reported in a boot notification against the latest update
+    pub(crate) async fn confirm_firmware_update(
+        &mut self,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let firmware_updates = self.data_store.get_firmware_updates(&self.id).await?;
+        let Some(mut firmware_update) = firmware_updates.into_iter().next() else {
+            return Ok(());
+        };
+
+        if confirm_firmware_update(
+            &mut firmware_update,
+            self.data.firmware_version.as_deref(),
+            Utc::now(),
+        ) {
+            info!(
+                firmware_update_id = firmware_update.id.to_string(),
+                firmware_version = self.data.firmware_version,
+                status = format!("{:?}", firmware_update.status),
+                "Firmware update finished"
+            );
+            self.data_store
+                .save_firmware_update(&firmware_update)
+                .await?;
+        }
+        Ok(())
+    }
+
+    pub(crate) async fn get_ongoing_transaction(
+        &mut self,
+        evse_ocpp_id: u32,
+    ) -> Result<Option<Transaction>, Box<dyn std::error::Error + Send + Sync>> {
+        let evse = self.data.evse_by_ocpp_id_or_create(evse_ocpp_id);
+        self.data_store
+            .get_ongoing_transaction(&self.id, evse.id)
+            .await
+    }
+
+    pub(crate) async fn transaction_by_ocpp_id(
+        &mut self,
+        transaction_ocpp_id: i32,
+    ) -> Result<Option<Transaction>, Box<dyn std::error::Error + Send + Sync>> {
+        self.data_store
+            .get_transaction_by_ocpp_id(&self.id, &transaction_ocpp_id.to_string())
+            .await
+    }
+
+    pub(crate) async fn end_ongoing_transaction(
+        &mut self,
+        connector_id: u32,
+        end_time: &Option<chrono::DateTime<Utc>>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if let Some(transaction) = self.get_ongoing_transaction(connector_id).await? {
+            info!(
+                "Ending transaction {} for connector {}",
+                transaction.ocpp_transaction_id, connector_id
+            );
+            self.data_store
+                .end_transaction(
+                    &self.id,
+                    &transaction.ocpp_transaction_id,
+                    end_time.unwrap_or_else(Utc::now),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) async fn reconcile_ocpp_1_6_charging_profiles(
         &mut self,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        let desired_profiles = self
+            .data_store
+            .get_desired_charging_profiles(&self.id)
+            .await?;
+
+        let mut profiles_by_evse: BTreeMap<Option<Uuid>, Vec<ChargingProfile>> = BTreeMap::new();
+        for desired_profile in desired_profiles {
+            profiles_by_evse
+                .entry(desired_profile.evse_id)
+                .or_default()
+                .push(desired_profile.profile);
+        }
+
+        let handle = match &self.handle {
+            ProtocolHandle::Ocpp1_6(handle) => handle.clone(),
+            ProtocolHandle::Ocpp2_0_1(_handle) => {
+                return Err("Charging profiles can only be reconciled on ocpp 1.6 chargers".into());
+            }
+        };
+
+        let now = Utc::now();
+        for (evse_id, profiles) in profiles_by_evse {
+            let Ok(connector_id) = self.ocpp_1_6_connector_id(evse_id) else {
+                warn!(
+                    charger_id = self.id,
+                    evse_id = ?evse_id,
+                    "Charging profile refers to an evse that no longer exists"
+                );
+                continue;
+            };
+
+            // The composite schedule is reported in a single unit, so each unit is compared with
+            // its own composite schedule
+            let mut units = Vec::new();
+            for profile in &profiles {
+                if !units.contains(&profile.schedule.charging_rate_unit) {
+                    units.push(profile.schedule.charging_rate_unit);
+                }
+            }
+
+            let mut reapplied_profile_ids = Vec::new();
+            for unit in units {
+                let unit_profiles: Vec<ChargingProfile> = profiles
+                    .iter()
+                    .filter(|profile| profile.schedule.charging_rate_unit == unit)
+                    .cloned()
+                    .collect();
+
+                let composite = match handle
+                    .send_get_composite_schedule(GetCompositeScheduleRequest {
+                        connector_id,
+                        duration: 24 * 60 * 60,
+                        charging_rate_unit: Some(unit.into()),
+                    })
+                    .await?
+                {
+                    Ok(response) if response.status == GetCompositeScheduleStatus::Accepted => {
+                        response.charging_schedule.map(|schedule| {
+                            let mut schedule = ChargingSchedule::from(schedule);
+                            schedule.start_schedule =
+                                schedule.start_schedule.or(response.schedule_start);
+                            schedule
+                        })
+                    }
+                    Ok(_) => None,
+                    Err(err) => {
+                        warn!(
+                            error_message = err.to_string(),
+                            "Failed to get composite schedule, re-applying all charging profiles"
+                        );
+                        None
+                    }
+                };
+
+                for profile in
+                    find_missing_charging_profiles(&unit_profiles, composite.as_ref(), now)
+                {
+                    match handle
+                        .send_set_charging_profile(SetChargingProfileRequest {
+                            connector_id,
+                            cs_charging_profiles: charging_profile_to_ocpp_1_6(&profile)?,
+                        })
+                        .await?
+                    {
+                        Ok(response) => {
+                            if response.status == ChargingProfileStatus::Accepted {
+                                info!("Charging profile {} re-applied", profile.id);
+                                reapplied_profile_ids.push(profile.id);
+                            } else {
+                                warn!(
+                                    "Failed to re-apply charging profile {}: {:?}",
+                                    profile.id, response.status
+                                );
+                            }
+                        }
+                        Err(err) => {
+                            warn!(
+                                error_message = err.to_string(),
+                                "Failed to re-apply charging profile"
+                            );
+                        }
+                    }
+                }
+            }
+
+            if !reapplied_profile_ids.is_empty() {
+                self.event_manager
+                    .send_event(EventPayload::ChargingProfileDriftCorrectedEvent(
+                        ChargingProfileDriftCorrectedEvent {
+                            charger_id: self.id.clone(),
+                            evse_id,
+                            vendor: self.data.vendor.clone(),
+                            model: self.data.model.clone(),
+                            firmware_version: self.data.firmware_version.clone(),
+                            charging_profile_ids: reapplied_profile_ids,
+                            corrected_at: now,
+                        },
+                    ))
+                    .await;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The OCPP 1.6 configuration the charger should have, the defaults adjusted to its vendor
+    /// with the overrides of the charger on top
+    pub fn desired_ocpp_1_6_configuration(&self) -> BTreeMap<String, String> {
+        let mut configuration = self
+            .data
+            .settings
+            .get_ocpp_1_6_configuration_entries(&self.config);
+        self.vendor_profile()
+            .apply_configuration_defaults(&mut configuration);
+        configuration.extend(self.data.settings.ocpp1_6_configuration_overrides.clone());
+        // Overrides stored before the vendor was known can't bring back unsupported keys
+        for key in self.vendor_profile().unsupported_configuration_keys() {
+            configuration.remove(*key);
+        }
+        configuration
+    }
+
+    /// Pushes every desired value that differs from the reported configuration, and returns how
+    /// the charger responded to each key that was pushed
+    pub(crate) async fn update_ocpp_1_6_charger_configuration(
+        &mut self,
+    ) -> Result<
+        BTreeMap<String, ConfigurationStatus>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    > {
+        let mut results = BTreeMap::new();
         if let Some(configuration) = &self.data.ocpp1_6configuration {
-            let target_configuration = self
-                .data
-                .settings
-                .get_ocpp_1_6_configuration_entries(&self.config);
+            let target_configuration = self.desired_ocpp_1_6_configuration();
 
             let mut needs_reboot = false;
             for (key, value) in target_configuration {
-                if let Some(config_value) = configuration.get_configuration(&key) {
-                    if !config_value.read_only && config_value.value != Some(value.clone()) {
-                        match self
-                            .handle
-                            .as_ocpp1_6()
-                            .unwrap()
-                            .send_change_configuration(ChangeConfigurationRequest {
-                                key: key.to_string(),
-                                value: value.to_string(),
-                            })
-                            .await?
-                        {
-                            Ok(response) => {
-                                if response.status == ConfigurationStatus::Accepted
-                                    || response.status == ConfigurationStatus::RebootRequired
-                                {
-                                    info!("Configuration {} updated to {}", key, value);
-                                    if response.status == ConfigurationStatus::RebootRequired {
-                                        needs_reboot = true;
-                                    }
-                                } else {
-                                    warn!(
-                                        "Failed to update configuration {}: {:?}",
-                                        key, response.status
-                                    );
+                // The fleet defaults aren't validated when they're configured, so keys this
+                // charger can't take only show up here
+                if let Some(reason) = invalid_configuration_key(
+                    &key,
+                    configuration,
+                    self.vendor_profile().unsupported_configuration_keys(),
+                ) {
+                    warn!(
+                        charger_id = self.id,
+                        key = key,
+                        reason = reason,
+                        "desired configuration value can't be pushed"
+                    );
+                    continue;
+                }
+                if let Some(config_value) = configuration.get_configuration(&key)
+                    && config_value.value != Some(value.clone())
+                {
+                    match self
+                        .handle
+                        .as_ocpp1_6()
+                        .unwrap()
+                        .send_change_configuration(ChangeConfigurationRequest {
+                            key: key.to_string(),
+                            value: value.to_string(),
+                        })
+                        .await?
+                    {
+                        Ok(response) => {
+                            results.insert(key.clone(), response.status.clone());
+                            if response.status == ConfigurationStatus::Accepted
+                                || response.status == ConfigurationStatus::RebootRequired
+                            {
+                                info!("Configuration {} updated to {}", key, value);
+                                if response.status == ConfigurationStatus::RebootRequired {
+                                    needs_reboot = true;
                                 }
-                            }
-                            Err(err) => {
+                            } else {
                                 warn!(
-                                    error_message = err.to_string(),
-                                    "Failed to change configuration"
+                                    "Failed to update configuration {}: {:?}",
+                                    key, response.status
                                 );
                             }
                         }
+                        Err(err) => {
+                            warn!(
+                                error_message = err.to_string(),
+                                "Failed to change configuration"
+                            );
+                        }
                     }
                 }
             }
@@ -630,6 +2249,676 @@ impl Charger {
                 }
             }
         }
-        Ok(())
+        Ok(results)
+    }
+
+    /// Stores the configuration the charger is left with after pushing the desired configuration
+    /// as a new snapshot, along with the keys that are still out of line with it
+    pub(crate) async fn record_ocpp_1_6_configuration_snapshot(
+        &self,
+        desired: &BTreeMap<String, String>,
+        results: &BTreeMap<String, ConfigurationStatus>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        let Some(configuration) = &self.data.ocpp1_6configuration else {
+            return Ok(());
+        };
+        let previous = self
+            .data_store
+            .get_latest_configuration_snapshot(&self.id)
+            .await?;
+
+        let drift = resolve_configuration_drift(
+            detect_configuration_drift(
+                desired,
+                configuration,
+                previous.as_ref().map(|snapshot| &snapshot.configuration),
+            ),
+            results,
+        );
+        for drift in &drift {
+            warn!(
+                charger_id = self.id,
+                key = drift.key,
+                desired_value = drift.desired_value,
+                actual_value = drift.actual_value,
+                reason = format!("{:?}", drift.reason),
+                "Configuration drifted from the desired value"
+            );
+        }
+
+        self.data_store
+            .save_configuration_snapshot(&ConfigurationSnapshot {
+                id: Uuid::new_v4(),
+                charger_id: self.id.clone(),
+                version: previous.map(|snapshot| snapshot.version + 1).unwrap_or(1),
+                taken_at: Utc::now(),
+                configuration: configuration_after_push(configuration, desired, results),
+                drift,
+            })
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::charger::test_charger::charger;
+    use crate::network_interface::{
+        MockOcpp2_0_1NetworkInterfaceHandle, MockOcpp16NetworkInterfaceHandle,
+    };
+    use crate::ocpp1_6_security::messages::certificate_signed::CertificateSignedResponse;
+    use crate::ocpp1_6_security::messages::extended_trigger_message::ExtendedTriggerMessageResponse;
+    use crate::ocpp1_6_security::messages::signed_update_firmware::SignedUpdateFirmwareResponse;
+    use rust_ocpp::v1_6::messages::change_configuration::ChangeConfigurationResponse;
+    use rust_ocpp::v1_6::messages::clear_cache::ClearCacheResponse;
+    use rust_ocpp::v1_6::messages::get_configuration::GetConfigurationResponse;
+    use rust_ocpp::v1_6::messages::get_local_list_version::GetLocalListVersionResponse;
+    use rust_ocpp::v1_6::messages::send_local_list::SendLocalListResponse;
+    use rust_ocpp::v1_6::messages::trigger_message::TriggerMessageResponse;
+    use rust_ocpp::v1_6::messages::unlock_connector::UnlockConnectorResponse;
+    use rust_ocpp::v1_6::types::{KeyValue, UpdateType};
+    use rust_ocpp::v2_0_1::enumerations::update_firmware_status_enum_type::UpdateFirmwareStatusEnumType;
+    use rust_ocpp::v2_0_1::messages::trigger_message::TriggerMessageResponse as TriggerMessageResponse2_0_1;
+    use rust_ocpp::v2_0_1::messages::unlock_connector::UnlockConnectorResponse as UnlockConnectorResponse2_0_1;
+    use shared::data::LocalAuthorizationStatus;
+    use shared::data_store::MockDataStore;
+
+    async fn ocpp_2_0_1_charger(
+        handle: MockOcpp2_0_1NetworkInterfaceHandle,
+        data_store: MockDataStore,
+    ) -> Charger {
+        let mut charger = charger(MockOcpp16NetworkInterfaceHandle::new(), data_store).await;
+        charger.handle = ProtocolHandle::Ocpp2_0_1(Arc::new(handle));
+        charger
+    }
+
+    fn entry(id_tag: &str) -> LocalAuthListEntry {
+        LocalAuthListEntry {
+            id_tag: id_tag.to_string(),
+            status: Some(LocalAuthorizationStatus::Accepted),
+            expiry_date: None,
+            parent_id_tag: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_send_local_list_is_split_into_chunks() {
+        let mut handle = MockOcpp16NetworkInterfaceHandle::new();
+        let mut sequence = mockall::Sequence::new();
+        handle
+            .expect_send_send_local_list()
+            .withf(|request| {
+                request.list_version == 5
+                    && request.update_type == UpdateType::Full
+                    && request.local_authorization_list.as_ref().unwrap().len() == 2
+            })
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_| {
+                Ok(Ok(SendLocalListResponse {
+                    status: UpdateStatus::Accepted,
+                }))
+            });
+        handle
+            .expect_send_send_local_list()
+            .withf(|request| {
+                request.list_version == 6
+                    && request.update_type == UpdateType::Differential
+                    && request.local_authorization_list.as_ref().unwrap().len() == 1
+            })
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_| {
+                Ok(Ok(SendLocalListResponse {
+                    status: UpdateStatus::Accepted,
+                }))
+            });
+        let mut data_store = MockDataStore::new();
+        data_store
+            .expect_save_charger_data()
+            .times(2)
+            .returning(|_| Ok(()));
+
+        let mut charger = charger(handle, data_store).await;
+        let mut configuration = Ocpp1_6Configuration::new();
+        configuration.set_value(Ocpp1_6Configuration::SEND_LOCAL_LIST_MAX_LENGTH, "2");
+        charger.data.ocpp1_6configuration = Some(configuration);
+        charger.data.local_auth_list_version = Some(4);
+
+        let list_version = charger
+            .send_local_list(
+                LocalListUpdateType::Full,
+                vec![entry("a"), entry("b"), entry("c")],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(list_version, 6);
+        assert_eq!(charger.data.local_auth_list_version, Some(6));
+    }
+
+    #[tokio::test]
+    async fn test_send_local_list_refreshes_version_on_mismatch() {
+        let mut handle = MockOcpp16NetworkInterfaceHandle::new();
+        handle
+            .expect_send_send_local_list()
+            .withf(|request| request.list_version == 2)
+            .times(1)
+            .returning(|_| {
+                Ok(Ok(SendLocalListResponse {
+                    status: UpdateStatus::VersionMismatch,
+                }))
+            });
+        handle
+            .expect_send_get_local_list_version()
+            .times(1)
+            .returning(|_| Ok(Ok(GetLocalListVersionResponse { list_version: 7 })));
+        handle
+            .expect_send_send_local_list()
+            .withf(|request| request.list_version == 8)
+            .times(1)
+            .returning(|_| {
+                Ok(Ok(SendLocalListResponse {
+                    status: UpdateStatus::Accepted,
+                }))
+            });
+        let mut data_store = MockDataStore::new();
+        data_store.expect_save_charger_data().returning(|_| Ok(()));
+
+        let mut charger = charger(handle, data_store).await;
+        charger.data.local_auth_list_version = Some(1);
+
+        let list_version = charger
+            .send_local_list(LocalListUpdateType::Differential, vec![entry("a")])
+            .await
+            .unwrap();
+
+        assert_eq!(list_version, 8);
+    }
+
+    #[tokio::test]
+    async fn test_send_full_local_list_requires_status() {
+        let mut charger = charger(
+            MockOcpp16NetworkInterfaceHandle::new(),
+            MockDataStore::new(),
+        )
+        .await;
+        let mut removal = entry("a");
+        removal.status = None;
+
+        let status = charger
+            .send_local_list(LocalListUpdateType::Full, vec![removal])
+            .await
+            .unwrap_err();
+
+        assert_eq!(status.code(), Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn test_get_local_list_version_not_supported() {
+        let mut handle = MockOcpp16NetworkInterfaceHandle::new();
+        handle
+            .expect_send_get_local_list_version()
+            .returning(|_| Ok(Ok(GetLocalListVersionResponse { list_version: -1 })));
+
+        let mut charger = charger(handle, MockDataStore::new()).await;
+
+        let status = charger.get_local_list_version().await.unwrap_err();
+
+        assert_eq!(status.code(), Code::Unimplemented);
+        assert_eq!(charger.data.local_auth_list_version, None);
+    }
+
+    #[tokio::test]
+    async fn test_accepted_configuration_value_is_stored() {
+        let mut handle = MockOcpp16NetworkInterfaceHandle::new();
+        handle
+            .expect_send_change_configuration()
+            .withf(|request| request.key == "HeartbeatInterval" && request.value == "60")
+            .times(1)
+            .returning(|_| {
+                Ok(Ok(ChangeConfigurationResponse {
+                    status: ConfigurationStatus::RebootRequired,
+                }))
+            });
+        let mut data_store = MockDataStore::new();
+        data_store
+            .expect_save_charger_data()
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let mut charger = charger(handle, data_store).await;
+
+        let status = charger
+            .change_ocpp_1_6_configuration_value("HeartbeatInterval", "60")
+            .await
+            .unwrap();
+
+        assert_eq!(status, ConfigurationStatus::RebootRequired);
+        let value = charger
+            .data
+            .ocpp1_6configuration
+            .as_ref()
+            .and_then(|configuration| configuration.get_configuration("HeartbeatInterval"))
+            .and_then(|value| value.value.clone());
+        assert_eq!(value.as_deref(), Some("60"));
+    }
+
+    #[tokio::test]
+    async fn test_rejected_configuration_value_is_not_stored() {
+        let mut handle = MockOcpp16NetworkInterfaceHandle::new();
+        handle.expect_send_change_configuration().returning(|_| {
+            Ok(Ok(ChangeConfigurationResponse {
+                status: ConfigurationStatus::Rejected,
+            }))
+        });
+
+        let mut charger = charger(handle, MockDataStore::new()).await;
+
+        let status = charger
+            .change_ocpp_1_6_configuration_value("HeartbeatInterval", "60")
+            .await
+            .unwrap();
+
+        assert_eq!(status, ConfigurationStatus::Rejected);
+        assert!(charger.data.ocpp1_6configuration.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_read_only_configuration_value_is_not_sent() {
+        let mut charger = charger(
+            MockOcpp16NetworkInterfaceHandle::new(),
+            MockDataStore::new(),
+        )
+        .await;
+        charger.data.ocpp1_6configuration = Some(
+            Ocpp1_6Configuration::from_full_get_configuration_response(&GetConfigurationResponse {
+                configuration_key: Some(vec![KeyValue {
+                    key: "NumberOfConnectors".to_string(),
+                    readonly: true,
+                    value: Some("1".to_string()),
+                }]),
+                unknown_key: None,
+            }),
+        );
+
+        let status = charger
+            .change_ocpp_1_6_configuration_value("NumberOfConnectors", "2")
+            .await
+            .unwrap_err();
+
+        assert_eq!(status.code(), Code::FailedPrecondition);
+    }
+
+    #[tokio::test]
+    async fn test_clear_cache() {
+        let mut handle = MockOcpp16NetworkInterfaceHandle::new();
+        let mut sequence = mockall::Sequence::new();
+        handle
+            .expect_send_clear_cache()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_| {
+                Ok(Ok(ClearCacheResponse {
+                    status: ClearCacheStatus::Accepted,
+                }))
+            });
+        handle
+            .expect_send_clear_cache()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_| {
+                Ok(Ok(ClearCacheResponse {
+                    status: ClearCacheStatus::Rejected,
+                }))
+            });
+
+        let mut charger = charger(handle, MockDataStore::new()).await;
+
+        assert!(charger.clear_cache().await.is_ok());
+        assert_eq!(
+            charger.clear_cache().await.unwrap_err().code(),
+            Code::Cancelled
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unlock_connector() {
+        let mut handle = MockOcpp16NetworkInterfaceHandle::new();
+        handle
+            .expect_send_unlock_connector()
+            .withf(|request| request.connector_id == 1)
+            .times(1)
+            .returning(|_| {
+                Ok(Ok(UnlockConnectorResponse {
+                    status: UnlockStatus::Unlocked,
+                }))
+            });
+
+        let mut charger = charger(handle, MockDataStore::new()).await;
+        let evse = charger.data.evses[0].clone();
+
+        let status = charger
+            .unlock_connector(evse.id, evse.connectors[0].id, false)
+            .await
+            .unwrap();
+
+        assert_eq!(status, UnlockStatus::Unlocked);
+    }
+
+    #[tokio::test]
+    async fn test_permanently_locked_cable_needs_override() {
+        let mut handle = MockOcpp16NetworkInterfaceHandle::new();
+        handle
+            .expect_send_unlock_connector()
+            .times(1)
+            .returning(|_| {
+                Ok(Ok(UnlockConnectorResponse {
+                    status: UnlockStatus::Unlocked,
+                }))
+            });
+
+        let mut charger = charger(handle, MockDataStore::new()).await;
+        charger.data.settings.permanently_lock_cable_to_charger = true;
+        let evse = charger.data.evses[0].clone();
+
+        let status = charger
+            .unlock_connector(evse.id, evse.connectors[0].id, false)
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::FailedPrecondition);
+
+        let status = charger
+            .unlock_connector(evse.id, evse.connectors[0].id, true)
+            .await
+            .unwrap();
+        assert_eq!(status, UnlockStatus::Unlocked);
+    }
+
+    #[tokio::test]
+    async fn test_unlock_unknown_connector() {
+        let mut charger = charger(
+            MockOcpp16NetworkInterfaceHandle::new(),
+            MockDataStore::new(),
+        )
+        .await;
+        let evse_id = charger.data.evses[0].id;
+
+        let status = charger
+            .unlock_connector(evse_id, Uuid::new_v4(), false)
+            .await
+            .unwrap_err();
+
+        assert_eq!(status.code(), Code::NotFound);
+    }
+
+    #[tokio::test]
+    async fn test_unlock_connector_with_ongoing_transaction_on_ocpp_2_0_1() {
+        let mut handle = MockOcpp2_0_1NetworkInterfaceHandle::new();
+        handle
+            .expect_send_unlock_connector()
+            .withf(|request| request.evse_id == 1 && request.connector_id == 1)
+            .returning(|_| {
+                Ok(Ok(UnlockConnectorResponse2_0_1 {
+                    status: UnlockStatusEnumType::OngoingAuthorizedTransaction,
+                    status_info: None,
+                }))
+            });
+
+        let mut charger = ocpp_2_0_1_charger(handle, MockDataStore::new()).await;
+        let evse = charger.data.evses[0].clone();
+
+        let status = charger
+            .unlock_connector(evse.id, evse.connectors[0].id, false)
+            .await
+            .unwrap_err();
+
+        assert_eq!(status.code(), Code::FailedPrecondition);
+    }
+
+    #[tokio::test]
+    async fn test_trigger_message_for_evse() {
+        let mut handle = MockOcpp16NetworkInterfaceHandle::new();
+        handle
+            .expect_send_trigger_message()
+            .withf(|request| {
+                request.requested_message == MessageTrigger::StatusNotification
+                    && request.connector_id == Some(1)
+            })
+            .times(1)
+            .returning(|_| {
+                Ok(Ok(TriggerMessageResponse {
+                    status: TriggerMessageStatus::Accepted,
+                }))
+            });
+
+        let mut charger = charger(handle, MockDataStore::new()).await;
+        let evse_id = charger.data.evses[0].id;
+
+        let status = charger
+            .trigger_message(TriggerableMessage::StatusNotification, Some(evse_id))
+            .await
+            .unwrap();
+
+        assert_eq!(status, TriggerMessageStatus::Accepted);
+    }
+
+    #[tokio::test]
+    async fn test_trigger_message_for_whole_charger() {
+        let mut handle = MockOcpp16NetworkInterfaceHandle::new();
+        handle
+            .expect_send_trigger_message()
+            .withf(|request| {
+                request.requested_message == MessageTrigger::Heartbeat
+                    && request.connector_id.is_none()
+            })
+            .times(1)
+            .returning(|_| {
+                Ok(Ok(TriggerMessageResponse {
+                    status: TriggerMessageStatus::NotImplemented,
+                }))
+            });
+
+        let mut charger = charger(handle, MockDataStore::new()).await;
+
+        let status = charger
+            .trigger_message(TriggerableMessage::Heartbeat, None)
+            .await
+            .unwrap();
+
+        assert_eq!(status, TriggerMessageStatus::NotImplemented);
+    }
+
+    #[tokio::test]
+    async fn test_trigger_message_for_unknown_evse() {
+        let mut charger = charger(
+            MockOcpp16NetworkInterfaceHandle::new(),
+            MockDataStore::new(),
+        )
+        .await;
+
+        let status = charger
+            .trigger_message(TriggerableMessage::MeterValues, Some(Uuid::new_v4()))
+            .await
+            .unwrap_err();
+
+        assert_eq!(status.code(), Code::NotFound);
+    }
+
+    #[tokio::test]
+    async fn test_trigger_diagnostics_status_on_ocpp_2_0_1() {
+        let mut handle = MockOcpp2_0_1NetworkInterfaceHandle::new();
+        handle
+            .expect_send_trigger_message()
+            .withf(|request| {
+                request.requested_message == MessageTriggerEnumType::LogStatusNotification
+                    && request.evse.as_ref().is_some_and(|evse| evse.id == 1)
+            })
+            .times(1)
+            .returning(|_| {
+                Ok(Ok(TriggerMessageResponse2_0_1 {
+                    status: TriggerMessageStatusEnumType::Rejected,
+                    status_info: None,
+                }))
+            });
+
+        let mut charger = ocpp_2_0_1_charger(handle, MockDataStore::new()).await;
+        let evse_id = charger.data.evses[0].id;
+
+        let status = charger
+            .trigger_message(
+                TriggerableMessage::DiagnosticsStatusNotification,
+                Some(evse_id),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(status, TriggerMessageStatus::Rejected);
+    }
+
+    #[tokio::test]
+    async fn test_certificate_signed() {
+        let mut handle = MockOcpp16NetworkInterfaceHandle::new();
+        handle
+            .expect_send_certificate_signed()
+            .withf(|request| request.certificate_chain == "chain")
+            .times(1)
+            .returning(|_| {
+                Ok(Ok(CertificateSignedResponse {
+                    status: CertificateSignedStatus::Accepted,
+                }))
+            });
+
+        let mut charger = charger(handle, MockDataStore::new()).await;
+
+        let status = charger
+            .certificate_signed("chain".to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(status, CertificateSignedStatus::Accepted);
+    }
+
+    #[tokio::test]
+    async fn test_extended_trigger_message_for_evse() {
+        let mut handle = MockOcpp16NetworkInterfaceHandle::new();
+        handle
+            .expect_send_extended_trigger_message()
+            .withf(|request| {
+                request.requested_message == ExtendedMessageTrigger::SignChargePointCertificate
+                    && request.connector_id == Some(1)
+            })
+            .times(1)
+            .returning(|_| {
+                Ok(Ok(ExtendedTriggerMessageResponse {
+                    status: TriggerMessageStatus::Accepted,
+                }))
+            });
+
+        let mut charger = charger(handle, MockDataStore::new()).await;
+        let evse_id = charger.data.evses[0].id;
+
+        let status = charger
+            .extended_trigger_message(
+                ExtendedMessageTrigger::SignChargePointCertificate,
+                Some(evse_id),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(status, TriggerMessageStatus::Accepted);
+    }
+
+    #[tokio::test]
+    async fn test_signed_firmware_uses_the_security_extension() {
+        let mut handle = MockOcpp16NetworkInterfaceHandle::new();
+        handle.expect_send_update_firmware().never();
+        handle
+            .expect_send_signed_update_firmware()
+            .withf(|request| {
+                request.firmware.location == "https://example.com/firmware.bin"
+                    && request.firmware.signing_certificate == "certificate"
+                    && request.firmware.signature == "signature"
+            })
+            .times(1)
+            .returning(|_| {
+                Ok(Ok(SignedUpdateFirmwareResponse {
+                    status: UpdateFirmwareStatusEnumType::Accepted,
+                }))
+            });
+        let mut data_store = MockDataStore::new();
+        data_store
+            .expect_save_firmware_update()
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let mut charger = charger(handle, data_store).await;
+
+        let firmware_update = charger
+            .update_firmware(
+                "https://example.com/firmware.bin",
+                None,
+                FirmwareUpdateOptions {
+                    signing_certificate: Some("certificate".to_string()),
+                    signature: Some("signature".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        assert!(firmware_update.ocpp_request_id.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_signed_firmware_with_invalid_certificate() {
+        let mut handle = MockOcpp16NetworkInterfaceHandle::new();
+        handle.expect_send_signed_update_firmware().returning(|_| {
+            Ok(Ok(SignedUpdateFirmwareResponse {
+                status: UpdateFirmwareStatusEnumType::InvalidCertificate,
+            }))
+        });
+        let mut data_store = MockDataStore::new();
+        data_store.expect_save_firmware_update().never();
+
+        let mut charger = charger(handle, data_store).await;
+
+        let status = charger
+            .update_firmware(
+                "https://example.com/firmware.bin",
+                None,
+                FirmwareUpdateOptions {
+                    signing_certificate: Some("certificate".to_string()),
+                    signature: Some("signature".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap_err();
+
+        assert_eq!(status.code(), Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn test_signature_without_certificate_is_refused() {
+        let mut charger = charger(
+            MockOcpp16NetworkInterfaceHandle::new(),
+            MockDataStore::new(),
+        )
+        .await;
+
+        let status = charger
+            .update_firmware(
+                "https://example.com/firmware.bin",
+                None,
+                FirmwareUpdateOptions {
+                    signature: Some("signature".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap_err();
+
+        assert_eq!(status.code(), Code::InvalidArgument);
     }
 }
@@ -1,9 +1,10 @@
 use crate::charger::Charger;
-use crate::charger::charger_model::ChargerModel;
+use crate::charger::vendor_profile::CredentialStrategy;
 use crate::network_interface::json::AuthenticationHandler;
 use poem::Response;
 use poem::http::StatusCode;
-use tracing::{error, warn};
+use shared::data::ClientCertificate;
+use tracing::{error, info, warn};
 
 #[async_trait::async_trait]
 impl AuthenticationHandler for Charger {
@@ -13,7 +14,17 @@ impl AuthenticationHandler for Charger {
     ) -> Result<(), Response> {
         self.password = password.clone();
 
-        if let Some(ChargerModel::Easee(_)) = self.model() {
+        if self.data.settings.require_client_certificate {
+            warn!(
+                charger_id = self.id.to_string(),
+                "The charger has to authenticate with a client certificate, but used a password"
+            );
+            return Err(Response::builder()
+                .status(StatusCode::FORBIDDEN)
+                .body("A client certificate is required".to_string()));
+        }
+
+        if self.vendor_profile().credential_strategy() == CredentialStrategy::EaseeMasterPassword {
             return match &self.easee_master_password {
                 Some(master_password) => {
                     if password.as_ref() == Some(master_password) {
@@ -92,4 +103,118 @@ impl AuthenticationHandler for Charger {
             }
         }
     }
+
+    async fn authenticate_with_certificate(
+        &mut self,
+        certificate: &ClientCertificate,
+    ) -> Result<(), Response> {
+        if certificate.common_name != self.id {
+            warn!(
+                charger_id = self.id.to_string(),
+                common_name = certificate.common_name,
+                "The client certificate was issued to another charger"
+            );
+            return Err(Response::builder()
+                .status(StatusCode::FORBIDDEN)
+                .body("The client certificate was issued to another charger".to_string()));
+        }
+
+        self.authenticated = true;
+        if self.data.client_certificate.as_ref() != Some(certificate) {
+            info!(
+                charger_id = self.id.to_string(),
+                serial_number = certificate.serial_number,
+                expires_at = certificate.expires_at.to_rfc3339(),
+                "Charger authenticated with a new client certificate"
+            );
+            self.data.client_certificate = Some(certificate.clone());
+            self.sync_data().await.map_err(|e| {
+                error!(
+                    error_message = e.to_string(),
+                    "Failed to save client certificate"
+                );
+                Response::builder()
+                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                    .body("Failed to validate credentials".to_string())
+            })?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::charger::test_charger::charger;
+    use crate::network_interface::MockOcpp16NetworkInterfaceHandle;
+    use chrono::{TimeZone, Utc};
+    use shared::data_store::MockDataStore;
+
+    fn client_certificate(common_name: &str) -> ClientCertificate {
+        ClientCertificate {
+            common_name: common_name.to_string(),
+            serial_number: "1234abcd".to_string(),
+            expires_at: Utc.with_ymd_and_hms(2030, 1, 1, 0, 0, 0).unwrap(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_certificate_of_the_charger_is_accepted_and_stored() {
+        let mut data_store = MockDataStore::new();
+        data_store
+            .expect_save_charger_data()
+            .times(1)
+            .returning(|_| Ok(()));
+        let mut charger = charger(MockOcpp16NetworkInterfaceHandle::new(), data_store).await;
+        charger.authenticated = false;
+
+        charger
+            .authenticate_with_certificate(&client_certificate("test"))
+            .await
+            .unwrap();
+
+        assert!(charger.authenticated);
+        assert_eq!(
+            charger.data.client_certificate,
+            Some(client_certificate("test"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_certificate_of_another_charger_is_rejected() {
+        let mut charger = charger(
+            MockOcpp16NetworkInterfaceHandle::new(),
+            MockDataStore::new(),
+        )
+        .await;
+        charger.authenticated = false;
+
+        let response = charger
+            .authenticate_with_certificate(&client_certificate("other"))
+            .await
+            .unwrap_err();
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        assert!(!charger.authenticated);
+        assert_eq!(charger.data.client_certificate, None);
+    }
+
+    #[tokio::test]
+    async fn test_password_is_rejected_when_a_certificate_is_required() {
+        let mut charger = charger(
+            MockOcpp16NetworkInterfaceHandle::new(),
+            MockDataStore::new(),
+        )
+        .await;
+        charger.authenticated = false;
+        charger.data.settings.require_client_certificate = true;
+
+        let response = charger
+            .authenticate_with_password(&Some("password".to_string()))
+            .await
+            .unwrap_err();
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        assert!(!charger.authenticated);
+    }
 }
@@ -1,4 +1,5 @@
 use crate::charger::{Charger, ChargerPool};
+use crate::data_transfer::DataTransferRegistry;
 use crate::event::EventManager;
 use crate::network_interface::ProtocolHandle;
 use crate::ocpp_csms_server_client::csms_server_client_client::CsmsServerClientClient;
@@ -17,9 +18,11 @@ pub struct ChargerFactory {
     event_manager: EventManager,
     charger_pool: ChargerPool,
     csms_server_client: Option<CsmsServerClientClient<Channel>>,
+    data_transfer_registry: DataTransferRegistry,
 }
 
 impl ChargerFactory {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         config: Arc<Config>,
         data_store: Arc<dyn DataStore + Send + Sync>,
@@ -28,6 +31,7 @@ impl ChargerFactory {
         event_manager: &EventManager,
         charger_pool: &ChargerPool,
         csms_server_client: &Option<CsmsServerClientClient<Channel>>,
+        data_transfer_registry: &DataTransferRegistry,
     ) -> Self {
         Self {
             config,
@@ -37,6 +41,7 @@ impl ChargerFactory {
             event_manager: event_manager.clone(),
             charger_pool: charger_pool.clone(),
             csms_server_client: csms_server_client.clone(),
+            data_transfer_registry: data_transfer_registry.clone(),
         }
     }
 }
@@ -57,6 +62,7 @@ impl crate::network_interface::ChargerFactory<Charger> for ChargerFactory {
             self.easee_master_password.clone(),
             self.event_manager.clone(),
             self.csms_server_client.clone(),
+            self.data_transfer_registry.clone(),
         )
         .await
     }
@@ -65,9 +71,18 @@ impl crate::network_interface::ChargerFactory<Charger> for ChargerFactory {
         &self,
         charger: &Arc<Mutex<Charger>>,
     ) -> Result<(), Box<dyn Error + Send + Sync>> {
-        self.charger_pool
-            .insert(&charger.lock().await.id, charger)
-            .await;
+        let id = charger.lock().await.id.clone();
+        self.charger_pool.insert(&id, charger).await;
+        self.data_store
+            .update_charger_connection_info(&id, true, &self.node_address)
+            .await?;
+        Ok(())
+    }
+
+    async fn on_seen(&self, charger_id: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.data_store
+            .update_charger_connection_info(charger_id, true, &self.node_address)
+            .await?;
         Ok(())
     }
 
@@ -75,7 +90,85 @@ impl crate::network_interface::ChargerFactory<Charger> for ChargerFactory {
         &self,
         charger: &Arc<Mutex<Charger>>,
     ) -> Result<(), Box<dyn Error + Send + Sync>> {
-        self.charger_pool.remove(&charger.lock().await.id).await;
+        let id = charger.lock().await.id.clone();
+        if self.charger_pool.remove(&id, charger).await {
+            self.data_store
+                .update_charger_connection_info(&id, false, &self.node_address)
+                .await?;
+        }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::charger::test_charger::charger;
+    use crate::network_interface::ChargerFactory as _;
+    use crate::network_interface::MockOcpp16NetworkInterfaceHandle;
+    use shared::data_store::MockDataStore;
+
+    async fn charger_factory(data_store: MockDataStore) -> ChargerFactory {
+        let config = Arc::new(Config::default());
+        ChargerFactory::new(
+            Arc::clone(&config),
+            Arc::new(data_store),
+            "http://localhost:50051",
+            None,
+            &EventManager::from_config(&config).await.unwrap(),
+            &ChargerPool::new(),
+            &None,
+            &DataTransferRegistry::new(),
+        )
+    }
+
+    async fn connected_charger() -> Arc<Mutex<Charger>> {
+        Arc::new(Mutex::new(
+            charger(
+                MockOcpp16NetworkInterfaceHandle::new(),
+                MockDataStore::new(),
+            )
+            .await,
+        ))
+    }
+
+    fn expect_connection_info(data_store: &mut MockDataStore, is_online: bool, times: usize) {
+        data_store
+            .expect_update_charger_connection_info()
+            .withf(move |id, online, node_address| {
+                id == "test" && *online == is_online && node_address == "http://localhost:50051"
+            })
+            .times(times)
+            .returning(|_, _, _| Ok(()));
+    }
+
+    #[tokio::test]
+    async fn test_disconnected_charger_is_marked_offline() {
+        let mut data_store = MockDataStore::new();
+        expect_connection_info(&mut data_store, true, 1);
+        expect_connection_info(&mut data_store, false, 1);
+        let charger_factory = charger_factory(data_store).await;
+        let charger = connected_charger().await;
+
+        charger_factory.on_connected(&charger).await.unwrap();
+        charger_factory.on_disconnected(&charger).await.unwrap();
+
+        assert!(charger_factory.charger_pool.get("test").await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_replaced_charger_does_not_mark_its_successor_offline() {
+        let mut data_store = MockDataStore::new();
+        expect_connection_info(&mut data_store, true, 2);
+        expect_connection_info(&mut data_store, false, 0);
+        let charger_factory = charger_factory(data_store).await;
+        let previous = connected_charger().await;
+        let current = connected_charger().await;
+
+        charger_factory.on_connected(&previous).await.unwrap();
+        charger_factory.on_connected(&current).await.unwrap();
+        charger_factory.on_disconnected(&previous).await.unwrap();
+
+        assert!(charger_factory.charger_pool.get("test").await.is_some());
+    }
+}
@@ -1,6 +1,10 @@
 use crate::charger::Charger;
-use crate::charger::charger_model::ChargerModel;
-use crate::charger::ocpp1_6::{create_transaction_id, update_charger_from_meter_values_request};
+use crate::charger::ocpp1_6::{
+    accumulate_delivered_energy, create_transaction_meter_values, energy_register_readings,
+    update_charger_from_meter_values_request,
+};
+use crate::charger::vendor_profile::CredentialStrategy;
+use crate::data_transfer::DataTransferRegistry;
 use crate::event;
 use crate::event::{
     EventPayload, EvseInfo, TransactionEvent, TransactionEventTriggerReason, TransactionEventType,
@@ -10,6 +14,20 @@ use crate::network_interface::Ocpp16RequestReceiver;
 use crate::ocpp_csms_server_client;
 use crate::ocpp_csms_server_client::authorize_request::Authorization;
 use crate::ocpp_csms_server_client::authorize_response;
+use crate::ocpp_csms_server_client::sign_certificate_response;
+use crate::ocpp1_6_security::messages::log_status_notification::{
+    LogStatusNotificationRequest, LogStatusNotificationResponse,
+};
+use crate::ocpp1_6_security::messages::security_event_notification::{
+    SecurityEventNotificationRequest, SecurityEventNotificationResponse,
+};
+use crate::ocpp1_6_security::messages::sign_certificate::{
+    SignCertificateRequest, SignCertificateResponse,
+};
+use crate::ocpp1_6_security::messages::signed_firmware_status_notification::{
+    SignedFirmwareStatusNotificationRequest, SignedFirmwareStatusNotificationResponse,
+};
+use crate::ocpp1_6_security::types::GenericStatus;
 use bcrypt::DEFAULT_COST;
 use chrono::{DateTime, TimeZone, Utc};
 use ocpp_client::ocpp_1_6::OCPP1_6Error;
@@ -21,7 +39,6 @@ use rust_ocpp::v1_6::messages::boot_notification::{
 };
 use rust_ocpp::v1_6::messages::cancel_reservation::CancelReservationRequest;
 use rust_ocpp::v1_6::messages::change_configuration::ChangeConfigurationRequest;
-use rust_ocpp::v1_6::messages::data_transfer::{DataTransferRequest, DataTransferResponse};
 use rust_ocpp::v1_6::messages::diagnostics_status_notification::{
     DiagnosticsStatusNotificationRequest, DiagnosticsStatusNotificationResponse,
 };
@@ -43,11 +60,14 @@ use rust_ocpp::v1_6::messages::stop_transaction::{
 };
 use rust_ocpp::v1_6::messages::trigger_message::TriggerMessageRequest;
 use rust_ocpp::v1_6::types::{
-    AuthorizationStatus, ChargePointStatus, ConfigurationStatus, DataTransferStatus, IdTagInfo,
-    Measurand, MessageTrigger, RegistrationStatus, ResetRequestStatus, ResetResponseStatus,
+    AuthorizationStatus, ChargePointStatus, ConfigurationStatus, IdTagInfo, Measurand,
+    MessageTrigger, MeterValue, RegistrationStatus, ResetRequestStatus, ResetResponseStatus,
     TriggerMessageStatus,
 };
-use shared::data::{ConnectorData, ConnectorStatus, ConnectorType, EvseData, Ocpp1_6Configuration};
+use shared::data::{
+    ConnectorData, ConnectorStatus, ConnectorType, EvseData, FirmwareUpdateStatus, Metric,
+    Ocpp1_6Configuration, Transaction,
+};
 use std::error::Error;
 use tracing::{error, info, warn};
 use uuid::Uuid;
@@ -186,15 +206,71 @@ impl Charger {
         }
     }
 
+    pub async fn save_transaction_meter_values_ocpp_1_6(
+        &self,
+        transaction: &Transaction,
+        meter_values: &[MeterValue],
+    ) -> Result<(), OCPP1_6Error> {
+        let meter_values = create_transaction_meter_values(transaction, meter_values);
+        if meter_values.is_empty() {
+            return Ok(());
+        }
+
+        self.data_store
+            .save_transaction_meter_values(&meter_values)
+            .await
+            .map_err(|error| {
+                error!(
+                    error_message = error.to_string(),
+                    "Failed to save transaction meter values"
+                );
+                OCPP1_6Error::new_internal(&error)
+            })
+    }
+
+    /// Adds the energy between the register readings and the last known reading to the energy
+    /// delivered in the transaction
+    pub async fn update_delivered_energy_ocpp_1_6(
+        &self,
+        transaction: &Transaction,
+        readings: Vec<(DateTime<Utc>, i32)>,
+    ) -> Result<(), OCPP1_6Error> {
+        let last_reading = transaction.last_energy_meter_reading.clone().or_else(|| {
+            transaction.energy_meter_at_start.map(|value| Metric {
+                value,
+                measured_at: Some(transaction.start_time),
+            })
+        });
+        let (watt_charged, last_reading) =
+            accumulate_delivered_energy(transaction.watt_charged, last_reading, readings);
+
+        match last_reading {
+            Some(last_reading) => self
+                .data_store
+                .update_transaction_delivered_energy(transaction.id, watt_charged, &last_reading)
+                .await
+                .map_err(|error| {
+                    error!(
+                        error_message = error.to_string(),
+                        "Failed to update delivered energy"
+                    );
+                    OCPP1_6Error::new_internal(&error)
+                }),
+            None => Ok(()),
+        }
+    }
+
     pub async fn send_meter_values_event_ocpp_1_6(
         &mut self,
         request: &MeterValuesRequest,
     ) -> Result<(), OCPP1_6Error> {
         if let Some(transaction_id) = request.transaction_id {
+            // Adopted transactions without a known evse were never announced
             if let Some(transaction) = self
                 .transaction_by_ocpp_id(transaction_id)
                 .await
                 .map_err(|e| OCPP1_6Error::new_internal(&e))?
+                && let Some(evse_id) = transaction.evse_id
             {
                 let payload = EventPayload::TransactionEvent(TransactionEvent {
                     charger_id: self.id.to_string(),
@@ -211,7 +287,7 @@ impl Charger {
                         stopped_reason: None,
                     },
                     evse: EvseInfo {
-                        id: transaction.evse_id,
+                        id: evse_id,
                         connector_id: Default::default(),
                     },
                     meter_values: request
@@ -304,8 +380,13 @@ impl Charger {
         &mut self,
         transaction_started_at: Option<DateTime<Utc>>,
         ocpp_evse_id: u32,
-    ) -> Result<(), OCPP1_6Error> {
-        let transaction_id = create_transaction_id();
+        is_authorized: bool,
+    ) -> Result<Transaction, OCPP1_6Error> {
+        let transaction_id = self
+            .data_store
+            .next_ocpp_transaction_id(&self.id)
+            .await
+            .map_err(|e| OCPP1_6Error::new_internal(&e))?;
         let evse = self.data.evse_by_ocpp_id_or_create(ocpp_evse_id);
         let connector = evse.connector_by_ocpp_id(1).ok_or_else(|| {
             OCPP1_6Error::new_internal_str("Connector with ID 1 not found for EVSE")
@@ -316,10 +397,10 @@ impl Charger {
             .data_store
             .create_transaction(
                 &self.id,
-                evse.id,
+                Some(evse.id),
                 &transaction_id.to_string(),
                 transaction_started_at,
-                false,
+                is_authorized,
             )
             .await
             .map_err(|e| OCPP1_6Error::new_internal(&e))?;
@@ -331,13 +412,94 @@ impl Charger {
                     evse_id: evse.id,
                     connector_id: connector.id,
                     transaction_id: transaction.id,
-                    authenticated: !self.data.settings.authorize_transactions,
+                    authenticated: is_authorized || !self.data.settings.authorize_transactions,
                     started_at: transaction_started_at,
                 },
             ))
             .await;
 
-        Ok(())
+        Ok(transaction)
+    }
+
+    /// Records a transaction the charger stopped without the start ever reaching us, e.g. when
+    /// the StartTransaction was lost while the charger was offline
+    async fn create_unseen_transaction_ocpp_1_6(
+        &mut self,
+        request: &StopTransactionRequest,
+        id_tag_info: Option<&IdTagInfo>,
+    ) -> Result<Transaction, OCPP1_6Error> {
+        warn!(
+            charger_id = self.id,
+            transaction_id = request.transaction_id,
+            "StopTransaction for a transaction that was never started, recording it"
+        );
+
+        // The stop doesn't say which connector was used, which is only unambiguous for chargers
+        // with a single evse
+        let evse = match self.data.evses.as_slice() {
+            [evse] => evse
+                .connector_by_ocpp_id(1)
+                .map(|connector| (evse.id, connector.id)),
+            _ => None,
+        };
+        let started_at = request
+            .transaction_data
+            .iter()
+            .flatten()
+            .map(|meter_value| meter_value.timestamp)
+            .min()
+            .unwrap_or(request.timestamp);
+        let is_authorized = id_tag_info
+            .is_some_and(|id_tag_info| id_tag_info.status == AuthorizationStatus::Accepted);
+
+        // The charger picked this id, so it must never be handed out for a later transaction.
+        // Reserved first, a failed reservation leaves nothing behind when the charger retries
+        self.data_store
+            .reserve_ocpp_transaction_id(&self.id, request.transaction_id)
+            .await
+            .map_err(|e| OCPP1_6Error::new_internal(&e))?;
+        let transaction = self
+            .data_store
+            .create_transaction(
+                &self.id,
+                evse.map(|(evse_id, _)| evse_id),
+                &request.transaction_id.to_string(),
+                started_at,
+                is_authorized,
+            )
+            .await
+            .map_err(|e| OCPP1_6Error::new_internal(&e))?;
+        if let Some(tag) = &request.id_tag {
+            self.data_store
+                .update_transaction_id_tag(transaction.id, tag, None)
+                .await
+                .map_err(|e| OCPP1_6Error::new_internal(&e))?;
+        }
+
+        match evse {
+            Some((evse_id, connector_id)) => {
+                self.event_manager
+                    .send_event(EventPayload::TransactionStartedEvent(
+                        TransactionStartedEvent {
+                            charger_id: self.id.to_string(),
+                            evse_id,
+                            connector_id,
+                            transaction_id: transaction.id,
+                            authenticated: is_authorized
+                                || !self.data.settings.authorize_transactions,
+                            started_at,
+                        },
+                    ))
+                    .await
+            }
+            None => warn!(
+                charger_id = self.id,
+                transaction_id = request.transaction_id,
+                "could not determine the evse of the transaction, not announcing its start"
+            ),
+        }
+
+        Ok(transaction)
     }
 
     pub async fn stop_transaction_ocpp_1_6(
@@ -352,18 +514,21 @@ impl Charger {
             .map_err(|e| OCPP1_6Error::new_internal(&e))?
             .ok_or_else(|| OCPP1_6Error::new_internal_str("Transaction not found"))?;
 
-        self.event_manager
-            .send_event(EventPayload::TransactionStoppedEvent(
-                TransactionStoppedEvent {
-                    charger_id: self.id.to_string(),
-                    evse_id: transaction.evse_id,
-                    connector_id: Default::default(),
-                    transaction_id: transaction.id,
-                    started_at: transaction.start_time,
-                    stopped_at,
-                },
-            ))
-            .await;
+        // The start of a transaction without a known evse was never announced either
+        if let Some(evse_id) = transaction.evse_id {
+            self.event_manager
+                .send_event(EventPayload::TransactionStoppedEvent(
+                    TransactionStoppedEvent {
+                        charger_id: self.id.to_string(),
+                        evse_id,
+                        connector_id: Default::default(),
+                        transaction_id: transaction.id,
+                        started_at: transaction.start_time,
+                        stopped_at,
+                    },
+                ))
+                .await;
+        }
 
         Ok(())
     }
@@ -415,11 +580,7 @@ impl Ocpp16RequestReceiver for Charger {
                     );
 
                     if let Some(conf) = &self.data.ocpp1_6configuration {
-                        let num_outlets_raw = conf
-                            .get_configuration("NumberOfConnectors")
-                            .and_then(|i| i.value.clone())
-                            .unwrap_or("1".to_string());
-                        let num_outlets = num_outlets_raw.parse::<usize>()?;
+                        let num_outlets = self.vendor_profile().number_of_evses(conf)?;
                         if self.data.evses.len() < num_outlets {
                             for index in 1..=num_outlets {
                                 if !self
@@ -453,7 +614,26 @@ impl Ocpp16RequestReceiver for Charger {
                         );
                     }
 
-                    self.update_ocpp_1_6_charger_configuration().await?;
+                    let desired_configuration = self.desired_ocpp_1_6_configuration();
+                    let results = self.update_ocpp_1_6_charger_configuration().await?;
+                    if let Err(err) = self
+                        .record_ocpp_1_6_configuration_snapshot(&desired_configuration, &results)
+                        .await
+                    {
+                        error!(
+                            error_message = err.to_string(),
+                            "Failed to record configuration snapshot"
+                        );
+                    }
+
+                    if self.authenticated
+                        && let Err(err) = self.reconcile_ocpp_1_6_charging_profiles().await
+                    {
+                        warn!(
+                            error_message = err.to_string(),
+                            "Failed to reconcile charging profiles"
+                        );
+                    }
                 }
                 Err(err) => {
                     warn!(
@@ -463,7 +643,9 @@ impl Ocpp16RequestReceiver for Charger {
                 }
             }
             if !self.authenticated {
-                if let Some(ChargerModel::Easee(_)) = self.model() {
+                if self.vendor_profile().credential_strategy()
+                    == CredentialStrategy::EaseeMasterPassword
+                {
                     warn!(
                         "Easee chargers uses a master password, it will be matched during next reconnect"
                     );
@@ -576,6 +758,7 @@ impl Ocpp16RequestReceiver for Charger {
         self.data.iccid = request.iccid;
         self.data.imsi = request.imsi;
         self.data.model = Some(request.charge_point_model);
+        self.update_vendor_profile();
 
         if let Err(err) = self.sync_data().await {
             error!(
@@ -584,6 +767,13 @@ impl Ocpp16RequestReceiver for Charger {
             );
         }
 
+        if let Err(err) = self.confirm_firmware_update().await {
+            error!(
+                error_message = err.to_string(),
+                "Failed to confirm firmware update"
+            );
+        }
+
         if self.authenticated {
             Ok(BootNotificationResponse {
                 current_time: Utc::now(),
@@ -599,14 +789,8 @@ impl Ocpp16RequestReceiver for Charger {
         }
     }
 
-    async fn data_transfer(
-        &mut self,
-        _request: DataTransferRequest,
-    ) -> Result<DataTransferResponse, OCPP1_6Error> {
-        Ok(DataTransferResponse {
-            status: DataTransferStatus::Rejected,
-            data: None,
-        })
+    fn data_transfer_registry(&self) -> DataTransferRegistry {
+        self.data_transfer_registry.clone()
     }
 
     async fn diagnostics_status_notification(
@@ -618,8 +802,16 @@ impl Ocpp16RequestReceiver for Charger {
 
     async fn firmware_status_notification(
         &mut self,
-        _request: FirmwareStatusNotificationRequest,
+        request: FirmwareStatusNotificationRequest,
     ) -> Result<FirmwareStatusNotificationResponse, OCPP1_6Error> {
+        if let Some(status) = FirmwareUpdateStatus::from_ocpp_1_6(request.status)
+            && let Err(err) = self.record_firmware_update_status(status, None).await
+        {
+            error!(
+                error_message = err.to_string(),
+                "Failed to record firmware status"
+            );
+        }
         Ok(FirmwareStatusNotificationResponse {})
     }
 
@@ -632,10 +824,25 @@ impl Ocpp16RequestReceiver for Charger {
         })
     }
 
+    async fn log_status_notification(
+        &mut self,
+        request: LogStatusNotificationRequest,
+    ) -> Result<LogStatusNotificationResponse, OCPP1_6Error> {
+        info!(
+            charger_id = self.id,
+            status = ?request.status,
+            request_id = request.request_id,
+            "Log upload status changed"
+        );
+        Ok(LogStatusNotificationResponse {})
+    }
+
     async fn meter_values(
         &mut self,
-        request: MeterValuesRequest,
+        mut request: MeterValuesRequest,
     ) -> Result<MeterValuesResponse, OCPP1_6Error> {
+        self.vendor_profile()
+            .normalize_meter_values(&mut request.meter_value);
         update_charger_from_meter_values_request(&mut self.data, &request)?;
         self.sync_data().await.map_err(|error| {
             error!(
@@ -645,16 +852,105 @@ impl Ocpp16RequestReceiver for Charger {
             OCPP1_6Error::new_internal(&error)
         })?;
 
+        if let Some(transaction_id) = request.transaction_id
+            && let Some(transaction) = self
+                .transaction_by_ocpp_id(transaction_id)
+                .await
+                .map_err(|e| OCPP1_6Error::new_internal(&e))?
+        {
+            self.save_transaction_meter_values_ocpp_1_6(&transaction, &request.meter_value)
+                .await?;
+            self.update_delivered_energy_ocpp_1_6(
+                &transaction,
+                energy_register_readings(&request.meter_value),
+            )
+            .await?;
+        }
+
         self.send_meter_values_event_ocpp_1_6(&request).await?;
 
         Ok(MeterValuesResponse {})
     }
 
+    async fn security_event_notification(
+        &mut self,
+        request: SecurityEventNotificationRequest,
+    ) -> Result<SecurityEventNotificationResponse, OCPP1_6Error> {
+        warn!(
+            charger_id = self.id,
+            kind = request.kind,
+            timestamp = request.timestamp.to_rfc3339(),
+            tech_info = request.tech_info,
+            "Charger reported a security event"
+        );
+        Ok(SecurityEventNotificationResponse {})
+    }
+
+    async fn sign_certificate(
+        &mut self,
+        request: SignCertificateRequest,
+    ) -> Result<SignCertificateResponse, OCPP1_6Error> {
+        let Some(mut client) = self.csms_server_client.clone() else {
+            warn!(
+                charger_id = self.id,
+                "Rejected certificate signing request, no CSMS server client is set to sign it"
+            );
+            return Ok(SignCertificateResponse {
+                status: GenericStatus::Rejected,
+            });
+        };
+
+        let response = client
+            .sign_certificate(ocpp_csms_server_client::SignCertificateRequest {
+                charger_id: self.id.clone(),
+                csr: request.csr,
+            })
+            .await
+            .map_err(|e| {
+                warn!(
+                    charger_id = self.id,
+                    error_message = e.to_string(),
+                    "failed to relay certificate signing request"
+                );
+                OCPP1_6Error::new_internal(&e)
+            })?;
+
+        let status = match sign_certificate_response::SignCertificateStatus::try_from(
+            response.into_inner().status,
+        )
+        .unwrap_or_default()
+        {
+            sign_certificate_response::SignCertificateStatus::Accepted => GenericStatus::Accepted,
+            sign_certificate_response::SignCertificateStatus::Unspecified
+            | sign_certificate_response::SignCertificateStatus::Rejected => GenericStatus::Rejected,
+        };
+
+        Ok(SignCertificateResponse { status })
+    }
+
+    async fn signed_firmware_status_notification(
+        &mut self,
+        request: SignedFirmwareStatusNotificationRequest,
+    ) -> Result<SignedFirmwareStatusNotificationResponse, OCPP1_6Error> {
+        if let Some(status) = FirmwareUpdateStatus::from_ocpp_2_0_1(request.status)
+            && let Err(err) = self
+                .record_firmware_update_status(status, request.request_id)
+                .await
+        {
+            error!(
+                error_message = err.to_string(),
+                "Failed to record firmware status"
+            );
+        }
+        Ok(SignedFirmwareStatusNotificationResponse {})
+    }
+
     async fn start_transaction(
         &mut self,
         request: StartTransactionRequest,
     ) -> Result<StartTransactionResponse, OCPP1_6Error> {
         let tag = request.id_tag;
+        let id_tag_info = self.validate_rfid_tag_ocpp_1_6(&tag).await?;
 
         let transaction = self
             .get_ongoing_transaction(request.connector_id)
@@ -663,23 +959,58 @@ impl Ocpp16RequestReceiver for Charger {
                 OCPP1_6Error::new_internal_str("Could not get transaction from database")
             })?;
 
-        if let Some(transaction) = &transaction {
-            let meter_start = request.meter_start;
+        // Sessions started on the charger itself, e.g. plug first or while offline, have no
+        // transaction yet, so the id is assigned here
+        let transaction = match transaction {
+            Some(transaction) => transaction,
+            None => {
+                info!(
+                    charger_id = self.id,
+                    connector_id = request.connector_id,
+                    "no ongoing transaction for StartTransaction, creating one"
+                );
+                self.start_transaction_ocpp_1_6(
+                    Some(request.timestamp),
+                    request.connector_id,
+                    id_tag_info.status == AuthorizationStatus::Accepted,
+                )
+                .await?
+            }
+        };
+
+        // Queued StartTransaction messages can be replayed long after the session began
+        if request.timestamp < transaction.start_time {
             self.data_store
-                .update_transaction_meter_start(transaction.id, meter_start)
+                .update_transaction_start_time(transaction.id, request.timestamp)
                 .await
-                .map_err(|e| {
-                    error!(
-                        error_message = e.to_string(),
-                        "Failed to update transaction"
-                    );
-                    OCPP1_6Error::new_internal_str("Failed to update transaction")
-                })?;
+                .map_err(|e| OCPP1_6Error::new_internal(&e))?;
         }
 
+        let meter_start = request.meter_start;
+        self.data_store
+            .update_transaction_meter_start(transaction.id, meter_start)
+            .await
+            .map_err(|e| {
+                error!(
+                    error_message = e.to_string(),
+                    "Failed to update transaction"
+                );
+                OCPP1_6Error::new_internal_str("Failed to update transaction")
+            })?;
+        self.data_store
+            .update_transaction_id_tag(transaction.id, &tag, request.reservation_id)
+            .await
+            .map_err(|e| {
+                error!(
+                    error_message = e.to_string(),
+                    "Failed to update transaction"
+                );
+                OCPP1_6Error::new_internal_str("Failed to update transaction")
+            })?;
+
         let transaction_id = transaction
-            .map(|t| t.ocpp_transaction_id.parse::<i32>())
-            .unwrap_or(Ok(0))
+            .ocpp_transaction_id
+            .parse::<i32>()
             .map_err(|e| {
                 error!(
                     error_message = e.to_string(),
@@ -689,7 +1020,7 @@ impl Ocpp16RequestReceiver for Charger {
             })?;
 
         Ok(StartTransactionResponse {
-            id_tag_info: self.validate_rfid_tag_ocpp_1_6(&tag).await?,
+            id_tag_info,
             transaction_id,
         })
     }
@@ -718,7 +1049,7 @@ impl Ocpp16RequestReceiver for Charger {
             }
 
             if request.status == ChargePointStatus::Preparing {
-                self.start_transaction_ocpp_1_6(request.timestamp, request.connector_id)
+                self.start_transaction_ocpp_1_6(request.timestamp, request.connector_id, false)
                     .await?;
             }
 
@@ -772,24 +1103,73 @@ impl Ocpp16RequestReceiver for Charger {
 
     async fn stop_transaction(
         &mut self,
-        request: StopTransactionRequest,
+        mut request: StopTransactionRequest,
     ) -> Result<StopTransactionResponse, OCPP1_6Error> {
-        self.data_store
-            .update_transaction_watt_charged(
-                &self.id,
-                &request.transaction_id.to_string(),
-                request.meter_stop,
-            )
+        if let Some(transaction_data) = request.transaction_data.as_mut() {
+            self.vendor_profile()
+                .normalize_meter_values(transaction_data);
+        }
+        let id_tag_info = match &request.id_tag {
+            Some(tag) => Some(self.validate_rfid_tag_ocpp_1_6(tag).await?),
+            None => None,
+        };
+
+        let transaction = match self
+            .transaction_by_ocpp_id(request.transaction_id)
             .await
-            .map_err(|e| OCPP1_6Error::new_internal(&e))?;
+            .map_err(|e| OCPP1_6Error::new_internal(&e))?
+        {
+            Some(transaction) => transaction,
+            None => {
+                self.create_unseen_transaction_ocpp_1_6(&request, id_tag_info.as_ref())
+                    .await?
+            }
+        };
+
+        // Meter values recorded while the charger was offline are only sent with the stop
+        if let Some(transaction_data) = &request.transaction_data {
+            self.save_transaction_meter_values_ocpp_1_6(&transaction, transaction_data)
+                .await?;
 
-        if let Some(tag) = request.id_tag {
-            Ok(StopTransactionResponse {
-                id_tag_info: Some(self.validate_rfid_tag_ocpp_1_6(&tag).await?),
+            let connector_id = transaction
+                .evse_id
+                .and_then(|evse_id| self.data.evse(evse_id))
+                .map(|evse| evse.ocpp_evse_id)
+                .unwrap_or_default();
+            self.send_meter_values_event_ocpp_1_6(&MeterValuesRequest {
+                connector_id,
+                transaction_id: Some(request.transaction_id),
+                meter_value: transaction_data.clone(),
             })
-        } else {
-            Ok(StopTransactionResponse { id_tag_info: None })
+            .await?;
         }
+
+        let mut readings = request
+            .transaction_data
+            .as_deref()
+            .map(energy_register_readings)
+            .unwrap_or_default();
+        readings.push((request.timestamp, request.meter_stop));
+        self.update_delivered_energy_ocpp_1_6(&transaction, readings)
+            .await?;
+
+        match transaction.end_time {
+            None => {
+                self.stop_transaction_ocpp_1_6(request.transaction_id, request.timestamp)
+                    .await?;
+            }
+            // The transaction was ended by a status notification, which might have been
+            // received long after the charger stopped the transaction
+            Some(end_time) if request.timestamp < end_time => {
+                self.data_store
+                    .update_transaction_end_time(transaction.id, request.timestamp)
+                    .await
+                    .map_err(|e| OCPP1_6Error::new_internal(&e))?;
+            }
+            Some(_) => {}
+        }
+
+        Ok(StopTransactionResponse { id_tag_info })
     }
 }
 
@@ -816,3 +1196,360 @@ impl From<authorize_response::AuthorizationStatus> for AuthorizationStatus {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::charger::test_charger::charger;
+    use crate::network_interface::MockOcpp16NetworkInterfaceHandle;
+    use chrono::Duration;
+    use shared::data_store::MockDataStore;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Default)]
+    struct RecordingHandler {
+        events: Arc<Mutex<Vec<EventPayload>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::event::EventHandler for RecordingHandler {
+        async fn send_event(&self, payload: EventPayload) {
+            self.events.lock().unwrap().push(payload);
+        }
+    }
+
+    fn transaction(ocpp_transaction_id: &str) -> Transaction {
+        Transaction {
+            id: Uuid::new_v4(),
+            charger_id: "test".to_string(),
+            evse_id: Some(Uuid::new_v4()),
+            ocpp_transaction_id: ocpp_transaction_id.to_string(),
+            start_time: Utc::now() - Duration::minutes(5),
+            end_time: None,
+            energy_meter_at_start: None,
+            last_energy_meter_reading: None,
+            watt_charged: 0,
+            is_authorized: true,
+            id_tag: None,
+            ocpp_reservation_id: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sign_certificate_without_client_is_rejected() {
+        let mut charger = charger(
+            MockOcpp16NetworkInterfaceHandle::new(),
+            MockDataStore::new(),
+        )
+        .await;
+
+        let response = charger
+            .sign_certificate(SignCertificateRequest {
+                csr: "csr".to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, GenericStatus::Rejected);
+    }
+
+    #[tokio::test]
+    async fn test_start_transaction_stores_id_tag_and_reservation() {
+        let ongoing = transaction("7");
+        let transaction_id = ongoing.id;
+        let mut data_store = MockDataStore::new();
+        data_store
+            .expect_get_ongoing_rfid_scanning_session()
+            .returning(|_| Ok(None));
+        data_store
+            .expect_get_ongoing_transaction()
+            .times(1)
+            .returning(move |_, _| Ok(Some(ongoing.clone())));
+        data_store
+            .expect_update_transaction_meter_start()
+            .withf(move |id, meter_start| *id == transaction_id && *meter_start == 1200)
+            .times(1)
+            .returning(|_, _| Ok(()));
+        data_store
+            .expect_update_transaction_id_tag()
+            .withf(move |id, id_tag, reservation_id| {
+                *id == transaction_id && id_tag == "tag" && *reservation_id == Some(3)
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let mut charger = charger(MockOcpp16NetworkInterfaceHandle::new(), data_store).await;
+        charger.data.settings.authorize_transactions = false;
+
+        let response = Ocpp16RequestReceiver::start_transaction(
+            &mut charger,
+            StartTransactionRequest {
+                connector_id: 1,
+                id_tag: "tag".to_string(),
+                meter_start: 1200,
+                reservation_id: Some(3),
+                timestamp: Utc::now(),
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(response.transaction_id, 7);
+        assert_eq!(response.id_tag_info.status, AuthorizationStatus::Accepted);
+    }
+
+    #[tokio::test]
+    async fn test_start_transaction_without_reservation_clears_it() {
+        let ongoing = transaction("7");
+        let mut data_store = MockDataStore::new();
+        data_store
+            .expect_get_ongoing_rfid_scanning_session()
+            .returning(|_| Ok(None));
+        data_store
+            .expect_get_ongoing_transaction()
+            .returning(move |_, _| Ok(Some(ongoing.clone())));
+        data_store
+            .expect_update_transaction_meter_start()
+            .returning(|_, _| Ok(()));
+        data_store
+            .expect_update_transaction_id_tag()
+            .withf(|_, id_tag, reservation_id| id_tag == "tag" && reservation_id.is_none())
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let mut charger = charger(MockOcpp16NetworkInterfaceHandle::new(), data_store).await;
+        charger.data.settings.authorize_transactions = false;
+
+        Ocpp16RequestReceiver::start_transaction(
+            &mut charger,
+            StartTransactionRequest {
+                connector_id: 1,
+                id_tag: "tag".to_string(),
+                meter_start: 0,
+                reservation_id: None,
+                timestamp: Utc::now(),
+            },
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_start_transaction_allocates_the_next_transaction_id() {
+        let mut data_store = MockDataStore::new();
+        data_store
+            .expect_get_ongoing_rfid_scanning_session()
+            .returning(|_| Ok(None));
+        data_store
+            .expect_get_ongoing_transaction()
+            .returning(|_, _| Ok(None));
+        data_store
+            .expect_next_ocpp_transaction_id()
+            .withf(|charger_id| charger_id == "test")
+            .times(1)
+            .returning(|_| Ok(42));
+        data_store
+            .expect_create_transaction()
+            .withf(|charger_id, _, ocpp_transaction_id, _, is_authorized| {
+                charger_id == "test" && ocpp_transaction_id == "42" && *is_authorized
+            })
+            .times(1)
+            .returning(|_, _, ocpp_transaction_id, _, _| Ok(transaction(ocpp_transaction_id)));
+        data_store
+            .expect_update_transaction_meter_start()
+            .returning(|_, _| Ok(()));
+        data_store
+            .expect_update_transaction_id_tag()
+            .returning(|_, _, _| Ok(()));
+
+        let mut charger = charger(MockOcpp16NetworkInterfaceHandle::new(), data_store).await;
+        charger.data.settings.authorize_transactions = false;
+
+        let response = Ocpp16RequestReceiver::start_transaction(
+            &mut charger,
+            StartTransactionRequest {
+                connector_id: 1,
+                id_tag: "tag".to_string(),
+                meter_start: 0,
+                reservation_id: None,
+                timestamp: Utc::now(),
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(response.transaction_id, 42);
+    }
+
+    #[tokio::test]
+    async fn test_transaction_is_not_created_without_a_transaction_id() {
+        let mut data_store = MockDataStore::new();
+        data_store
+            .expect_next_ocpp_transaction_id()
+            .returning(|_| Err("sequence unavailable".into()));
+        data_store.expect_create_transaction().never();
+
+        let mut charger = charger(MockOcpp16NetworkInterfaceHandle::new(), data_store).await;
+
+        let result = charger.start_transaction_ocpp_1_6(None, 1, true).await;
+
+        assert!(result.is_err());
+    }
+
+    fn adopt_data_store(ocpp_transaction_id: i32) -> MockDataStore {
+        let mut data_store = MockDataStore::new();
+        data_store
+            .expect_get_transaction_by_ocpp_id()
+            .returning(|_, _| Ok(None));
+        data_store
+            .expect_create_transaction()
+            .withf(move |_, _, id, _, _| id == ocpp_transaction_id.to_string())
+            .times(1)
+            .returning(|_, evse_id, id, _, _| {
+                Ok(Transaction {
+                    evse_id,
+                    ..transaction(id)
+                })
+            });
+        data_store
+            .expect_reserve_ocpp_transaction_id()
+            .withf(move |charger_id, id| charger_id == "test" && *id == ocpp_transaction_id)
+            .times(1)
+            .returning(|_, _| Ok(()));
+        data_store
+            .expect_update_transaction_delivered_energy()
+            .returning(|_, _, _| Ok(()));
+        data_store
+            .expect_end_transaction()
+            .returning(|_, id, _| Ok(Some(transaction(id))));
+        data_store
+    }
+
+    fn stop(ocpp_transaction_id: i32) -> StopTransactionRequest {
+        StopTransactionRequest {
+            id_tag: None,
+            meter_stop: 1000,
+            timestamp: Utc::now(),
+            transaction_id: ocpp_transaction_id,
+            reason: None,
+            transaction_data: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unseen_transaction_is_adopted_and_its_id_reserved() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let mut charger = charger(
+            MockOcpp16NetworkInterfaceHandle::new(),
+            adopt_data_store(17),
+        )
+        .await;
+        charger.event_manager = charger
+            .event_manager
+            .with_handler(Box::new(RecordingHandler {
+                events: Arc::clone(&events),
+            }));
+        let evse_id = charger.data.evses[0].id;
+
+        Ocpp16RequestReceiver::stop_transaction(&mut charger, stop(17))
+            .await
+            .unwrap();
+
+        let events = events.lock().unwrap();
+        assert!(events.iter().any(|event| matches!(
+            event,
+            EventPayload::TransactionStartedEvent(started) if started.evse_id == evse_id
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_unseen_transaction_on_unknown_evse_is_not_announced() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let mut charger = charger(
+            MockOcpp16NetworkInterfaceHandle::new(),
+            adopt_data_store(17),
+        )
+        .await;
+        charger.data.evse_by_ocpp_id_or_create(2);
+        charger.event_manager = charger
+            .event_manager
+            .with_handler(Box::new(RecordingHandler {
+                events: Arc::clone(&events),
+            }));
+
+        Ocpp16RequestReceiver::stop_transaction(&mut charger, stop(17))
+            .await
+            .unwrap();
+
+        let events = events.lock().unwrap();
+        assert!(
+            !events
+                .iter()
+                .any(|event| matches!(event, EventPayload::TransactionStartedEvent(_)))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unseen_transaction_on_unknown_evse_has_no_evse() {
+        let mut data_store = MockDataStore::new();
+        data_store
+            .expect_get_transaction_by_ocpp_id()
+            .returning(|_, _| Ok(None));
+        data_store
+            .expect_reserve_ocpp_transaction_id()
+            .returning(|_, _| Ok(()));
+        data_store
+            .expect_create_transaction()
+            .withf(|_, evse_id, _, _, _| evse_id.is_none())
+            .times(1)
+            .returning(|_, evse_id, id, _, _| {
+                Ok(Transaction {
+                    evse_id,
+                    ..transaction(id)
+                })
+            });
+        data_store
+            .expect_update_transaction_delivered_energy()
+            .returning(|_, _, _| Ok(()));
+        data_store.expect_end_transaction().returning(|_, id, _| {
+            Ok(Some(Transaction {
+                evse_id: None,
+                ..transaction(id)
+            }))
+        });
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let mut charger = charger(MockOcpp16NetworkInterfaceHandle::new(), data_store).await;
+        charger.data.evse_by_ocpp_id_or_create(2);
+        charger.event_manager = charger
+            .event_manager
+            .with_handler(Box::new(RecordingHandler {
+                events: Arc::clone(&events),
+            }));
+
+        Ocpp16RequestReceiver::stop_transaction(&mut charger, stop(17))
+            .await
+            .unwrap();
+
+        assert!(events.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_unseen_transaction_is_not_created_when_its_id_cant_be_reserved() {
+        let mut data_store = MockDataStore::new();
+        data_store
+            .expect_get_transaction_by_ocpp_id()
+            .returning(|_, _| Ok(None));
+        data_store
+            .expect_reserve_ocpp_transaction_id()
+            .times(1)
+            .returning(|_, _| Err("database unavailable".into()));
+        data_store.expect_create_transaction().never();
+        let mut charger = charger(MockOcpp16NetworkInterfaceHandle::new(), data_store).await;
+
+        assert!(
+            Ocpp16RequestReceiver::stop_transaction(&mut charger, stop(17))
+                .await
+                .is_err()
+        );
+    }
+}
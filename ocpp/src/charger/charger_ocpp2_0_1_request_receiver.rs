@@ -1,8 +1,8 @@
 use crate::charger::Charger;
+use crate::data_transfer::DataTransferRegistry;
 use crate::network_interface::Ocpp2_0_1RequestReceiver;
 use chrono::Utc;
 use ocpp_client::ocpp_2_0_1::OCPP2_0_1Error;
-use rust_ocpp::v2_0_1::enumerations::data_transfer_status_enum_type::DataTransferStatusEnumType;
 use rust_ocpp::v2_0_1::enumerations::registration_status_enum_type::RegistrationStatusEnumType;
 use rust_ocpp::v2_0_1::messages::authorize::{AuthorizeRequest, AuthorizeResponse};
 use rust_ocpp::v2_0_1::messages::boot_notification::{
@@ -11,7 +11,6 @@ use rust_ocpp::v2_0_1::messages::boot_notification::{
 use rust_ocpp::v2_0_1::messages::cleared_charging_limit::{
     ClearedChargingLimitRequest, ClearedChargingLimitResponse,
 };
-use rust_ocpp::v2_0_1::messages::datatransfer::{DataTransferRequest, DataTransferResponse};
 use rust_ocpp::v2_0_1::messages::firmware_status_notification::{
     FirmwareStatusNotificationRequest, FirmwareStatusNotificationResponse,
 };
@@ -70,6 +69,7 @@ use rust_ocpp::v2_0_1::messages::status_notification::{
 use rust_ocpp::v2_0_1::messages::transaction_event::{
     TransactionEventRequest, TransactionEventResponse,
 };
+use shared::data::FirmwareUpdateStatus;
 use std::error::Error;
 use tracing::error;
 
@@ -97,6 +97,7 @@ impl Ocpp2_0_1RequestReceiver for Charger {
         self.data.serial_number = request.charging_station.serial_number;
         self.data.firmware_version = request.charging_station.firmware_version;
         self.data.model = Some(request.charging_station.model);
+        self.update_vendor_profile();
 
         if let Some(modem) = &request.charging_station.modem {
             self.data.iccid = modem.iccid.clone();
@@ -110,6 +111,13 @@ impl Ocpp2_0_1RequestReceiver for Charger {
             );
         }
 
+        if let Err(err) = self.confirm_firmware_update().await {
+            error!(
+                error_message = err.to_string(),
+                "Failed to confirm firmware update"
+            );
+        }
+
         if self.authenticated {
             Ok(BootNotificationResponse {
                 current_time: Utc::now(),
@@ -134,21 +142,24 @@ impl Ocpp2_0_1RequestReceiver for Charger {
         Ok(ClearedChargingLimitResponse {})
     }
 
-    async fn data_transfer(
-        &mut self,
-        _request: DataTransferRequest,
-    ) -> Result<DataTransferResponse, OCPP2_0_1Error> {
-        Ok(DataTransferResponse {
-            status: DataTransferStatusEnumType::Rejected,
-            data: None,
-            status_info: None,
-        })
+    fn data_transfer_registry(&self) -> DataTransferRegistry {
+        self.data_transfer_registry.clone()
     }
 
     async fn firmware_status_notification(
         &mut self,
-        _request: FirmwareStatusNotificationRequest,
+        request: FirmwareStatusNotificationRequest,
     ) -> Result<FirmwareStatusNotificationResponse, OCPP2_0_1Error> {
+        if let Some(status) = FirmwareUpdateStatus::from_ocpp_2_0_1(request.status)
+            && let Err(err) = self
+                .record_firmware_update_status(status, request.request_id)
+                .await
+        {
+            error!(
+                error_message = err.to_string(),
+                "Failed to record firmware status"
+            );
+        }
         Ok(FirmwareStatusNotificationResponse {})
     }
 
@@ -56,9 +56,17 @@ impl ChargerPool {
         lock.get(charger_id).and_then(|i| i.upgrade())
     }
 
-    pub async fn remove(&self, charger_id: &str) {
+    /// Only removes the charger if it is still the one in the pool, a charger whose connection
+    /// was replaced must not remove its successor. Returns whether it was removed
+    pub async fn remove(&self, charger_id: &str, charger: &Arc<Mutex<Charger>>) -> bool {
         let mut lock = self.chargers.lock().await;
-        lock.remove(charger_id);
+        let is_current = lock
+            .get(charger_id)
+            .is_some_and(|weak| weak.as_ptr() == Arc::as_ptr(charger));
+        if is_current {
+            lock.remove(charger_id);
+        }
         CONNECTED_CHARGERS.set(lock.len() as f64);
+        is_current
     }
 }
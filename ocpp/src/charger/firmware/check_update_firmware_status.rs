@@ -0,0 +1,20 @@
+use rust_ocpp::v2_0_1::enumerations::update_firmware_status_enum_type::UpdateFirmwareStatusEnumType;
+use tonic::Status;
+
+/// Maps the answer to a (signed) firmware update to an error when the charger refused it
+pub fn check_update_firmware_status(status: UpdateFirmwareStatusEnumType) -> Result<(), Status> {
+    match status {
+        UpdateFirmwareStatusEnumType::Accepted | UpdateFirmwareStatusEnumType::AcceptedCanceled => {
+            Ok(())
+        }
+        UpdateFirmwareStatusEnumType::Rejected => {
+            Err(Status::cancelled("Charger rejected the firmware update"))
+        }
+        UpdateFirmwareStatusEnumType::InvalidCertificate => Err(Status::invalid_argument(
+            "Charger rejected the signing certificate",
+        )),
+        UpdateFirmwareStatusEnumType::RevokedCertificate => Err(Status::invalid_argument(
+            "The signing certificate has been revoked",
+        )),
+    }
+}
@@ -0,0 +1,117 @@
+use chrono::{DateTime, Utc};
+use shared::data::{FirmwareUpdate, FirmwareUpdateStatus};
+
+/// Uses the firmware version from a boot notification to decide if an ongoing update succeeded.
+///
+/// The update is confirmed when the charger reports the target version, or any new version if no
+/// target was given. A charger that was installing but booted with another version has failed.
+/// Returns true if the status of the update changed.
+pub fn confirm_firmware_update(
+    update: &mut FirmwareUpdate,
+    firmware_version: Option<&str>,
+    now: DateTime<Utc>,
+) -> bool {
+    if update.status.is_final() {
+        return false;
+    }
+
+    let succeeded = match &update.target_version {
+        Some(target_version) => firmware_version == Some(target_version.as_str()),
+        None => {
+            firmware_version.is_some() && firmware_version != update.previous_version.as_deref()
+        }
+    };
+    let was_installing = matches!(
+        update.status,
+        FirmwareUpdateStatus::Installing
+            | FirmwareUpdateStatus::InstallRebooting
+            | FirmwareUpdateStatus::Installed
+    );
+
+    if succeeded {
+        update.record_status(FirmwareUpdateStatus::Confirmed, now);
+        true
+    } else if was_installing {
+        update.record_status(FirmwareUpdateStatus::Failed, now);
+        true
+    } else {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn update(target_version: Option<&str>, status: FirmwareUpdateStatus) -> FirmwareUpdate {
+        let mut update = FirmwareUpdate::new(
+            "charger",
+            "https://example.com/firmware.bin",
+            target_version.map(|version| version.to_string()),
+            Some("1.0.0".to_string()),
+            None,
+        );
+        update.record_status(status, Utc::now());
+        update
+    }
+
+    #[test]
+    fn test_target_version_confirms_update() {
+        let mut update = update(Some("1.1.0"), FirmwareUpdateStatus::Installed);
+
+        assert!(confirm_firmware_update(
+            &mut update,
+            Some("1.1.0"),
+            Utc::now()
+        ));
+        assert_eq!(update.status, FirmwareUpdateStatus::Confirmed);
+    }
+
+    #[test]
+    fn test_unexpected_version_after_install_fails_update() {
+        let mut update = update(Some("1.1.0"), FirmwareUpdateStatus::Installing);
+
+        assert!(confirm_firmware_update(
+            &mut update,
+            Some("1.0.0"),
+            Utc::now()
+        ));
+        assert_eq!(update.status, FirmwareUpdateStatus::Failed);
+    }
+
+    #[test]
+    fn test_any_new_version_confirms_update_without_target() {
+        let mut update = update(None, FirmwareUpdateStatus::InstallRebooting);
+
+        assert!(confirm_firmware_update(
+            &mut update,
+            Some("1.0.1"),
+            Utc::now()
+        ));
+        assert_eq!(update.status, FirmwareUpdateStatus::Confirmed);
+    }
+
+    #[test]
+    fn test_reboot_while_downloading_is_ignored() {
+        let mut update = update(Some("1.1.0"), FirmwareUpdateStatus::Downloading);
+
+        assert!(!confirm_firmware_update(
+            &mut update,
+            Some("1.0.0"),
+            Utc::now()
+        ));
+        assert_eq!(update.status, FirmwareUpdateStatus::Downloading);
+    }
+
+    #[test]
+    fn test_finished_updates_are_left_alone() {
+        let mut update = update(Some("1.1.0"), FirmwareUpdateStatus::DownloadFailed);
+
+        assert!(!confirm_firmware_update(
+            &mut update,
+            Some("1.1.0"),
+            Utc::now()
+        ));
+        assert_eq!(update.status, FirmwareUpdateStatus::DownloadFailed);
+    }
+}
@@ -0,0 +1,18 @@
+use chrono::{DateTime, Utc};
+
+/// Optional parameters of a firmware update request
+#[derive(Debug, Clone, Default)]
+pub struct FirmwareUpdateOptions {
+    /// When the charger should start downloading, defaults to now
+    pub retrieve_date: Option<DateTime<Utc>>,
+    /// When the charger should install the firmware, only supported for signed firmware on OCPP
+    /// 1.6
+    pub install_date: Option<DateTime<Utc>>,
+    pub retries: Option<i32>,
+    pub retry_interval: Option<i32>,
+    /// PEM encoded certificate used to sign the firmware. OCPP 1.6 chargers need the security
+    /// extension for signed firmware.
+    pub signing_certificate: Option<String>,
+    /// Base64 encoded signature of the firmware, given together with the signing certificate
+    pub signature: Option<String>,
+}
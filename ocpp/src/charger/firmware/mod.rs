@@ -0,0 +1,7 @@
+mod check_update_firmware_status;
+mod confirm_firmware_update;
+mod firmware_update_options;
+
+pub use self::check_update_firmware_status::check_update_firmware_status;
+pub use self::confirm_firmware_update::confirm_firmware_update;
+pub use self::firmware_update_options::FirmwareUpdateOptions;
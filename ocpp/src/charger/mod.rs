@@ -1,13 +1,22 @@
 mod charger;
 mod charger_authentication_handler;
 mod charger_factory;
-mod charger_model;
 mod charger_ocpp1_6_request_receiver;
 mod charger_ocpp2_0_1_request_receiver;
 mod charger_pool;
+mod firmware;
 mod ocpp1_6;
+mod ocpp2_0_1;
+mod smart_charging;
+#[cfg(test)]
+mod test_charger;
+mod triggerable_message;
+mod vendor_profile;
 
 pub use self::charger::Charger;
 pub use self::charger_pool::ChargerPool;
+pub use self::firmware::FirmwareUpdateOptions;
+pub use self::smart_charging::{EvseLimit, evse_phase_count, evse_voltage};
+pub use self::triggerable_message::TriggerableMessage;
 
 pub use self::charger_factory::ChargerFactory;
@@ -0,0 +1,130 @@
+use rust_decimal::Decimal;
+use rust_ocpp::v1_6::types::{ChargingProfile, ChargingSchedule, ChargingSchedulePeriod};
+
+pub fn charging_profile_to_ocpp_1_6(
+    profile: &shared::data::ChargingProfile,
+) -> Result<ChargingProfile, Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let transaction_id = profile
+        .transaction_id
+        .as_ref()
+        .map(|transaction_id| transaction_id.parse::<i32>())
+        .transpose()
+        .map_err(|_| "The transaction id is not a valid ocpp 1.6 transaction id")?;
+
+    let charging_schedule_period = profile
+        .schedule
+        .periods
+        .iter()
+        .map(|period| {
+            Ok(ChargingSchedulePeriod {
+                start_period: period.start_period,
+                limit: to_decimal(period.limit)?,
+                number_phases: period.number_phases,
+            })
+        })
+        .collect::<Result<Vec<_>, Box<dyn std::error::Error + Send + Sync + 'static>>>()?;
+
+    Ok(ChargingProfile {
+        charging_profile_id: profile.id,
+        transaction_id,
+        stack_level: u32::try_from(profile.stack_level)
+            .map_err(|_| "The stack level must not be negative for ocpp 1.6")?,
+        charging_profile_purpose: profile.purpose.into(),
+        charging_profile_kind: profile.kind.into(),
+        recurrency_kind: profile.recurrency_kind.map(|kind| kind.into()),
+        valid_from: profile.valid_from,
+        valid_to: profile.valid_to,
+        charging_schedule: ChargingSchedule {
+            duration: profile.schedule.duration,
+            start_schedule: profile.schedule.start_schedule,
+            charging_rate_unit: profile.schedule.charging_rate_unit.into(),
+            charging_schedule_period,
+            min_charging_rate: profile
+                .schedule
+                .min_charging_rate
+                .map(to_decimal)
+                .transpose()?,
+        },
+    })
+}
+
+fn to_decimal(value: f32) -> Result<Decimal, Box<dyn std::error::Error + Send + Sync + 'static>> {
+    // OCPP 1.6 only allows a single decimal for the limit
+    Decimal::from_f32_retain(value)
+        .map(|value| value.round_dp(1))
+        .ok_or_else(|| format!("{} is not a valid charging rate", value).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_ocpp::v1_6::types::{
+        ChargingProfileKindType, ChargingProfilePurposeType, ChargingRateUnitType,
+    };
+    use shared::data::{
+        ChargingProfileKind, ChargingProfilePurpose, ChargingRateUnit,
+        ChargingSchedule as ScheduleData, ChargingSchedulePeriod as PeriodData,
+    };
+
+    fn profile(transaction_id: Option<&str>, limit: f32) -> shared::data::ChargingProfile {
+        shared::data::ChargingProfile {
+            id: 1,
+            transaction_id: transaction_id.map(|id| id.to_string()),
+            stack_level: 2,
+            purpose: ChargingProfilePurpose::TxProfile,
+            kind: ChargingProfileKind::Relative,
+            recurrency_kind: None,
+            valid_from: None,
+            valid_to: None,
+            schedule: ScheduleData {
+                duration: Some(3600),
+                start_schedule: None,
+                charging_rate_unit: ChargingRateUnit::Ampere,
+                periods: vec![PeriodData {
+                    start_period: 0,
+                    limit,
+                    number_phases: Some(3),
+                }],
+                min_charging_rate: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_converts_profile() {
+        let result = charging_profile_to_ocpp_1_6(&profile(Some("42"), 16.04)).unwrap();
+        assert_eq!(result.charging_profile_id, 1);
+        assert_eq!(result.transaction_id, Some(42));
+        assert_eq!(result.stack_level, 2);
+        assert_eq!(
+            result.charging_profile_purpose,
+            ChargingProfilePurposeType::TxProfile
+        );
+        assert_eq!(
+            result.charging_profile_kind,
+            ChargingProfileKindType::Relative
+        );
+        assert_eq!(
+            result.charging_schedule.charging_rate_unit,
+            ChargingRateUnitType::A
+        );
+        assert_eq!(
+            result.charging_schedule.charging_schedule_period[0].limit,
+            Decimal::new(160, 1)
+        );
+        assert_eq!(
+            result.charging_schedule.charging_schedule_period[0].number_phases,
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn test_invalid_transaction_id() {
+        assert!(charging_profile_to_ocpp_1_6(&profile(Some("not a number"), 16.0)).is_err());
+    }
+
+    #[test]
+    fn test_invalid_limit() {
+        assert!(charging_profile_to_ocpp_1_6(&profile(None, f32::NAN)).is_err());
+    }
+}
@@ -0,0 +1,163 @@
+use crate::charger::ocpp1_6::ocpp_name::ocpp_name;
+use crate::charger::ocpp1_6::parse_metric_value::parse_sampled_value;
+use rust_ocpp::v1_6::types::{MeterValue, ValueFormat};
+use shared::data::{Transaction, TransactionMeterValue};
+use tracing::warn;
+use uuid::Uuid;
+
+pub fn create_transaction_meter_values(
+    transaction: &Transaction,
+    meter_values: &[MeterValue],
+) -> Vec<TransactionMeterValue> {
+    meter_values
+        .iter()
+        .flat_map(|meter_value| {
+            meter_value
+                .sampled_value
+                .iter()
+                .map(move |sampled_value| (meter_value.timestamp, sampled_value))
+        })
+        // Signed values can't be plotted, they are only useful as a whole
+        .filter(|(_, sampled_value)| sampled_value.format != Some(ValueFormat::SignedData))
+        .filter_map(|(timestamp, sampled_value)| {
+            let (value, unit) = match parse_sampled_value(sampled_value) {
+                Ok(parsed) => parsed,
+                Err(_) => {
+                    warn!(
+                        charger_id = transaction.charger_id,
+                        value = sampled_value.value,
+                        "skipping meter value that is not a number"
+                    );
+                    return None;
+                }
+            };
+
+            Some(TransactionMeterValue {
+                id: Uuid::new_v4(),
+                transaction_id: transaction.id,
+                charger_id: transaction.charger_id.clone(),
+                timestamp,
+                measurand: ocpp_name(&sampled_value.measurand.clone().unwrap_or_default())
+                    .unwrap_or_default(),
+                phase: sampled_value.phase.as_ref().and_then(ocpp_name),
+                unit,
+                context: sampled_value.context.as_ref().and_then(ocpp_name),
+                location: sampled_value.location.as_ref().and_then(ocpp_name),
+                value,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use rust_ocpp::v1_6::types::{Measurand, Phase, SampledValue, UnitOfMeasure};
+
+    fn transaction() -> Transaction {
+        Transaction {
+            id: Uuid::new_v4(),
+            charger_id: "charger".to_string(),
+            evse_id: Some(Uuid::new_v4()),
+            ocpp_transaction_id: "1".to_string(),
+            start_time: Utc::now(),
+            end_time: None,
+            energy_meter_at_start: None,
+            last_energy_meter_reading: None,
+            watt_charged: 0,
+            is_authorized: true,
+            id_tag: None,
+            ocpp_reservation_id: None,
+        }
+    }
+
+    #[test]
+    fn test_every_sampled_value_is_kept() {
+        let transaction = transaction();
+        let now = Utc::now();
+        let meter_values = vec![MeterValue {
+            timestamp: now,
+            sampled_value: vec![
+                SampledValue {
+                    value: "7400".to_string(),
+                    measurand: Some(Measurand::PowerActiveImport),
+                    unit: Some(UnitOfMeasure::W),
+                    ..Default::default()
+                },
+                SampledValue {
+                    value: "16.1".to_string(),
+                    measurand: Some(Measurand::CurrentImport),
+                    phase: Some(Phase::L1),
+                    ..Default::default()
+                },
+            ],
+        }];
+
+        let result = create_transaction_meter_values(&transaction, &meter_values);
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].transaction_id, transaction.id);
+        assert_eq!(result[0].timestamp, now);
+        assert_eq!(result[0].measurand, "Power.Active.Import");
+        assert_eq!(result[0].unit.as_deref(), Some("W"));
+        assert_eq!(result[0].value, 7400.0);
+        assert_eq!(result[1].measurand, "Current.Import");
+        assert_eq!(result[1].phase.as_deref(), Some("L1"));
+    }
+
+    #[test]
+    fn test_values_are_stored_in_base_units() {
+        let meter_values = vec![MeterValue {
+            timestamp: Utc::now(),
+            sampled_value: vec![SampledValue {
+                value: "11".to_string(),
+                measurand: Some(Measurand::PowerActiveImport),
+                unit: Some(UnitOfMeasure::Kw),
+                ..Default::default()
+            }],
+        }];
+
+        let result = create_transaction_meter_values(&transaction(), &meter_values);
+
+        assert_eq!(result[0].value, 11000.0);
+        assert_eq!(result[0].unit.as_deref(), Some("W"));
+    }
+
+    #[test]
+    fn test_missing_measurand_defaults_to_energy_register() {
+        let meter_values = vec![MeterValue {
+            timestamp: Utc::now(),
+            sampled_value: vec![SampledValue {
+                value: "1200".to_string(),
+                ..Default::default()
+            }],
+        }];
+
+        let result = create_transaction_meter_values(&transaction(), &meter_values);
+
+        assert_eq!(result[0].measurand, "Energy.Active.Import.Register");
+    }
+
+    #[test]
+    fn test_signed_and_invalid_values_are_skipped() {
+        let meter_values = vec![MeterValue {
+            timestamp: Utc::now(),
+            sampled_value: vec![
+                SampledValue {
+                    value: "c2lnbmVk".to_string(),
+                    format: Some(ValueFormat::SignedData),
+                    ..Default::default()
+                },
+                SampledValue {
+                    value: "not a number".to_string(),
+                    ..Default::default()
+                },
+            ],
+        }];
+
+        let result = create_transaction_meter_values(&transaction(), &meter_values);
+
+        assert!(result.is_empty());
+    }
+}
@@ -0,0 +1,200 @@
+use crate::charger::ocpp1_6::ocpp_name::ocpp_name;
+use crate::charger::ocpp1_6::parse_metric_value::parse_sampled_value;
+use chrono::{DateTime, Utc};
+use rust_ocpp::v1_6::types::{Measurand, MeterValue};
+use shared::data::Metric;
+
+/// Energy registers are expected to wrap at one of these values, or at a power of ten
+const REGISTER_LIMITS: [i64; 2] = [i32::MAX as i64 + 1, u32::MAX as i64 + 1];
+
+/// A decreasing register is treated as a rollover when the previous reading was this close to
+/// the limit and the current reading this close to zero
+const ROLLOVER_MARGIN: f64 = 0.1;
+
+/// The energy in Wh delivered between two readings of an energy register. A decreasing register
+/// either rolled over at its limit, or was reset or misread, which doesn't count as delivered
+/// energy.
+pub fn register_delta(previous: i32, current: i32) -> i32 {
+    if current >= previous {
+        return current - previous;
+    }
+
+    let previous = previous as i64;
+    let power_of_ten_limit = 10i64.pow(previous.max(1).ilog10() + 1);
+    let limit = REGISTER_LIMITS
+        .into_iter()
+        .chain([power_of_ten_limit])
+        .filter(|limit| *limit > previous)
+        .min()
+        .unwrap_or(power_of_ten_limit);
+
+    let margin = limit as f64 * ROLLOVER_MARGIN;
+    if (limit - previous) as f64 <= margin && (current as f64) < margin {
+        (limit - previous + current as i64).clamp(0, i32::MAX as i64) as i32
+    } else {
+        0
+    }
+}
+
+/// The total imported energy in Wh of every meter value, readings of single phases or of the
+/// grid connection are left out
+pub fn energy_register_readings(meter_values: &[MeterValue]) -> Vec<(DateTime<Utc>, i32)> {
+    meter_values
+        .iter()
+        .flat_map(|meter_value| {
+            meter_value
+                .sampled_value
+                .iter()
+                .filter(|sampled_value| {
+                    sampled_value.measurand.clone().unwrap_or_default()
+                        == Measurand::EnergyActiveImportRegister
+                        && sampled_value.phase.is_none()
+                        && sampled_value
+                            .location
+                            .as_ref()
+                            .and_then(ocpp_name)
+                            .is_none_or(|location| location == "Outlet")
+                })
+                // Energy is reported in Wh when no unit is given
+                .filter_map(|sampled_value| parse_sampled_value(sampled_value).ok())
+                .map(move |(value, _)| (meter_value.timestamp, value.round() as i32))
+        })
+        .collect()
+}
+
+/// Adds the energy between consecutive register readings to what was delivered so far. Readings
+/// that aren't newer than the last reading are ignored, as chargers may send values out of
+/// order. Returns the delivered energy and the last reading.
+pub fn accumulate_delivered_energy(
+    delivered: i32,
+    last_reading: Option<Metric<i32>>,
+    readings: impl IntoIterator<Item = (DateTime<Utc>, i32)>,
+) -> (i32, Option<Metric<i32>>) {
+    let mut readings: Vec<_> = readings.into_iter().collect();
+    readings.sort_by_key(|(timestamp, _)| *timestamp);
+
+    readings.into_iter().fold(
+        (delivered, last_reading),
+        |(delivered, last_reading), (timestamp, value)| {
+            let is_newer = last_reading
+                .as_ref()
+                .and_then(|last_reading| last_reading.measured_at)
+                .is_none_or(|measured_at| timestamp > measured_at);
+            if !is_newer {
+                return (delivered, last_reading);
+            }
+
+            let delta = last_reading
+                .map(|last_reading| register_delta(last_reading.value, value))
+                .unwrap_or_default();
+            (
+                delivered.saturating_add(delta),
+                Some(Metric {
+                    value,
+                    measured_at: Some(timestamp),
+                }),
+            )
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use rust_ocpp::v1_6::types::{Phase, SampledValue, UnitOfMeasure};
+
+    fn at(seconds: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(seconds, 0).unwrap()
+    }
+
+    fn reading(value: i32, seconds: i64) -> Option<Metric<i32>> {
+        Some(Metric {
+            value,
+            measured_at: Some(at(seconds)),
+        })
+    }
+
+    #[test]
+    fn test_register_delta_increasing() {
+        assert_eq!(register_delta(1000, 1500), 500);
+    }
+
+    #[test]
+    fn test_register_delta_rollover_at_power_of_ten() {
+        assert_eq!(register_delta(99_900, 200), 300);
+    }
+
+    #[test]
+    fn test_register_delta_rollover_at_i32_max() {
+        assert_eq!(register_delta(i32::MAX - 99, 50), 150);
+    }
+
+    #[test]
+    fn test_register_delta_reset() {
+        assert_eq!(register_delta(45_000, 300), 0);
+    }
+
+    #[test]
+    fn test_register_delta_small_step_back_near_limit() {
+        assert_eq!(register_delta(950_000, 949_999), 0);
+    }
+
+    #[test]
+    fn test_energy_register_readings() {
+        let meter_values = vec![MeterValue {
+            timestamp: at(10),
+            sampled_value: vec![
+                SampledValue {
+                    value: "1.25".to_string(),
+                    measurand: Some(Measurand::EnergyActiveImportRegister),
+                    unit: Some(UnitOfMeasure::KWh),
+                    ..Default::default()
+                },
+                SampledValue {
+                    value: "400".to_string(),
+                    measurand: Some(Measurand::EnergyActiveImportRegister),
+                    phase: Some(Phase::L1),
+                    ..Default::default()
+                },
+                SampledValue {
+                    value: "7400".to_string(),
+                    measurand: Some(Measurand::PowerActiveImport),
+                    ..Default::default()
+                },
+            ],
+        }];
+
+        assert_eq!(
+            energy_register_readings(&meter_values),
+            vec![(at(10), 1250)]
+        );
+    }
+
+    #[test]
+    fn test_accumulate_delivered_energy() {
+        let (delivered, last_reading) =
+            accumulate_delivered_energy(0, reading(1000, 0), [(at(20), 1800), (at(10), 1200)]);
+
+        assert_eq!(delivered, 800);
+        assert_eq!(last_reading.unwrap().value, 1800);
+    }
+
+    #[test]
+    fn test_accumulate_ignores_old_readings() {
+        let (delivered, last_reading) =
+            accumulate_delivered_energy(500, reading(1500, 30), [(at(20), 1200)]);
+
+        assert_eq!(delivered, 500);
+        assert_eq!(last_reading.unwrap().value, 1500);
+    }
+
+    #[test]
+    fn test_accumulate_without_baseline() {
+        let (delivered, last_reading) =
+            accumulate_delivered_energy(0, None, [(at(10), 1200), (at(20), 1300)]);
+
+        assert_eq!(delivered, 100);
+        assert_eq!(last_reading.unwrap().value, 1300);
+    }
+}
@@ -0,0 +1,211 @@
+use rust_ocpp::v1_6::types::ConfigurationStatus;
+use shared::data::{ConfigurationDrift, ConfigurationDriftReason, Ocpp1_6Configuration};
+use std::collections::BTreeMap;
+
+/// Compares the configuration a charger reported against the desired configuration. Keys the
+/// charger doesn't report, or reports as read only, are never pushed and so can't drift. The
+/// previous configuration is the one the charger was left with after the last push, so a key
+/// that was in line then and isn't now was changed on the charger.
+pub fn detect_configuration_drift(
+    desired: &BTreeMap<String, String>,
+    current: &Ocpp1_6Configuration,
+    previous: Option<&Ocpp1_6Configuration>,
+) -> Vec<ConfigurationDrift> {
+    desired
+        .iter()
+        .filter_map(|(key, desired_value)| {
+            let current_value = current.get_configuration(key)?;
+            if current_value.read_only || current_value.value.as_ref() == Some(desired_value) {
+                return None;
+            }
+
+            let was_desired = previous
+                .and_then(|previous| previous.get_configuration(key))
+                .is_some_and(|previous_value| previous_value.value.as_ref() == Some(desired_value));
+
+            Some(ConfigurationDrift {
+                key: key.clone(),
+                desired_value: desired_value.clone(),
+                actual_value: current_value.value.clone(),
+                reason: match was_desired {
+                    true => ConfigurationDriftReason::ChangedOnCharger,
+                    false => ConfigurationDriftReason::Differs,
+                },
+            })
+        })
+        .collect()
+}
+
+/// Updates the drift with how the charger responded to the desired values being pushed, keys
+/// that were accepted are back in line
+pub fn resolve_configuration_drift(
+    drift: Vec<ConfigurationDrift>,
+    results: &BTreeMap<String, ConfigurationStatus>,
+) -> Vec<ConfigurationDrift> {
+    drift
+        .into_iter()
+        .filter_map(|mut drift| {
+            drift.reason = match results.get(&drift.key) {
+                Some(ConfigurationStatus::Accepted) => return None,
+                Some(ConfigurationStatus::RebootRequired) => {
+                    ConfigurationDriftReason::RebootRequired
+                }
+                Some(ConfigurationStatus::Rejected) => ConfigurationDriftReason::Rejected,
+                Some(ConfigurationStatus::NotSupported) => ConfigurationDriftReason::NotSupported,
+                None => drift.reason,
+            };
+            Some(drift)
+        })
+        .collect()
+}
+
+/// The configuration the charger is left with once the desired values it accepted are applied
+pub fn configuration_after_push(
+    configuration: &Ocpp1_6Configuration,
+    desired: &BTreeMap<String, String>,
+    results: &BTreeMap<String, ConfigurationStatus>,
+) -> Ocpp1_6Configuration {
+    let mut configuration = configuration.clone();
+    for (key, status) in results {
+        if let (ConfigurationStatus::Accepted | ConfigurationStatus::RebootRequired, Some(value)) =
+            (status, desired.get(key))
+        {
+            configuration.set_value(key, value);
+        }
+    }
+    configuration
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::charger::ocpp1_6::test_configuration::configuration;
+
+    fn desired() -> BTreeMap<String, String> {
+        BTreeMap::from([
+            ("MeterValueSampleInterval".to_string(), "30".to_string()),
+            ("HeartbeatInterval".to_string(), "300".to_string()),
+            ("NumberOfConnectors".to_string(), "1".to_string()),
+            ("ResetRetries".to_string(), "3".to_string()),
+        ])
+    }
+
+    #[test]
+    fn test_detects_drift() {
+        let previous = configuration(&[
+            ("MeterValueSampleInterval", "30", false),
+            ("HeartbeatInterval", "60", false),
+        ]);
+        let current = configuration(&[
+            ("MeterValueSampleInterval", "10", false),
+            ("HeartbeatInterval", "120", false),
+            ("NumberOfConnectors", "2", true),
+            ("ResetRetries", "3", false),
+        ]);
+
+        let drift = detect_configuration_drift(&desired(), &current, Some(&previous));
+
+        assert_eq!(
+            drift,
+            vec![
+                ConfigurationDrift {
+                    key: "HeartbeatInterval".to_string(),
+                    desired_value: "300".to_string(),
+                    actual_value: Some("120".to_string()),
+                    reason: ConfigurationDriftReason::Differs,
+                },
+                ConfigurationDrift {
+                    key: "MeterValueSampleInterval".to_string(),
+                    desired_value: "30".to_string(),
+                    actual_value: Some("10".to_string()),
+                    reason: ConfigurationDriftReason::ChangedOnCharger,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resolves_drift_from_push_results() {
+        let drift = |key: &str| ConfigurationDrift {
+            key: key.to_string(),
+            desired_value: "1".to_string(),
+            actual_value: Some("0".to_string()),
+            reason: ConfigurationDriftReason::Differs,
+        };
+        let results = BTreeMap::from([
+            ("A".to_string(), ConfigurationStatus::Accepted),
+            ("B".to_string(), ConfigurationStatus::RebootRequired),
+            ("C".to_string(), ConfigurationStatus::Rejected),
+        ]);
+
+        let resolved = resolve_configuration_drift(
+            vec![drift("A"), drift("B"), drift("C"), drift("D")],
+            &results,
+        );
+
+        assert_eq!(
+            resolved
+                .iter()
+                .map(|drift| (drift.key.as_str(), drift.reason))
+                .collect::<Vec<_>>(),
+            vec![
+                ("B", ConfigurationDriftReason::RebootRequired),
+                ("C", ConfigurationDriftReason::Rejected),
+                ("D", ConfigurationDriftReason::Differs),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_accepted_values_are_changed_on_the_charger_on_the_next_boot() {
+        let desired = BTreeMap::from([("HeartbeatInterval".to_string(), "300".to_string())]);
+        let reported = configuration(&[("HeartbeatInterval", "60", false)]);
+        let results = BTreeMap::from([(
+            "HeartbeatInterval".to_string(),
+            ConfigurationStatus::Accepted,
+        )]);
+
+        let after_push = configuration_after_push(&reported, &desired, &results);
+        let drift = detect_configuration_drift(&desired, &reported, Some(&after_push));
+
+        assert_eq!(
+            drift
+                .iter()
+                .map(|drift| (drift.key.as_str(), drift.reason))
+                .collect::<Vec<_>>(),
+            vec![(
+                "HeartbeatInterval",
+                ConfigurationDriftReason::ChangedOnCharger
+            )]
+        );
+    }
+
+    #[test]
+    fn test_rejected_values_are_not_applied_after_the_push() {
+        let desired = BTreeMap::from([
+            ("HeartbeatInterval".to_string(), "300".to_string()),
+            ("ResetRetries".to_string(), "3".to_string()),
+        ]);
+        let reported = configuration(&[
+            ("HeartbeatInterval", "60", false),
+            ("ResetRetries", "1", false),
+        ]);
+        let results = BTreeMap::from([
+            (
+                "HeartbeatInterval".to_string(),
+                ConfigurationStatus::RebootRequired,
+            ),
+            ("ResetRetries".to_string(), ConfigurationStatus::Rejected),
+        ]);
+
+        let after_push = configuration_after_push(&reported, &desired, &results);
+
+        let value = |key| {
+            after_push
+                .get_configuration(key)
+                .and_then(|value| value.value.clone())
+        };
+        assert_eq!(value("HeartbeatInterval").as_deref(), Some("300"));
+        assert_eq!(value("ResetRetries").as_deref(), Some("1"));
+    }
+}
@@ -0,0 +1,190 @@
+use chrono::{DateTime, Utc};
+use shared::data::{ChargingProfile, ChargingProfileKind, ChargingSchedule, RecurrencyKind};
+
+/// Compares the desired profiles of a connector with the composite schedule reported by the
+/// charger, and returns the profiles that appear to be missing from the charger.
+///
+/// This is deliberately conservative, a profile is considered missing if the charger currently
+/// allows more than the profile does, or if the composite schedule is in another unit and can't be
+/// compared. Re-applying a profile that was present is harmless.
+pub fn find_missing_charging_profiles(
+    desired: &[ChargingProfile],
+    composite: Option<&ChargingSchedule>,
+    now: DateTime<Utc>,
+) -> Vec<ChargingProfile> {
+    let active = desired
+        .iter()
+        .filter(|profile| profile.valid_to.is_none_or(|valid_to| valid_to > now));
+
+    match composite {
+        None => active.cloned().collect(),
+        Some(composite) => {
+            let composite_limit = schedule_limit_at(
+                composite,
+                composite.start_schedule.unwrap_or(now),
+                now,
+                None,
+            );
+            active
+                .filter(|profile| {
+                    profile
+                        .valid_from
+                        .is_none_or(|valid_from| valid_from <= now)
+                })
+                .filter(|profile| {
+                    if profile.schedule.charging_rate_unit != composite.charging_rate_unit {
+                        return true;
+                    }
+                    match (profile_limit_at(profile, now), composite_limit) {
+                        (Some(limit), Some(composite_limit)) => composite_limit > limit + 0.1,
+                        (Some(_), None) => true,
+                        (None, _) => false,
+                    }
+                })
+                .cloned()
+                .collect()
+        }
+    }
+}
+
+fn profile_limit_at(profile: &ChargingProfile, now: DateTime<Utc>) -> Option<f32> {
+    match profile.kind {
+        ChargingProfileKind::Absolute => schedule_limit_at(
+            &profile.schedule,
+            profile.schedule.start_schedule?,
+            now,
+            None,
+        ),
+        // Relative profiles start with the transaction, so the first period is what applies
+        ChargingProfileKind::Relative => schedule_limit_at(&profile.schedule, now, now, None),
+        ChargingProfileKind::Recurring => {
+            let recurrence_secs = match profile.recurrency_kind? {
+                RecurrencyKind::Daily => 24 * 60 * 60,
+                RecurrencyKind::Weekly => 7 * 24 * 60 * 60,
+            };
+            schedule_limit_at(
+                &profile.schedule,
+                profile.schedule.start_schedule?,
+                now,
+                Some(recurrence_secs),
+            )
+        }
+    }
+}
+
+fn schedule_limit_at(
+    schedule: &ChargingSchedule,
+    start: DateTime<Utc>,
+    now: DateTime<Utc>,
+    recurrence_secs: Option<i64>,
+) -> Option<f32> {
+    let mut elapsed = (now - start).num_seconds();
+    if elapsed < 0 {
+        return None;
+    }
+    if let Some(recurrence_secs) = recurrence_secs {
+        elapsed %= recurrence_secs;
+    }
+    if let Some(duration) = schedule.duration
+        && elapsed > duration as i64
+    {
+        return None;
+    }
+    schedule
+        .periods
+        .iter()
+        .filter(|period| period.start_period as i64 <= elapsed)
+        .max_by_key(|period| period.start_period)
+        .map(|period| period.limit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeDelta;
+    use shared::data::{ChargingProfilePurpose, ChargingRateUnit, ChargingSchedulePeriod};
+
+    fn schedule(unit: ChargingRateUnit, limits: &[(i32, f32)]) -> ChargingSchedule {
+        ChargingSchedule {
+            duration: None,
+            start_schedule: None,
+            charging_rate_unit: unit,
+            periods: limits
+                .iter()
+                .map(|(start_period, limit)| ChargingSchedulePeriod {
+                    start_period: *start_period,
+                    limit: *limit,
+                    number_phases: None,
+                })
+                .collect(),
+            min_charging_rate: None,
+        }
+    }
+
+    fn profile(id: i32, limit: f32) -> ChargingProfile {
+        ChargingProfile {
+            id,
+            transaction_id: None,
+            stack_level: 0,
+            purpose: ChargingProfilePurpose::TxDefaultProfile,
+            kind: ChargingProfileKind::Relative,
+            recurrency_kind: None,
+            valid_from: None,
+            valid_to: None,
+            schedule: schedule(ChargingRateUnit::Ampere, &[(0, limit)]),
+        }
+    }
+
+    #[test]
+    fn test_all_profiles_missing_without_composite() {
+        let now = Utc::now();
+        let desired = vec![profile(1, 16.0), profile(2, 10.0)];
+        assert_eq!(find_missing_charging_profiles(&desired, None, now), desired);
+    }
+
+    #[test]
+    fn test_expired_profiles_are_ignored() {
+        let now = Utc::now();
+        let mut expired = profile(1, 16.0);
+        expired.valid_to = Some(now - TimeDelta::hours(1));
+        assert!(find_missing_charging_profiles(&[expired], None, now).is_empty());
+    }
+
+    #[test]
+    fn test_profile_present_when_composite_is_limited() {
+        let now = Utc::now();
+        let composite = schedule(ChargingRateUnit::Ampere, &[(0, 16.0)]);
+        let missing = find_missing_charging_profiles(&[profile(1, 16.0)], Some(&composite), now);
+        assert!(missing.is_empty());
+    }
+
+    #[test]
+    fn test_profile_missing_when_composite_allows_more() {
+        let now = Utc::now();
+        let composite = schedule(ChargingRateUnit::Ampere, &[(0, 32.0)]);
+        let missing = find_missing_charging_profiles(&[profile(1, 16.0)], Some(&composite), now);
+        assert_eq!(missing, vec![profile(1, 16.0)]);
+    }
+
+    #[test]
+    fn test_profile_with_other_unit_is_missing() {
+        let now = Utc::now();
+        let composite = schedule(ChargingRateUnit::Watt, &[(0, 22000.0)]);
+        let missing = find_missing_charging_profiles(&[profile(1, 16.0)], Some(&composite), now);
+        assert_eq!(missing, vec![profile(1, 16.0)]);
+    }
+
+    #[test]
+    fn test_recurring_profile_uses_current_period() {
+        let now = Utc::now();
+        let mut recurring = profile(1, 32.0);
+        recurring.kind = ChargingProfileKind::Recurring;
+        recurring.recurrency_kind = Some(RecurrencyKind::Daily);
+        recurring.schedule = schedule(ChargingRateUnit::Ampere, &[(0, 32.0), (3600, 6.0)]);
+        recurring.schedule.start_schedule = Some(now - TimeDelta::days(3) - TimeDelta::hours(2));
+
+        let composite = schedule(ChargingRateUnit::Ampere, &[(0, 16.0)]);
+        let missing = find_missing_charging_profiles(&[recurring.clone()], Some(&composite), now);
+        assert_eq!(missing, vec![recurring]);
+    }
+}
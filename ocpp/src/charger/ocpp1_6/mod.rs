@@ -1,9 +1,29 @@
-mod create_transaction_id;
+mod charging_profile_to_ocpp_1_6;
+mod create_transaction_meter_values;
+mod delivered_energy;
+mod detect_configuration_drift;
+mod find_missing_charging_profiles;
 mod handle_meter_values_request;
+mod normalize_metric_value;
+mod ocpp_name;
 mod parse_metric_value;
+mod select_charging_rate_unit;
+#[cfg(test)]
+mod test_configuration;
 mod update_evse_ampere_from_metric_request;
 mod update_evse_voltage_from_metric_request;
 mod update_metric;
+mod validate_configuration_overrides;
 
-pub use self::create_transaction_id::create_transaction_id;
+pub use self::charging_profile_to_ocpp_1_6::charging_profile_to_ocpp_1_6;
+pub use self::create_transaction_meter_values::create_transaction_meter_values;
+pub use self::delivered_energy::{accumulate_delivered_energy, energy_register_readings};
+pub use self::detect_configuration_drift::{
+    configuration_after_push, detect_configuration_drift, resolve_configuration_drift,
+};
+pub use self::find_missing_charging_profiles::find_missing_charging_profiles;
 pub use self::handle_meter_values_request::update_charger_from_meter_values_request;
+pub use self::select_charging_rate_unit::select_charging_rate_unit;
+pub use self::validate_configuration_overrides::{
+    invalid_configuration_key, validate_configuration_overrides,
+};
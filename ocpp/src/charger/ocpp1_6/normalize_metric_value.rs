@@ -0,0 +1,52 @@
+/// Converts a reading to the base unit of its OCPP unit of measure, so kWh becomes Wh, kW
+/// becomes W and temperatures are in Celsius. The multiplier is the power of ten the reading is
+/// scaled by.
+pub fn normalize_metric_value(value: f64, unit: &str, multiplier: i32) -> (f64, &str) {
+    let value = value * 10f64.powi(multiplier);
+
+    match unit {
+        "kWh" => (value * 1000.0, "Wh"),
+        "kvarh" => (value * 1000.0, "varh"),
+        "kW" => (value * 1000.0, "W"),
+        "kVA" => (value * 1000.0, "VA"),
+        "kvar" => (value * 1000.0, "var"),
+        "Fahrenheit" => ((value - 32.0) * 5.0 / 9.0, "Celsius"),
+        "K" => (value - 273.15, "Celsius"),
+        unit => (value, unit),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kilo_units_are_converted() {
+        assert_eq!(normalize_metric_value(1.5, "kWh", 0), (1500.0, "Wh"));
+        assert_eq!(normalize_metric_value(11.0, "kW", 0), (11000.0, "W"));
+        assert_eq!(normalize_metric_value(2.0, "kvarh", 0), (2000.0, "varh"));
+    }
+
+    #[test]
+    fn test_base_units_are_kept() {
+        assert_eq!(normalize_metric_value(230.0, "V", 0), (230.0, "V"));
+        assert_eq!(normalize_metric_value(1500.0, "Wh", 0), (1500.0, "Wh"));
+    }
+
+    #[test]
+    fn test_temperatures_are_converted_to_celsius() {
+        assert_eq!(
+            normalize_metric_value(212.0, "Fahrenheit", 0),
+            (100.0, "Celsius")
+        );
+        let (value, unit) = normalize_metric_value(300.0, "K", 0);
+        assert!((value - 26.85).abs() < 1e-9);
+        assert_eq!(unit, "Celsius");
+    }
+
+    #[test]
+    fn test_multiplier_is_applied() {
+        assert_eq!(normalize_metric_value(15.0, "Wh", 2), (1500.0, "Wh"));
+        assert_eq!(normalize_metric_value(15.0, "kWh", -1), (1500.0, "Wh"));
+    }
+}
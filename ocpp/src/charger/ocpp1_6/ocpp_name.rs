@@ -0,0 +1,9 @@
+use serde::Serialize;
+
+/// The name OCPP uses for the value, e.g. "Energy.Active.Import.Register"
+pub fn ocpp_name<T: Serialize>(value: &T) -> Option<String> {
+    serde_json::to_value(value)
+        .ok()?
+        .as_str()
+        .map(str::to_string)
+}
@@ -1,4 +1,7 @@
+use crate::charger::ocpp1_6::normalize_metric_value::normalize_metric_value;
+use crate::charger::ocpp1_6::ocpp_name::ocpp_name;
 use ocpp_client::ocpp_1_6::OCPP1_6Error;
+use rust_ocpp::v1_6::types::SampledValue;
 use tracing::error;
 
 pub fn parse_metric_value<T: std::str::FromStr>(value: &str) -> Result<T, OCPP1_6Error> {
@@ -10,10 +13,27 @@ pub fn parse_metric_value<T: std::str::FromStr>(value: &str) -> Result<T, OCPP1_
     Ok(value)
 }
 
+/// Parses the value in the base unit of its unit of measure, see [normalize_metric_value].
+/// Returns the value with the name of its unit, if the charger reported one.
+pub fn parse_sampled_value(
+    sampled_value: &SampledValue,
+) -> Result<(f64, Option<String>), OCPP1_6Error> {
+    let value = parse_metric_value::<f64>(&sampled_value.value)?;
+
+    Ok(match sampled_value.unit.as_ref().and_then(ocpp_name) {
+        Some(unit) => {
+            let (value, unit) = normalize_metric_value(value, &unit, 0);
+            (value, Some(unit.to_string()))
+        }
+        None => (value, None),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use ocpp_client::ocpp_1_6::OCPP1_6Error;
+    use rust_ocpp::v1_6::types::UnitOfMeasure;
 
     #[test]
     fn test_parse_metric_value_success_int() {
@@ -32,4 +52,28 @@ mod tests {
         let result: Result<i32, OCPP1_6Error> = parse_metric_value("not_a_number");
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_parse_sampled_value_normalizes_unit() {
+        let sampled_value = SampledValue {
+            value: "12.5".to_string(),
+            unit: Some(UnitOfMeasure::KWh),
+            ..Default::default()
+        };
+
+        let (value, unit) = parse_sampled_value(&sampled_value).unwrap();
+
+        assert_eq!(value, 12500.0);
+        assert_eq!(unit.as_deref(), Some("Wh"));
+    }
+
+    #[test]
+    fn test_parse_sampled_value_without_unit() {
+        let sampled_value = SampledValue {
+            value: "16".to_string(),
+            ..Default::default()
+        };
+
+        assert_eq!(parse_sampled_value(&sampled_value).unwrap(), (16.0, None));
+    }
 }
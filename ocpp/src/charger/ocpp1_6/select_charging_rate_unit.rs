@@ -0,0 +1,74 @@
+use shared::data::{ChargingRateUnit, Ocpp1_6Configuration};
+
+/// Picks the charging rate unit to use for a charger, preferring the given unit if the charger
+/// supports it. Chargers that don't report their supported units are assumed to support current.
+pub fn select_charging_rate_unit(
+    configuration: Option<&Ocpp1_6Configuration>,
+    preferred: ChargingRateUnit,
+) -> ChargingRateUnit {
+    let allowed = configuration
+        .and_then(|configuration| {
+            configuration.get_configuration(
+                Ocpp1_6Configuration::CHARGING_SCHEDULE_ALLOWED_CHARGING_RATE_UNIT,
+            )
+        })
+        .and_then(|value| value.value.clone())
+        .unwrap_or_default();
+
+    let supports_current = allowed.contains("Current");
+    let supports_power = allowed.contains("Power");
+
+    match preferred {
+        ChargingRateUnit::Watt if supports_power => ChargingRateUnit::Watt,
+        ChargingRateUnit::Ampere if supports_current => ChargingRateUnit::Ampere,
+        _ if supports_power && !supports_current => ChargingRateUnit::Watt,
+        _ => ChargingRateUnit::Ampere,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::charger::ocpp1_6::test_configuration::configuration as reported;
+
+    fn configuration(allowed: &str) -> Ocpp1_6Configuration {
+        reported(&[(
+            Ocpp1_6Configuration::CHARGING_SCHEDULE_ALLOWED_CHARGING_RATE_UNIT,
+            allowed,
+            true,
+        )])
+    }
+
+    #[test]
+    fn test_preferred_unit_when_supported() {
+        let configuration = configuration("Current,Power");
+        assert_eq!(
+            select_charging_rate_unit(Some(&configuration), ChargingRateUnit::Watt),
+            ChargingRateUnit::Watt
+        );
+        assert_eq!(
+            select_charging_rate_unit(Some(&configuration), ChargingRateUnit::Ampere),
+            ChargingRateUnit::Ampere
+        );
+    }
+
+    #[test]
+    fn test_falls_back_to_supported_unit() {
+        assert_eq!(
+            select_charging_rate_unit(Some(&configuration("Current")), ChargingRateUnit::Watt),
+            ChargingRateUnit::Ampere
+        );
+        assert_eq!(
+            select_charging_rate_unit(Some(&configuration("Power")), ChargingRateUnit::Ampere),
+            ChargingRateUnit::Watt
+        );
+    }
+
+    #[test]
+    fn test_defaults_to_current() {
+        assert_eq!(
+            select_charging_rate_unit(None, ChargingRateUnit::Watt),
+            ChargingRateUnit::Ampere
+        );
+    }
+}
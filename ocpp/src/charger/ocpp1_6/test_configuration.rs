@@ -0,0 +1,20 @@
+use rust_ocpp::v1_6::messages::get_configuration::GetConfigurationResponse;
+use rust_ocpp::v1_6::types::KeyValue;
+use shared::data::Ocpp1_6Configuration;
+
+/// A configuration as a charger would report it, from `(key, value, read_only)` entries
+pub fn configuration(entries: &[(&str, &str, bool)]) -> Ocpp1_6Configuration {
+    Ocpp1_6Configuration::from_full_get_configuration_response(&GetConfigurationResponse {
+        configuration_key: Some(
+            entries
+                .iter()
+                .map(|(key, value, read_only)| KeyValue {
+                    key: key.to_string(),
+                    readonly: *read_only,
+                    value: Some(value.to_string()),
+                })
+                .collect(),
+        ),
+        unknown_key: None,
+    })
+}
@@ -33,10 +33,11 @@ mod tests {
     use super::*;
     use chrono::Utc;
     use rust_ocpp::v1_6::types::Phase;
+    use shared::Config;
     use shared::data::{ChargerData, EvseData, PhaseMetric};
 
     fn setup_charger_with_evse(connector_id: u32) -> ChargerData {
-        let mut charger = ChargerData::default();
+        let mut charger = ChargerData::new("test", &Config::default());
         let evse = EvseData {
             id: Default::default(),
             ocpp_evse_id: connector_id,
@@ -93,7 +94,7 @@ mod tests {
 
     #[test]
     fn test_update_voltage_nonexistent_evse() {
-        let mut charger = ChargerData::default();
+        let mut charger = ChargerData::new("test", &Config::default());
         let now = Utc::now();
         let res = update_evse_voltage_from_metric_request(
             &mut charger,
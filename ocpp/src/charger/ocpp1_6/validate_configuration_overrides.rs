@@ -0,0 +1,100 @@
+use shared::data::Ocpp1_6Configuration;
+use std::collections::BTreeMap;
+
+/// Checks that the charger reported every key of the overrides, that none of them is read only
+/// and that the vendor of the charger supports changing them. Returns the reason the first
+/// invalid key is rejected.
+pub fn validate_configuration_overrides(
+    overrides: &BTreeMap<String, String>,
+    configuration: &Ocpp1_6Configuration,
+    unsupported_keys: &[&str],
+) -> Result<(), String> {
+    for key in overrides.keys() {
+        if let Some(reason) = invalid_configuration_key(key, configuration, unsupported_keys) {
+            return Err(reason);
+        }
+    }
+    Ok(())
+}
+
+/// The reason a value for the key can't be pushed to the charger, if it can't
+pub fn invalid_configuration_key(
+    key: &str,
+    configuration: &Ocpp1_6Configuration,
+    unsupported_keys: &[&str],
+) -> Option<String> {
+    if unsupported_keys.contains(&key) {
+        return Some(format!(
+            "The configuration key '{}' is not supported by the vendor of the charger",
+            key
+        ));
+    }
+    match configuration.get_configuration(key) {
+        None => Some(format!(
+            "The charger does not report the configuration key '{}'",
+            key
+        )),
+        Some(value) if value.read_only => {
+            Some(format!("The configuration key '{}' is read only", key))
+        }
+        Some(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::charger::ocpp1_6::test_configuration::configuration as reported;
+
+    fn configuration() -> Ocpp1_6Configuration {
+        reported(&[
+            ("MeterValueSampleInterval", "30", false),
+            ("NumberOfConnectors", "2", true),
+        ])
+    }
+
+    #[test]
+    fn test_accepts_writable_keys() {
+        let overrides =
+            BTreeMap::from([("MeterValueSampleInterval".to_string(), "60".to_string())]);
+        assert_eq!(
+            validate_configuration_overrides(&overrides, &configuration(), &[]),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_rejects_read_only_keys() {
+        let overrides = BTreeMap::from([("NumberOfConnectors".to_string(), "1".to_string())]);
+        assert_eq!(
+            validate_configuration_overrides(&overrides, &configuration(), &[]),
+            Err("The configuration key 'NumberOfConnectors' is read only".to_string())
+        );
+    }
+
+    #[test]
+    fn test_rejects_unknown_keys() {
+        let overrides = BTreeMap::from([("HeartbeatInteval".to_string(), "300".to_string())]);
+        assert_eq!(
+            validate_configuration_overrides(&overrides, &configuration(), &[]),
+            Err("The charger does not report the configuration key 'HeartbeatInteval'".to_string())
+        );
+    }
+
+    #[test]
+    fn test_rejects_keys_unsupported_by_the_vendor() {
+        let overrides =
+            BTreeMap::from([("MeterValueSampleInterval".to_string(), "60".to_string())]);
+        assert_eq!(
+            validate_configuration_overrides(
+                &overrides,
+                &configuration(),
+                &["MeterValueSampleInterval"]
+            ),
+            Err(
+                "The configuration key 'MeterValueSampleInterval' is not supported by the vendor of the charger"
+                    .to_string()
+            )
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use rust_ocpp::v2_0_1::datatypes::charging_profile_type::ChargingProfileType;
+use rust_ocpp::v2_0_1::datatypes::charging_schedule_period_type::ChargingSchedulePeriodType;
+use rust_ocpp::v2_0_1::datatypes::charging_schedule_type::ChargingScheduleType;
+use rust_ocpp::v2_0_1::enumerations::charging_profile_kind_enum_type::ChargingProfileKindEnumType;
+use rust_ocpp::v2_0_1::enumerations::charging_profile_purpose_enum_type::ChargingProfilePurposeEnumType;
+use rust_ocpp::v2_0_1::enumerations::charging_rate_unit_enum_type::ChargingRateUnitEnumType;
+use shared::data::ChargingRateUnit;
+
+/// Creates a ChargingStationExternalConstraints profile, which is how OCPP 2.0.1 expects limits
+/// from an energy management system to be expressed
+pub fn create_external_constraints_profile(
+    id: i32,
+    charging_rate_unit: ChargingRateUnit,
+    limit: f32,
+    number_phases: u8,
+    start_schedule: DateTime<Utc>,
+) -> Result<ChargingProfileType, Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let limit = Decimal::from_f32_retain(limit)
+        .map(|limit| limit.round_dp(1))
+        .ok_or_else(|| format!("{} is not a valid charging rate", limit))?;
+
+    Ok(ChargingProfileType {
+        id,
+        stack_level: 0,
+        charging_profile_purpose:
+            ChargingProfilePurposeEnumType::ChargingStationExternalConstraints,
+        charging_profile_kind: ChargingProfileKindEnumType::Absolute,
+        recurrency_kind: None,
+        valid_from: None,
+        valid_to: None,
+        transaction_id: None,
+        charging_schedule: vec![ChargingScheduleType {
+            id,
+            start_schedule: Some(start_schedule),
+            duration: None,
+            charging_rate_unit: match charging_rate_unit {
+                ChargingRateUnit::Ampere => ChargingRateUnitEnumType::A,
+                ChargingRateUnit::Watt => ChargingRateUnitEnumType::W,
+            },
+            min_charging_rate: None,
+            charging_schedule_period: vec![ChargingSchedulePeriodType {
+                start_period: 0,
+                limit,
+                number_phases: Some(number_phases as i32),
+                phase_to_use: None,
+            }],
+            sales_tariff: None,
+        }],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_creates_external_constraints_profile() {
+        let now = Utc::now();
+        let profile =
+            create_external_constraints_profile(7, ChargingRateUnit::Watt, 11000.0, 3, now)
+                .unwrap();
+        assert_eq!(
+            profile.charging_profile_purpose,
+            ChargingProfilePurposeEnumType::ChargingStationExternalConstraints
+        );
+        assert_eq!(profile.charging_schedule[0].start_schedule, Some(now));
+        assert_eq!(
+            profile.charging_schedule[0].charging_rate_unit,
+            ChargingRateUnitEnumType::W
+        );
+        assert_eq!(
+            profile.charging_schedule[0].charging_schedule_period[0].limit,
+            Decimal::new(11000, 0)
+        );
+    }
+
+    #[test]
+    fn test_invalid_limit() {
+        assert!(
+            create_external_constraints_profile(
+                7,
+                ChargingRateUnit::Ampere,
+                f32::NAN,
+                3,
+                Utc::now()
+            )
+            .is_err()
+        );
+    }
+}
@@ -0,0 +1,3 @@
+mod create_external_constraints_profile;
+
+pub use self::create_external_constraints_profile::create_external_constraints_profile;
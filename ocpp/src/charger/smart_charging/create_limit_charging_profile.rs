@@ -0,0 +1,37 @@
+use shared::data::{
+    ChargingProfile, ChargingProfileKind, ChargingProfilePurpose, ChargingRateUnit,
+    ChargingSchedule, ChargingSchedulePeriod,
+};
+
+/// Creates a profile with a single, indefinite period capping the evse at the given limit
+pub fn create_limit_charging_profile(
+    id: i32,
+    purpose: ChargingProfilePurpose,
+    stack_level: i32,
+    transaction_id: Option<String>,
+    charging_rate_unit: ChargingRateUnit,
+    limit: f32,
+    number_phases: u8,
+) -> ChargingProfile {
+    ChargingProfile {
+        id,
+        transaction_id,
+        stack_level,
+        purpose,
+        kind: ChargingProfileKind::Relative,
+        recurrency_kind: None,
+        valid_from: None,
+        valid_to: None,
+        schedule: ChargingSchedule {
+            duration: None,
+            start_schedule: None,
+            charging_rate_unit,
+            periods: vec![ChargingSchedulePeriod {
+                start_period: 0,
+                limit,
+                number_phases: Some(number_phases as i32),
+            }],
+            min_charging_rate: None,
+        },
+    }
+}
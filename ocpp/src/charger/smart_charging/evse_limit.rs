@@ -0,0 +1,56 @@
+/// A protocol independent power limit for a single evse
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EvseLimit {
+    Kilowatts(f32),
+    Amperes(f32),
+}
+
+impl EvseLimit {
+    /// Charging profile ids reserved for evse limits, the ocpp evse id is added to these
+    pub const TX_DEFAULT_PROFILE_ID_OFFSET: i32 = 1_000_000;
+    pub const TX_PROFILE_ID_OFFSET: i32 = 2_000_000;
+
+    /// The limit expressed as the current per phase
+    pub fn to_amperes(self, phases: u8, voltage: f32) -> f32 {
+        match self {
+            EvseLimit::Kilowatts(kilowatts) => kilowatts * 1000.0 / (voltage * phases as f32),
+            EvseLimit::Amperes(amperes) => amperes,
+        }
+    }
+
+    /// The limit expressed as the total power over all phases
+    pub fn to_watts(self, phases: u8, voltage: f32) -> f32 {
+        match self {
+            EvseLimit::Kilowatts(kilowatts) => kilowatts * 1000.0,
+            EvseLimit::Amperes(amperes) => amperes * voltage * phases as f32,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kilowatts_to_amperes_three_phase() {
+        let amperes = EvseLimit::Kilowatts(11.0).to_amperes(3, 230.0);
+        assert!((amperes - 15.94).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_kilowatts_to_amperes_single_phase() {
+        let amperes = EvseLimit::Kilowatts(7.36).to_amperes(1, 230.0);
+        assert!((amperes - 32.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_amperes_to_watts() {
+        assert_eq!(EvseLimit::Amperes(16.0).to_watts(3, 230.0), 11040.0);
+    }
+
+    #[test]
+    fn test_same_unit_is_unchanged() {
+        assert_eq!(EvseLimit::Amperes(16.0).to_amperes(3, 230.0), 16.0);
+        assert_eq!(EvseLimit::Kilowatts(22.0).to_watts(1, 230.0), 22000.0);
+    }
+}
@@ -0,0 +1,80 @@
+use shared::data::EvseData;
+
+/// Used when the charger has not reported any voltages yet
+pub const DEFAULT_PHASE_COUNT: u8 = 3;
+pub const DEFAULT_VOLTAGE: f32 = 230.0;
+
+/// Anything below this is considered noise on a disconnected phase
+const MIN_PHASE_VOLTAGE: f32 = 100.0;
+
+fn connected_phase_voltages(evse: &EvseData) -> Vec<f32> {
+    [&evse.voltage.l1, &evse.voltage.l2, &evse.voltage.l3]
+        .into_iter()
+        .filter(|metric| metric.measured_at.is_some() && metric.value >= MIN_PHASE_VOLTAGE)
+        .map(|metric| metric.value)
+        .collect()
+}
+
+/// The number of phases the evse is connected to, based on the reported voltages
+pub fn evse_phase_count(evse: &EvseData) -> u8 {
+    match connected_phase_voltages(evse).len() {
+        0 => DEFAULT_PHASE_COUNT,
+        count => count as u8,
+    }
+}
+
+/// The average phase to neutral voltage of the evse
+pub fn evse_voltage(evse: &EvseData) -> f32 {
+    let voltages = connected_phase_voltages(evse);
+    if voltages.is_empty() {
+        DEFAULT_VOLTAGE
+    } else {
+        voltages.iter().sum::<f32>() / voltages.len() as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use shared::data::Metric;
+
+    fn evse_with_voltages(l1: f32, l2: f32, l3: f32) -> EvseData {
+        let mut evse = EvseData::new(1);
+        let measured_at = Some(Utc::now());
+        evse.voltage.l1 = Metric {
+            value: l1,
+            measured_at,
+        };
+        evse.voltage.l2 = Metric {
+            value: l2,
+            measured_at,
+        };
+        evse.voltage.l3 = Metric {
+            value: l3,
+            measured_at,
+        };
+        evse
+    }
+
+    #[test]
+    fn test_defaults_without_measurements() {
+        let evse = EvseData::new(1);
+        assert_eq!(evse_phase_count(&evse), DEFAULT_PHASE_COUNT);
+        assert_eq!(evse_voltage(&evse), DEFAULT_VOLTAGE);
+    }
+
+    #[test]
+    fn test_three_phase() {
+        let evse = evse_with_voltages(229.0, 231.0, 230.0);
+        assert_eq!(evse_phase_count(&evse), 3);
+        assert_eq!(evse_voltage(&evse), 230.0);
+    }
+
+    #[test]
+    fn test_single_phase_ignores_noise() {
+        let evse = evse_with_voltages(240.0, 1.5, 0.0);
+        assert_eq!(evse_phase_count(&evse), 1);
+        assert_eq!(evse_voltage(&evse), 240.0);
+    }
+}
@@ -0,0 +1,7 @@
+mod create_limit_charging_profile;
+mod evse_limit;
+mod evse_supply;
+
+pub use self::create_limit_charging_profile::create_limit_charging_profile;
+pub use self::evse_limit::EvseLimit;
+pub use self::evse_supply::{evse_phase_count, evse_voltage};
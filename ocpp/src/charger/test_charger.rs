@@ -0,0 +1,32 @@
+use crate::charger::Charger;
+use crate::charger::vendor_profile::GenericProfile;
+use crate::data_transfer::DataTransferRegistry;
+use crate::event::EventManager;
+use crate::network_interface::{MockOcpp16NetworkInterfaceHandle, ProtocolHandle};
+use shared::Config;
+use shared::data::ChargerData;
+use shared::data_store::MockDataStore;
+use std::sync::Arc;
+
+/// A connected and authenticated OCPP 1.6 charger with the id `test`
+pub async fn charger(
+    handle: MockOcpp16NetworkInterfaceHandle,
+    data_store: MockDataStore,
+) -> Charger {
+    let config = Arc::new(Config::default());
+    Charger {
+        id: "test".to_string(),
+        handle: ProtocolHandle::Ocpp1_6(Arc::new(handle)),
+        config: Arc::clone(&config),
+        data_store: Arc::new(data_store),
+        authenticated: true,
+        data: ChargerData::new("test", &config),
+        password: None,
+        node_address: "http://localhost:50051".to_string(),
+        easee_master_password: None,
+        event_manager: EventManager::from_config(&config).await.unwrap(),
+        csms_server_client: None,
+        data_transfer_registry: DataTransferRegistry::new(),
+        vendor_profile: Box::new(GenericProfile),
+    }
+}
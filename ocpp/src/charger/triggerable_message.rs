@@ -0,0 +1,11 @@
+/// The messages an operator can ask a charger to send, independent of the protocol
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriggerableMessage {
+    BootNotification,
+    /// Sent as a LogStatusNotification by OCPP 2.0.1 chargers
+    DiagnosticsStatusNotification,
+    FirmwareStatusNotification,
+    Heartbeat,
+    MeterValues,
+    StatusNotification,
+}
@@ -0,0 +1,93 @@
+use crate::charger::vendor_profile::VendorProfile;
+use rust_ocpp::v1_6::types::{Measurand, MeterValue, UnitOfMeasure};
+
+pub struct AbbProfile {
+    /// The Terra AC wallboxes report the energy register in kWh, but leave out the unit
+    energy_register_in_kwh: bool,
+}
+
+impl AbbProfile {
+    pub fn from_model(model: &str) -> Self {
+        Self {
+            energy_register_in_kwh: model.trim().to_lowercase().starts_with("terra ac"),
+        }
+    }
+}
+
+impl VendorProfile for AbbProfile {
+    fn name(&self) -> &'static str {
+        "ABB"
+    }
+
+    fn normalize_meter_values(&self, meter_values: &mut [MeterValue]) {
+        if !self.energy_register_in_kwh {
+            return;
+        }
+        for sampled_value in meter_values
+            .iter_mut()
+            .flat_map(|meter_value| meter_value.sampled_value.iter_mut())
+        {
+            let is_energy_register = matches!(
+                sampled_value.measurand,
+                None | Some(Measurand::EnergyActiveImportRegister)
+            );
+            if is_energy_register && sampled_value.unit.is_none() {
+                sampled_value.unit = Some(UnitOfMeasure::KWh);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use rust_ocpp::v1_6::types::SampledValue;
+
+    fn meter_value(measurand: Option<Measurand>, unit: Option<UnitOfMeasure>) -> MeterValue {
+        MeterValue {
+            timestamp: Utc::now(),
+            sampled_value: vec![SampledValue {
+                value: "12.5".to_string(),
+                measurand,
+                unit,
+                ..Default::default()
+            }],
+        }
+    }
+
+    #[test]
+    fn test_terra_ac_energy_register_is_in_kwh() {
+        let mut meter_values = vec![
+            meter_value(None, None),
+            meter_value(Some(Measurand::EnergyActiveImportRegister), None),
+            meter_value(
+                Some(Measurand::EnergyActiveImportRegister),
+                Some(UnitOfMeasure::Wh),
+            ),
+            meter_value(Some(Measurand::PowerActiveImport), None),
+        ];
+        AbbProfile::from_model("Terra AC").normalize_meter_values(&mut meter_values);
+
+        let units: Vec<_> = meter_values
+            .iter()
+            .map(|meter_value| meter_value.sampled_value[0].unit.clone())
+            .collect();
+        assert_eq!(
+            units,
+            vec![
+                Some(UnitOfMeasure::KWh),
+                Some(UnitOfMeasure::KWh),
+                Some(UnitOfMeasure::Wh),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_other_models_are_left_alone() {
+        let mut meter_values = vec![meter_value(None, None)];
+        AbbProfile::from_model("Terra 54").normalize_meter_values(&mut meter_values);
+        assert_eq!(meter_values[0].sampled_value[0].unit, None);
+    }
+}
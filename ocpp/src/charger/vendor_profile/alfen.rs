@@ -0,0 +1,9 @@
+use crate::charger::vendor_profile::VendorProfile;
+
+pub struct AlfenProfile;
+
+impl VendorProfile for AlfenProfile {
+    fn name(&self) -> &'static str {
+        "Alfen"
+    }
+}
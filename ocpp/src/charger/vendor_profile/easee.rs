@@ -0,0 +1,13 @@
+use crate::charger::vendor_profile::{CredentialStrategy, VendorProfile};
+
+pub struct EaseeProfile;
+
+impl VendorProfile for EaseeProfile {
+    fn name(&self) -> &'static str {
+        "Easee"
+    }
+
+    fn credential_strategy(&self) -> CredentialStrategy {
+        CredentialStrategy::EaseeMasterPassword
+    }
+}
@@ -0,0 +1,10 @@
+use crate::charger::vendor_profile::VendorProfile;
+
+/// Chargers of vendors without known quirks
+pub struct GenericProfile;
+
+impl VendorProfile for GenericProfile {
+    fn name(&self) -> &'static str {
+        "Generic"
+    }
+}
@@ -0,0 +1,9 @@
+use crate::charger::vendor_profile::VendorProfile;
+
+pub struct KempowerProfile;
+
+impl VendorProfile for KempowerProfile {
+    fn name(&self) -> &'static str {
+        "Kempower"
+    }
+}
@@ -0,0 +1,17 @@
+mod abb;
+mod alfen;
+mod easee;
+mod generic;
+mod kempower;
+mod vendor_profile;
+mod wallbox;
+mod zaptec;
+
+pub use self::abb::AbbProfile;
+pub use self::alfen::AlfenProfile;
+pub use self::easee::EaseeProfile;
+pub use self::generic::GenericProfile;
+pub use self::kempower::KempowerProfile;
+pub use self::vendor_profile::{CredentialStrategy, VendorProfile, select_vendor_profile};
+pub use self::wallbox::WallboxProfile;
+pub use self::zaptec::ZaptecProfile;
@@ -0,0 +1,135 @@
+use crate::charger::vendor_profile::{
+    AbbProfile, AlfenProfile, EaseeProfile, GenericProfile, KempowerProfile, WallboxProfile,
+    ZaptecProfile,
+};
+use rust_ocpp::v1_6::types::MeterValue;
+use shared::data::Ocpp1_6Configuration;
+use std::collections::BTreeMap;
+use std::num::ParseIntError;
+
+/// How a charger proves its identity when it connects
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CredentialStrategy {
+    /// A random password is set through the AuthorizationKey configuration during onboarding
+    GeneratedPassword,
+    /// The charger always uses the master password shared by every Easee charger
+    EaseeMasterPassword,
+}
+
+/// Behavior that differs between charger vendors. Every method defaults to what the OCPP 1.6
+/// specification describes, so a profile only overrides the quirks of its vendor.
+pub trait VendorProfile: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    fn credential_strategy(&self) -> CredentialStrategy {
+        CredentialStrategy::GeneratedPassword
+    }
+
+    /// Configuration values that are pushed on top of the defaults of every charger
+    fn configuration_overrides(&self) -> BTreeMap<String, String> {
+        BTreeMap::new()
+    }
+
+    /// Keys the charger reports as writable, but rejects or ignores when changed
+    fn unsupported_configuration_keys(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// The number of EVSEs of the charger, OCPP 1.6 has one EVSE per connector
+    fn number_of_evses(
+        &self,
+        configuration: &Ocpp1_6Configuration,
+    ) -> Result<usize, ParseIntError> {
+        configuration
+            .get_configuration("NumberOfConnectors")
+            .and_then(|entry| entry.value.clone())
+            .unwrap_or("1".to_string())
+            .parse::<usize>()
+    }
+
+    /// Rewrites meter values the charger reports in a way that doesn't follow the specification
+    fn normalize_meter_values(&self, _meter_values: &mut [MeterValue]) {}
+
+    /// Applies the overrides and leaves out the unsupported keys of the configuration to push
+    fn apply_configuration_defaults(&self, entries: &mut BTreeMap<String, String>) {
+        entries.extend(self.configuration_overrides());
+        for key in self.unsupported_configuration_keys() {
+            entries.remove(*key);
+        }
+    }
+}
+
+/// Selects the profile from the vendor and model reported in the BootNotification
+pub fn select_vendor_profile(vendor: &str, model: &str) -> Box<dyn VendorProfile> {
+    let vendor = vendor.trim().to_lowercase();
+    if vendor.starts_with("easee") {
+        Box::new(EaseeProfile)
+    } else if vendor.starts_with("alfen") {
+        Box::new(AlfenProfile)
+    } else if vendor.starts_with("abb") {
+        Box::new(AbbProfile::from_model(model))
+    } else if vendor.starts_with("zaptec") {
+        Box::new(ZaptecProfile)
+    } else if vendor.starts_with("wallbox") || vendor.starts_with("wall box") {
+        Box::new(WallboxProfile)
+    } else if vendor.starts_with("kempower") {
+        Box::new(KempowerProfile)
+    } else {
+        Box::new(GenericProfile)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_selects_profile_from_vendor() {
+        assert_eq!(select_vendor_profile("Easee", "Home").name(), "Easee");
+        assert_eq!(select_vendor_profile("Alfen BV", "NG910").name(), "Alfen");
+        assert_eq!(select_vendor_profile("ABB", "Terra AC").name(), "ABB");
+        assert_eq!(select_vendor_profile("Zaptec", "Go").name(), "Zaptec");
+        assert_eq!(
+            select_vendor_profile("Wall Box Chargers", "PLP1").name(),
+            "Wallbox"
+        );
+        assert_eq!(
+            select_vendor_profile(" kempower ", "S-Series").name(),
+            "Kempower"
+        );
+        assert_eq!(select_vendor_profile("Acme", "X").name(), "Generic");
+    }
+
+    #[test]
+    fn test_apply_configuration_defaults() {
+        struct TestProfile;
+        impl VendorProfile for TestProfile {
+            fn name(&self) -> &'static str {
+                "Test"
+            }
+
+            fn configuration_overrides(&self) -> BTreeMap<String, String> {
+                BTreeMap::from([("MeterValueSampleInterval".to_string(), "60".to_string())])
+            }
+
+            fn unsupported_configuration_keys(&self) -> &'static [&'static str] {
+                &["ResetRetries"]
+            }
+        }
+
+        let mut entries = BTreeMap::from([
+            ("MeterValueSampleInterval".to_string(), "30".to_string()),
+            ("ResetRetries".to_string(), "3".to_string()),
+            ("HeartbeatInterval".to_string(), "300".to_string()),
+        ]);
+        TestProfile.apply_configuration_defaults(&mut entries);
+
+        assert_eq!(
+            entries,
+            BTreeMap::from([
+                ("HeartbeatInterval".to_string(), "300".to_string()),
+                ("MeterValueSampleInterval".to_string(), "60".to_string()),
+            ])
+        );
+    }
+}
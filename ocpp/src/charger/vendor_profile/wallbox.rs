@@ -0,0 +1,9 @@
+use crate::charger::vendor_profile::VendorProfile;
+
+pub struct WallboxProfile;
+
+impl VendorProfile for WallboxProfile {
+    fn name(&self) -> &'static str {
+        "Wallbox"
+    }
+}
@@ -0,0 +1,9 @@
+use crate::charger::vendor_profile::VendorProfile;
+
+pub struct ZaptecProfile;
+
+impl VendorProfile for ZaptecProfile {
+    fn name(&self) -> &'static str {
+        "Zaptec"
+    }
+}
@@ -0,0 +1,12 @@
+use crate::data_transfer::{DataTransferMessage, DataTransferReply};
+use std::error::Error;
+use std::fmt;
+
+/// Handles vendor specific DataTransfer messages sent by chargers
+#[async_trait::async_trait]
+pub trait DataTransferHandler: Send + Sync + fmt::Debug {
+    async fn handle(
+        &self,
+        message: &DataTransferMessage,
+    ) -> Result<DataTransferReply, Box<dyn Error + Send + Sync + 'static>>;
+}
@@ -0,0 +1,8 @@
+/// A DataTransfer sent by a charger, independent of the protocol
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataTransferMessage {
+    pub charger_id: String,
+    pub vendor_id: String,
+    pub message_id: Option<String>,
+    pub data: Option<String>,
+}
@@ -0,0 +1,184 @@
+use crate::data_transfer::{
+    DataTransferHandler, DataTransferMessage, DataTransferRegistryBuilder, DataTransferReply,
+    DataTransferStatus,
+};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tracing::error;
+
+/// Handlers by vendor id and optional message id
+pub type DataTransferHandlers = BTreeMap<(String, Option<String>), Box<dyn DataTransferHandler>>;
+
+/// Routes DataTransfer messages from chargers to the handler registered for their vendor and
+/// message id.
+///
+/// A handler registered without a message id handles every message of the vendor that has no
+/// more specific handler. Messages without any matching handler go to the fallback, if any.
+#[derive(Debug, Clone, Default)]
+pub struct DataTransferRegistry {
+    handlers: Arc<DataTransferHandlers>,
+    fallback: Option<Arc<dyn DataTransferHandler>>,
+}
+
+impl DataTransferRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(super) fn from_handlers(
+        handlers: DataTransferHandlers,
+        fallback: Option<Arc<dyn DataTransferHandler>>,
+    ) -> Self {
+        Self {
+            handlers: Arc::new(handlers),
+            fallback,
+        }
+    }
+
+    pub fn builder() -> DataTransferRegistryBuilder {
+        DataTransferRegistryBuilder::new()
+    }
+
+    pub async fn handle(&self, message: &DataTransferMessage) -> DataTransferReply {
+        let handler = match self.find_handler(&message.vendor_id, message.message_id.as_deref()) {
+            Ok(handler) => handler,
+            Err(status) => return DataTransferReply::new(status),
+        };
+
+        match handler.handle(message).await {
+            Ok(reply) => reply,
+            Err(error) => {
+                error!(
+                    charger_id = message.charger_id,
+                    vendor_id = message.vendor_id,
+                    message_id = message.message_id,
+                    error_message = error.to_string(),
+                    "data transfer handler failed"
+                );
+                DataTransferReply::new(DataTransferStatus::Rejected)
+            }
+        }
+    }
+
+    fn find_handler(
+        &self,
+        vendor_id: &str,
+        message_id: Option<&str>,
+    ) -> Result<&dyn DataTransferHandler, DataTransferStatus> {
+        let specific = message_id.and_then(|message_id| {
+            self.handlers
+                .get(&(vendor_id.to_string(), Some(message_id.to_string())))
+        });
+        let vendor = || self.handlers.get(&(vendor_id.to_string(), None));
+
+        if let Some(handler) = specific.or_else(vendor) {
+            return Ok(handler.as_ref());
+        }
+        if let Some(fallback) = &self.fallback {
+            return Ok(fallback.as_ref());
+        }
+
+        if self
+            .handlers
+            .keys()
+            .any(|(registered_vendor_id, _)| registered_vendor_id == vendor_id)
+        {
+            Err(DataTransferStatus::UnknownMessageId)
+        } else {
+            Err(DataTransferStatus::UnknownVendorId)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[derive(Debug)]
+    struct ReplyHandler(&'static str);
+
+    #[async_trait::async_trait]
+    impl DataTransferHandler for ReplyHandler {
+        async fn handle(
+            &self,
+            _message: &DataTransferMessage,
+        ) -> Result<DataTransferReply, Box<dyn Error + Send + Sync + 'static>> {
+            Ok(DataTransferReply {
+                status: DataTransferStatus::Accepted,
+                data: Some(self.0.to_string()),
+            })
+        }
+    }
+
+    #[derive(Debug)]
+    struct FailingHandler;
+
+    #[async_trait::async_trait]
+    impl DataTransferHandler for FailingHandler {
+        async fn handle(
+            &self,
+            _message: &DataTransferMessage,
+        ) -> Result<DataTransferReply, Box<dyn Error + Send + Sync + 'static>> {
+            Err("failed".into())
+        }
+    }
+
+    fn message(vendor_id: &str, message_id: Option<&str>) -> DataTransferMessage {
+        DataTransferMessage {
+            charger_id: "charger".to_string(),
+            vendor_id: vendor_id.to_string(),
+            message_id: message_id.map(str::to_string),
+            data: None,
+        }
+    }
+
+    fn registry() -> DataTransferRegistryBuilder {
+        DataTransferRegistry::builder()
+            .with_handler("com.vendor", Some("Meter"), Box::new(ReplyHandler("meter")))
+            .with_handler("com.vendor", None, Box::new(ReplyHandler("vendor")))
+            .with_handler("com.other", Some("Fail"), Box::new(FailingHandler))
+    }
+
+    #[tokio::test]
+    async fn test_the_most_specific_handler_is_used() {
+        let registry = registry().build();
+
+        let meter = registry.handle(&message("com.vendor", Some("Meter"))).await;
+        let other = registry.handle(&message("com.vendor", Some("Other"))).await;
+
+        assert_eq!(meter.data.as_deref(), Some("meter"));
+        assert_eq!(other.data.as_deref(), Some("vendor"));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_messages_are_reported() {
+        let registry = registry().build();
+
+        let unknown_message = registry.handle(&message("com.other", Some("Other"))).await;
+        let unknown_vendor = registry.handle(&message("com.unknown", None)).await;
+
+        assert_eq!(unknown_message.status, DataTransferStatus::UnknownMessageId);
+        assert_eq!(unknown_vendor.status, DataTransferStatus::UnknownVendorId);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_messages_go_to_the_fallback() {
+        let registry = registry()
+            .with_fallback(Box::new(ReplyHandler("fallback")))
+            .build();
+
+        let reply = registry.handle(&message("com.unknown", None)).await;
+
+        assert_eq!(reply.data.as_deref(), Some("fallback"));
+    }
+
+    #[tokio::test]
+    async fn test_failing_handlers_reject() {
+        let registry = registry().build();
+
+        let reply = registry.handle(&message("com.other", Some("Fail"))).await;
+
+        assert_eq!(reply.status, DataTransferStatus::Rejected);
+    }
+}
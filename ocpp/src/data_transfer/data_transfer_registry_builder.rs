@@ -0,0 +1,45 @@
+use crate::data_transfer::{DataTransferHandler, DataTransferHandlers, DataTransferRegistry};
+use std::sync::Arc;
+
+/// Collects the DataTransfer handlers before they are shared between chargers
+#[derive(Debug, Default)]
+pub struct DataTransferRegistryBuilder {
+    handlers: DataTransferHandlers,
+    fallback: Option<Arc<dyn DataTransferHandler>>,
+}
+
+impl DataTransferRegistryBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// No vendor extensions are built in yet, everything goes to the fallback
+    #[cfg(test)]
+    pub fn with_handler(
+        mut self,
+        vendor_id: &str,
+        message_id: Option<&str>,
+        handler: Box<dyn DataTransferHandler>,
+    ) -> Self {
+        tracing::info!(
+            vendor_id = vendor_id,
+            message_id = message_id,
+            "registering data transfer handler"
+        );
+        self.handlers.insert(
+            (vendor_id.to_string(), message_id.map(str::to_string)),
+            handler,
+        );
+        self
+    }
+
+    /// Sets the handler for messages no vendor handler is registered for
+    pub fn with_fallback(mut self, handler: Box<dyn DataTransferHandler>) -> Self {
+        self.fallback = Some(Arc::from(handler));
+        self
+    }
+
+    pub fn build(self) -> DataTransferRegistry {
+        DataTransferRegistry::from_handlers(self.handlers, self.fallback)
+    }
+}
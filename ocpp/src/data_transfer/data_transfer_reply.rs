@@ -0,0 +1,13 @@
+use crate::data_transfer::DataTransferStatus;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataTransferReply {
+    pub status: DataTransferStatus,
+    pub data: Option<String>,
+}
+
+impl DataTransferReply {
+    pub fn new(status: DataTransferStatus) -> Self {
+        Self { status, data: None }
+    }
+}
@@ -0,0 +1,85 @@
+use crate::ocpp_csms_server_client::data_transfer_response::DataTransferStatus as ClientDataTransferStatus;
+use rust_ocpp::v1_6::types::DataTransferStatus as Ocpp16DataTransferStatus;
+use rust_ocpp::v2_0_1::enumerations::data_transfer_status_enum_type::DataTransferStatusEnumType;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataTransferStatus {
+    Accepted,
+    Rejected,
+    UnknownMessageId,
+    UnknownVendorId,
+}
+
+impl From<DataTransferStatus> for Ocpp16DataTransferStatus {
+    fn from(status: DataTransferStatus) -> Self {
+        match status {
+            DataTransferStatus::Accepted => Ocpp16DataTransferStatus::Accepted,
+            DataTransferStatus::Rejected => Ocpp16DataTransferStatus::Rejected,
+            DataTransferStatus::UnknownMessageId => Ocpp16DataTransferStatus::UnknownMessageId,
+            DataTransferStatus::UnknownVendorId => Ocpp16DataTransferStatus::UnknownVendorId,
+        }
+    }
+}
+
+impl From<Ocpp16DataTransferStatus> for DataTransferStatus {
+    fn from(status: Ocpp16DataTransferStatus) -> Self {
+        match status {
+            Ocpp16DataTransferStatus::Accepted => DataTransferStatus::Accepted,
+            Ocpp16DataTransferStatus::Rejected => DataTransferStatus::Rejected,
+            Ocpp16DataTransferStatus::UnknownMessageId => DataTransferStatus::UnknownMessageId,
+            Ocpp16DataTransferStatus::UnknownVendorId => DataTransferStatus::UnknownVendorId,
+        }
+    }
+}
+
+impl From<DataTransferStatus> for DataTransferStatusEnumType {
+    fn from(status: DataTransferStatus) -> Self {
+        match status {
+            DataTransferStatus::Accepted => DataTransferStatusEnumType::Accepted,
+            DataTransferStatus::Rejected => DataTransferStatusEnumType::Rejected,
+            DataTransferStatus::UnknownMessageId => DataTransferStatusEnumType::UnknownMessageId,
+            DataTransferStatus::UnknownVendorId => DataTransferStatusEnumType::UnknownVendorId,
+        }
+    }
+}
+
+impl From<DataTransferStatusEnumType> for DataTransferStatus {
+    fn from(status: DataTransferStatusEnumType) -> Self {
+        match status {
+            DataTransferStatusEnumType::Accepted => DataTransferStatus::Accepted,
+            DataTransferStatusEnumType::Rejected => DataTransferStatus::Rejected,
+            DataTransferStatusEnumType::UnknownMessageId => DataTransferStatus::UnknownMessageId,
+            DataTransferStatusEnumType::UnknownVendorId => DataTransferStatus::UnknownVendorId,
+        }
+    }
+}
+
+impl From<ClientDataTransferStatus> for DataTransferStatus {
+    fn from(status: ClientDataTransferStatus) -> Self {
+        match status {
+            ClientDataTransferStatus::Accepted => DataTransferStatus::Accepted,
+            ClientDataTransferStatus::Unspecified | ClientDataTransferStatus::Rejected => {
+                DataTransferStatus::Rejected
+            }
+            ClientDataTransferStatus::UnknownMessageId => DataTransferStatus::UnknownMessageId,
+            ClientDataTransferStatus::UnknownVendorId => DataTransferStatus::UnknownVendorId,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backends_that_give_no_status_reject() {
+        assert_eq!(
+            DataTransferStatus::from(ClientDataTransferStatus::Unspecified),
+            DataTransferStatus::Rejected
+        );
+        assert_eq!(
+            DataTransferStatus::from(ClientDataTransferStatus::Accepted),
+            DataTransferStatus::Accepted
+        );
+    }
+}
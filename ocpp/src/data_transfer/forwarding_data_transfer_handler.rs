@@ -0,0 +1,60 @@
+use crate::data_transfer::{DataTransferHandler, DataTransferMessage, DataTransferReply};
+use crate::ocpp_csms_server_client;
+use crate::ocpp_csms_server_client::csms_server_client_client::CsmsServerClientClient;
+use crate::ocpp_csms_server_client::data_transfer_response;
+use std::error::Error;
+use std::time::Duration;
+use tokio::time::timeout;
+use tonic::transport::Channel;
+
+/// Relays DataTransfer messages to the csms server client, so the backend can implement vendor
+/// extensions without them being compiled into this service.
+///
+/// The backend gets part of the charger's message timeout to answer, so a slow backend is
+/// reported to the charger as Rejected rather than as a timed out call.
+#[derive(Debug, Clone)]
+pub struct ForwardingDataTransferHandler {
+    client: CsmsServerClientClient<Channel>,
+    timeout: Duration,
+}
+
+impl ForwardingDataTransferHandler {
+    pub fn new(client: CsmsServerClientClient<Channel>, message_timeout: Duration) -> Self {
+        Self {
+            client,
+            timeout: message_timeout * 4 / 5,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl DataTransferHandler for ForwardingDataTransferHandler {
+    async fn handle(
+        &self,
+        message: &DataTransferMessage,
+    ) -> Result<DataTransferReply, Box<dyn Error + Send + Sync + 'static>> {
+        let request = ocpp_csms_server_client::DataTransferRequest {
+            charger_id: message.charger_id.clone(),
+            vendor_id: message.vendor_id.clone(),
+            message_id: message.message_id.clone(),
+            data: message.data.clone(),
+        };
+
+        let response = timeout(self.timeout, self.client.clone().data_transfer(request))
+            .await
+            .map_err(|_| {
+                format!(
+                    "backend did not answer within {}ms",
+                    self.timeout.as_millis()
+                )
+            })??
+            .into_inner();
+
+        Ok(DataTransferReply {
+            status: data_transfer_response::DataTransferStatus::try_from(response.status)
+                .unwrap_or_default()
+                .into(),
+            data: response.data,
+        })
+    }
+}
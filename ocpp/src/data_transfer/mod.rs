@@ -0,0 +1,17 @@
+mod data_transfer_handler;
+mod data_transfer_registry;
+mod data_transfer_registry_builder;
+
+mod data_transfer_message;
+mod data_transfer_reply;
+mod data_transfer_status;
+mod forwarding_data_transfer_handler;
+
+pub use self::data_transfer_handler::DataTransferHandler;
+pub use self::data_transfer_registry::{DataTransferHandlers, DataTransferRegistry};
+pub use self::data_transfer_registry_builder::DataTransferRegistryBuilder;
+
+pub use data_transfer_message::DataTransferMessage;
+pub use data_transfer_reply::DataTransferReply;
+pub use data_transfer_status::DataTransferStatus;
+pub use forwarding_data_transfer_handler::ForwardingDataTransferHandler;
@@ -0,0 +1,16 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChargingProfileDriftCorrectedEvent {
+    pub charger_id: String,
+    pub evse_id: Option<Uuid>,
+    pub vendor: Option<String>,
+    pub model: Option<String>,
+    pub firmware_version: Option<String>,
+    /// The charging profiles that were missing on the charger, and have been re-applied
+    pub charging_profile_ids: Vec<i32>,
+    pub corrected_at: DateTime<Utc>,
+}
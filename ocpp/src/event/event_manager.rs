@@ -6,7 +6,7 @@ use tracing::info;
 
 #[derive(Debug, Clone)]
 pub struct EventManager {
-    event_handlers: Arc<Vec<Box<dyn EventHandler>>>,
+    event_handlers: Arc<Vec<Arc<dyn EventHandler>>>,
 }
 
 impl EventManager {
@@ -25,7 +25,67 @@ impl EventManager {
         })
     }
 
+    /// Adds a handler that lives inside this service rather than being configured. Clones made
+    /// before keep sending to the handlers they already had.
+    pub fn with_handler(self, handler: Box<dyn EventHandler>) -> Self {
+        let mut event_handlers: Vec<Arc<dyn EventHandler>> =
+            self.event_handlers.iter().cloned().collect();
+        event_handlers.push(Arc::from(handler));
+
+        EventManager {
+            event_handlers: Arc::new(event_handlers),
+        }
+    }
+
     pub async fn send_event(&self, payload: EventPayload) {
-        unimplemented!()
+        for handler in self.event_handlers.iter() {
+            handler.send_event(payload.clone()).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::{ChargingProfileDriftCorrectedEvent, EventPayload};
+    use chrono::Utc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Debug, Default)]
+    struct CountingHandler {
+        count: Arc<AtomicUsize>,
+    }
+
+    #[async_trait::async_trait]
+    impl EventHandler for CountingHandler {
+        async fn send_event(&self, _payload: EventPayload) {
+            self.count.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handler_can_be_added_to_shared_manager() {
+        let event_manager = EventManager::from_config(&Config::default()).await.unwrap();
+        let _shared = event_manager.clone();
+
+        let count = Arc::new(AtomicUsize::new(0));
+        let event_manager = event_manager.with_handler(Box::new(CountingHandler {
+            count: Arc::clone(&count),
+        }));
+        event_manager
+            .send_event(EventPayload::ChargingProfileDriftCorrectedEvent(
+                ChargingProfileDriftCorrectedEvent {
+                    charger_id: "test".to_string(),
+                    evse_id: None,
+                    vendor: None,
+                    model: None,
+                    firmware_version: None,
+                    charging_profile_ids: vec![1],
+                    corrected_at: Utc::now(),
+                },
+            ))
+            .await;
+
+        assert_eq!(count.load(Ordering::SeqCst), 1);
     }
 }
@@ -1,4 +1,5 @@
 use crate::event::TransactionStartedEvent;
+use crate::event::charging_profile_drift_corrected_event::ChargingProfileDriftCorrectedEvent;
 use crate::event::transaction_event::TransactionEvent;
 use crate::event::transaction_stopped_event::TransactionStoppedEvent;
 use chrono::{DateTime, Utc};
@@ -11,6 +12,7 @@ pub enum EventPayload {
     TransactionEvent(TransactionEvent),
     TransactionStartedEvent(TransactionStartedEvent),
     TransactionStoppedEvent(TransactionStoppedEvent),
+    ChargingProfileDriftCorrectedEvent(ChargingProfileDriftCorrectedEvent),
 }
 
 impl EventPayload {
@@ -19,6 +21,7 @@ impl EventPayload {
             EventPayload::TransactionEvent(event) => &event.timestamp,
             EventPayload::TransactionStartedEvent(event) => &event.started_at,
             EventPayload::TransactionStoppedEvent(event) => &event.stopped_at,
+            EventPayload::ChargingProfileDriftCorrectedEvent(event) => &event.corrected_at,
         }
     }
 
@@ -27,6 +30,7 @@ impl EventPayload {
             EventPayload::TransactionEvent(event) => &event.charger_id,
             EventPayload::TransactionStartedEvent(event) => &event.charger_id,
             EventPayload::TransactionStoppedEvent(event) => &event.charger_id,
+            EventPayload::ChargingProfileDriftCorrectedEvent(event) => &event.charger_id,
         }
     }
 }
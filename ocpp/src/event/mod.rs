@@ -1,6 +1,7 @@
 mod event_handler;
 mod event_manager;
 
+mod charging_profile_drift_corrected_event;
 mod charging_state;
 mod event_payload;
 mod evse_info;
@@ -14,8 +15,10 @@ mod transaction_info;
 mod transaction_started_event;
 mod transaction_stopped_event;
 
+pub use self::event_handler::EventHandler;
 pub use self::event_manager::EventManager;
 
+pub use charging_profile_drift_corrected_event::ChargingProfileDriftCorrectedEvent;
 pub use event_payload::EventPayload;
 pub use evse_info::EvseInfo;
 pub use meter_value::MeterValue;
@@ -0,0 +1,357 @@
+use chrono::{DateTime, Utc};
+use shared::data::Site;
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+/// How much headroom an evse gets above its measured current before its allocation is capped,
+/// so a car that is ramping up isn't starved by its own previous measurement
+const DEMAND_HEADROOM: f32 = 2.0;
+
+/// Remaining capacity on a phase below this is considered exhausted
+const EPSILON: f32 = 0.01;
+
+/// An evse with an ongoing transaction competing for the capacity of a site
+#[derive(Debug, Clone, PartialEq)]
+pub struct EvseLoad {
+    pub evse_id: Uuid,
+    /// The phases (0 = L1, 1 = L2, 2 = L3) of the site the evse draws current from
+    pub phases: Vec<usize>,
+    /// The highest current in amperes currently measured on any of the evse's phases
+    pub measured_current: f32,
+    /// The current in amperes the evse was given in the previous allocation
+    pub previous_allocation: Option<f32>,
+    pub transaction_started_at: DateTime<Utc>,
+}
+
+/// An evse without a transaction, which holds on to the minimum current so a car connecting to it
+/// can't overload the site before it has been rebalanced
+#[derive(Debug, Clone, PartialEq)]
+pub struct IdleEvse {
+    pub evse_id: Uuid,
+    /// The phases (0 = L1, 1 = L2, 2 = L3) of the site the evse could draw current from
+    pub phases: Vec<usize>,
+}
+
+/// Distributes the capacity of a site across the evses using max-min fairness per phase.
+///
+/// Every evse is first given the minimum current, in the order the transactions started, as long
+/// as all of its phases can fit it. Evses that don't fit are given 0A so the car pauses instead of
+/// tripping the fuse. Idle evses then reserve the minimum current in the same way, so a car that
+/// connects can start charging without exceeding the site. The remaining capacity is then filled evenly, while evses that drew clearly
+/// less than their previous allocation are capped at their measured current plus some headroom so
+/// the excess goes to cars that can use it.
+///
+/// If the site has a maximum phase imbalance, the difference between the most and least loaded
+/// phase is kept within it. Only the load of the evses is considered, as that is all we measure.
+pub fn allocate_site_current(
+    site: &Site,
+    loads: &[EvseLoad],
+    idle_evses: &[IdleEvse],
+) -> BTreeMap<Uuid, f32> {
+    let mut ordered: Vec<&EvseLoad> = loads.iter().collect();
+    ordered.sort_by_key(|load| load.transaction_started_at);
+
+    let mut phase_loads = [0.0; 3];
+    let mut allocations = BTreeMap::new();
+    let mut admitted = Vec::new();
+
+    for load in ordered {
+        if reserve_minimum(site, &mut phase_loads, &load.phases) {
+            admitted.push((
+                load,
+                site.min_current_per_evse,
+                demand_cap(load, site.min_current_per_evse),
+            ));
+        } else {
+            allocations.insert(load.evse_id, 0.0);
+        }
+    }
+
+    for idle_evse in idle_evses {
+        let allocation = if reserve_minimum(site, &mut phase_loads, &idle_evse.phases) {
+            site.min_current_per_evse
+        } else {
+            0.0
+        };
+        allocations.insert(idle_evse.evse_id, allocation);
+    }
+
+    let mut growing: Vec<usize> = (0..admitted.len()).collect();
+
+    loop {
+        growing.retain(|index| {
+            let (_, allocation, cap) = &admitted[*index];
+            *allocation + EPSILON < *cap
+        });
+        if growing.is_empty() {
+            break;
+        }
+
+        let mut evses_per_phase = [0usize; 3];
+        for index in &growing {
+            for phase in &admitted[*index].0.phases {
+                evses_per_phase[*phase] += 1;
+            }
+        }
+
+        // The largest equal increase all growing evses can get before a constraint is hit
+        let mut share = f32::INFINITY;
+        for index in &growing {
+            let (_, allocation, cap) = &admitted[*index];
+            share = share.min(*cap - *allocation);
+        }
+        for phase in 0..3 {
+            if evses_per_phase[phase] > 0 {
+                share = share.min(
+                    (site.max_current_per_phase - phase_loads[phase])
+                        / evses_per_phase[phase] as f32,
+                );
+            }
+        }
+        if let Some(max_imbalance) = site.max_phase_imbalance {
+            for (high, low) in phase_pairs() {
+                if evses_per_phase[high] > evses_per_phase[low] {
+                    share = share.min(
+                        (max_imbalance - (phase_loads[high] - phase_loads[low]))
+                            / (evses_per_phase[high] - evses_per_phase[low]) as f32,
+                    );
+                }
+            }
+        }
+        let share = share.max(0.0);
+
+        for index in &growing {
+            let (load, allocation, _) = &mut admitted[*index];
+            *allocation += share;
+            for phase in &load.phases {
+                phase_loads[*phase] += share;
+            }
+        }
+
+        // Evses on a phase that is now full, or that would increase a maxed out imbalance, stop
+        // growing, while the rest can continue using the capacity left on other phases
+        let full_phases: Vec<usize> = (0..3)
+            .filter(|phase| site.max_current_per_phase - phase_loads[*phase] <= EPSILON)
+            .collect();
+        let tight_pairs: Vec<(usize, usize)> = match site.max_phase_imbalance {
+            Some(max_imbalance) => phase_pairs()
+                .filter(|(high, low)| {
+                    evses_per_phase[*high] > evses_per_phase[*low]
+                        && max_imbalance - (phase_loads[*high] - phase_loads[*low]) <= EPSILON
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+        growing.retain(|index| {
+            let phases = &admitted[*index].0.phases;
+            !phases.iter().any(|phase| full_phases.contains(phase))
+                && !tight_pairs
+                    .iter()
+                    .any(|(high, low)| phases.contains(high) && !phases.contains(low))
+        });
+    }
+
+    for (load, allocation, _) in admitted {
+        allocations.insert(load.evse_id, (allocation * 10.0).floor() / 10.0);
+    }
+
+    allocations
+}
+
+/// Adds the minimum current to the phases if the site can fit it, returning if it did
+fn reserve_minimum(site: &Site, phase_loads: &mut [f32; 3], phases: &[usize]) -> bool {
+    let mut with_load = *phase_loads;
+    for phase in phases {
+        with_load[*phase] += site.min_current_per_evse;
+    }
+    let fits = !phases.is_empty()
+        && with_load
+            .iter()
+            .all(|phase_load| *phase_load <= site.max_current_per_phase + EPSILON)
+        && site
+            .max_phase_imbalance
+            .is_none_or(|max_imbalance| imbalance(&with_load) <= max_imbalance + EPSILON);
+    if fits {
+        *phase_loads = with_load;
+    }
+    fits
+}
+
+fn imbalance(phase_loads: &[f32; 3]) -> f32 {
+    let max = phase_loads.iter().copied().fold(f32::MIN, f32::max);
+    let min = phase_loads.iter().copied().fold(f32::MAX, f32::min);
+    max - min
+}
+
+/// Every ordered pair of distinct phases
+fn phase_pairs() -> impl Iterator<Item = (usize, usize)> {
+    (0..3).flat_map(|high| {
+        (0..3)
+            .filter(move |low| *low != high)
+            .map(move |low| (high, low))
+    })
+}
+
+fn demand_cap(load: &EvseLoad, min_current_per_evse: f32) -> f32 {
+    match load.previous_allocation {
+        Some(previous) if load.measured_current + DEMAND_HEADROOM < previous => {
+            (load.measured_current + DEMAND_HEADROOM).max(min_current_per_evse)
+        }
+        _ => f32::INFINITY,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn site(max_current_per_phase: f32, max_phase_imbalance: Option<f32>) -> Site {
+        let mut site = Site::new("Test", max_current_per_phase);
+        site.max_phase_imbalance = max_phase_imbalance;
+        site
+    }
+
+    fn load(phases: Vec<usize>, minutes_ago: i64) -> EvseLoad {
+        EvseLoad {
+            evse_id: Uuid::new_v4(),
+            phases,
+            measured_current: 0.0,
+            previous_allocation: None,
+            transaction_started_at: Utc::now() - Duration::minutes(minutes_ago),
+        }
+    }
+
+    #[test]
+    fn a_single_evse_gets_the_whole_site() {
+        let loads = vec![load(vec![0, 1, 2], 0)];
+
+        let allocations = allocate_site_current(&site(32.0, None), &loads, &[]);
+
+        assert_eq!(allocations[&loads[0].evse_id], 32.0);
+    }
+
+    #[test]
+    fn the_capacity_is_split_evenly() {
+        let loads = vec![load(vec![0, 1, 2], 2), load(vec![0, 1, 2], 1)];
+
+        let allocations = allocate_site_current(&site(32.0, None), &loads, &[]);
+
+        assert_eq!(allocations[&loads[0].evse_id], 16.0);
+        assert_eq!(allocations[&loads[1].evse_id], 16.0);
+    }
+
+    #[test]
+    fn the_latest_transactions_are_paused_when_the_minimum_does_not_fit() {
+        let loads = vec![
+            load(vec![0, 1, 2], 5),
+            load(vec![0, 1, 2], 4),
+            load(vec![0, 1, 2], 3),
+            load(vec![0, 1, 2], 2),
+            load(vec![0, 1, 2], 1),
+        ];
+
+        let allocations = allocate_site_current(&site(25.0, None), &loads, &[]);
+
+        for load in &loads[..4] {
+            assert_eq!(allocations[&load.evse_id], 6.2);
+        }
+        assert_eq!(allocations[&loads[4].evse_id], 0.0);
+    }
+
+    #[test]
+    fn unused_capacity_is_given_to_evses_that_can_use_it() {
+        let mut slow = load(vec![0, 1, 2], 2);
+        slow.previous_allocation = Some(16.0);
+        slow.measured_current = 8.0;
+        let fast = load(vec![0, 1, 2], 1);
+        let loads = vec![slow, fast];
+
+        let allocations = allocate_site_current(&site(32.0, None), &loads, &[]);
+
+        assert_eq!(allocations[&loads[0].evse_id], 10.0);
+        assert_eq!(allocations[&loads[1].evse_id], 22.0);
+    }
+
+    #[test]
+    fn per_phase_limits_are_respected() {
+        let loads = vec![load(vec![0], 3), load(vec![0], 2), load(vec![0, 1, 2], 1)];
+
+        let allocations = allocate_site_current(&site(32.0, None), &loads, &[]);
+
+        let l1: f32 = loads.iter().map(|load| allocations[&load.evse_id]).sum();
+        assert!(l1 <= 32.0);
+        assert_eq!(allocations[&loads[0].evse_id], 10.6);
+        assert_eq!(allocations[&loads[2].evse_id], 10.6);
+    }
+
+    #[test]
+    fn capacity_on_other_phases_is_not_wasted() {
+        let loads = vec![load(vec![0], 2), load(vec![1], 1)];
+
+        let allocations = allocate_site_current(&site(16.0, None), &loads, &[]);
+
+        assert_eq!(allocations[&loads[0].evse_id], 16.0);
+        assert_eq!(allocations[&loads[1].evse_id], 16.0);
+    }
+
+    #[test]
+    fn phase_imbalance_is_limited() {
+        let loads = vec![load(vec![0], 2), load(vec![0, 1, 2], 1)];
+
+        let allocations = allocate_site_current(&site(32.0, Some(10.0)), &loads, &[]);
+
+        assert_eq!(allocations[&loads[0].evse_id], 10.0);
+        assert_eq!(allocations[&loads[1].evse_id], 22.0);
+    }
+
+    #[test]
+    fn cars_are_paused_when_the_imbalance_would_be_exceeded() {
+        let loads = vec![load(vec![0], 2), load(vec![0], 1)];
+
+        let allocations = allocate_site_current(&site(32.0, Some(8.0)), &loads, &[]);
+
+        assert_eq!(allocations[&loads[0].evse_id], 8.0);
+        assert_eq!(allocations[&loads[1].evse_id], 0.0);
+    }
+
+    #[test]
+    fn balanced_single_phase_cars_can_use_the_whole_site() {
+        let loads = vec![load(vec![0], 3), load(vec![1], 2), load(vec![2], 1)];
+
+        let allocations = allocate_site_current(&site(16.0, Some(6.0)), &loads, &[]);
+
+        for load in &loads {
+            assert_eq!(allocations[&load.evse_id], 16.0);
+        }
+    }
+
+    #[test]
+    fn idle_evses_keep_the_minimum_reserved() {
+        let loads = vec![load(vec![0, 1, 2], 1)];
+        let idle_evses = vec![IdleEvse {
+            evse_id: Uuid::new_v4(),
+            phases: vec![0, 1, 2],
+        }];
+
+        let allocations = allocate_site_current(&site(32.0, None), &loads, &idle_evses);
+
+        assert_eq!(allocations[&loads[0].evse_id], 26.0);
+        assert_eq!(allocations[&idle_evses[0].evse_id], 6.0);
+    }
+
+    #[test]
+    fn idle_evses_that_dont_fit_get_nothing() {
+        let loads = vec![load(vec![0, 1, 2], 2), load(vec![0, 1, 2], 1)];
+        let idle_evses = vec![IdleEvse {
+            evse_id: Uuid::new_v4(),
+            phases: vec![0],
+        }];
+
+        let allocations = allocate_site_current(&site(12.0, None), &loads, &idle_evses);
+
+        assert_eq!(allocations[&loads[0].evse_id], 6.0);
+        assert_eq!(allocations[&loads[1].evse_id], 6.0);
+        assert_eq!(allocations[&idle_evses[0].evse_id], 0.0);
+    }
+}
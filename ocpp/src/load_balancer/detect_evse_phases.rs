@@ -0,0 +1,128 @@
+use crate::charger::evse_phase_count;
+use crate::load_balancer::PhaseRotation;
+use shared::data::EvseData;
+
+/// A phase drawing less than this is not considered to be used by the car
+const MIN_ACTIVE_PHASE_CURRENT: f32 = 1.0;
+
+/// The site phases (0 = L1, 1 = L2, 2 = L3) the car connected to the evse is loading from.
+///
+/// A car that is drawing current is identified by its per phase current, so a single phase car is
+/// only accounted on the phase it is actually using. A car that stopped drawing keeps the phases
+/// it was last detected on during the transaction, otherwise a paused single phase car would be
+/// accounted on every phase and lose the capacity it needs to resume. Cars that haven't started
+/// drawing yet are assumed to use every connected phase, as we can't know which one a single phase
+/// car will pick.
+pub fn detect_evse_phases(
+    evse: &EvseData,
+    rotation: PhaseRotation,
+    previous_phases: Option<&[usize]>,
+) -> Vec<usize> {
+    let active_phases: Vec<usize> = [
+        &evse.ampere_output.l1,
+        &evse.ampere_output.l2,
+        &evse.ampere_output.l3,
+    ]
+    .into_iter()
+    .enumerate()
+    .filter(|(_, metric)| metric.measured_at.is_some() && metric.value >= MIN_ACTIVE_PHASE_CURRENT)
+    .map(|(phase, _)| phase)
+    .collect();
+
+    let charger_phases = match previous_phases {
+        Some(previous_phases) if active_phases.is_empty() => return previous_phases.to_vec(),
+        None if active_phases.is_empty() => (0..evse_phase_count(evse) as usize).collect(),
+        _ => active_phases,
+    };
+
+    let mut site_phases: Vec<usize> = charger_phases
+        .into_iter()
+        .map(|phase| rotation.site_phase(phase))
+        .collect();
+    site_phases.sort();
+    site_phases
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use shared::data::Metric;
+
+    fn metric(value: f32) -> Metric<f32> {
+        Metric {
+            value,
+            measured_at: Some(Utc::now()),
+        }
+    }
+
+    #[test]
+    fn test_single_phase_car_is_detected() {
+        let mut evse = EvseData::new(1);
+        evse.ampere_output.l1 = metric(0.0);
+        evse.ampere_output.l2 = metric(15.8);
+        evse.ampere_output.l3 = metric(0.2);
+
+        assert_eq!(
+            detect_evse_phases(&evse, PhaseRotation::STANDARD, None),
+            vec![1]
+        );
+    }
+
+    #[test]
+    fn test_three_phase_car_is_detected() {
+        let mut evse = EvseData::new(1);
+        evse.ampere_output.l1 = metric(10.0);
+        evse.ampere_output.l2 = metric(10.1);
+        evse.ampere_output.l3 = metric(9.9);
+
+        assert_eq!(
+            detect_evse_phases(&evse, PhaseRotation::STANDARD, None),
+            vec![0, 1, 2]
+        );
+    }
+
+    #[test]
+    fn test_rotation_is_applied() {
+        let mut evse = EvseData::new(1);
+        evse.ampere_output.l1 = metric(16.0);
+
+        let rotation = PhaseRotation::parse("TRS").unwrap();
+
+        assert_eq!(detect_evse_phases(&evse, rotation, None), vec![2]);
+    }
+
+    #[test]
+    fn test_idle_car_reserves_all_connected_phases() {
+        let evse = EvseData::new(1);
+
+        assert_eq!(
+            detect_evse_phases(&evse, PhaseRotation::STANDARD, None),
+            vec![0, 1, 2]
+        );
+    }
+
+    #[test]
+    fn test_paused_car_keeps_its_previous_phases() {
+        let mut evse = EvseData::new(1);
+        evse.ampere_output.l1 = metric(0.0);
+        evse.ampere_output.l2 = metric(0.1);
+        evse.ampere_output.l3 = metric(0.0);
+
+        assert_eq!(
+            detect_evse_phases(&evse, PhaseRotation::STANDARD, Some(&[1])),
+            vec![1]
+        );
+    }
+
+    #[test]
+    fn test_drawing_car_replaces_previous_phases() {
+        let mut evse = EvseData::new(1);
+        evse.ampere_output.l3 = metric(16.0);
+
+        assert_eq!(
+            detect_evse_phases(&evse, PhaseRotation::STANDARD, Some(&[1])),
+            vec![2]
+        );
+    }
+}
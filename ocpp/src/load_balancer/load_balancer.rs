@@ -0,0 +1,266 @@
+use crate::charger::{ChargerPool, EvseLimit, evse_phase_count, evse_voltage};
+use crate::load_balancer::{
+    EvseLoad, IdleEvse, LoadBalancerEventHandler, PhaseRotation, allocate_site_current,
+    detect_evse_phases,
+};
+use shared::Config;
+use shared::data::{
+    ChargingRateUnit, DesiredChargingProfile, EvseData, Ocpp1_6Configuration, Site,
+};
+use shared::data_store::DataStore;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::select;
+use tokio::sync::Mutex;
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel};
+use tokio::time::interval;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+/// Changes smaller than this are not pushed to the charger, to avoid flooding it with profiles
+const MIN_LIMIT_CHANGE: f32 = 0.5;
+
+/// An evse of a site together with the limit it was last given
+struct SiteEvse {
+    charger_id: String,
+    evse_id: Uuid,
+    previous_allocation: Option<f32>,
+}
+
+/// Periodically redistributes the grid capacity of every site across its ongoing transactions.
+///
+/// Every node computes the allocation for the whole site, but only pushes limits to the chargers
+/// connected to itself. The previous allocations are read from the persisted evse limit profiles,
+/// so all nodes arrive at the same result.
+pub struct LoadBalancer {
+    config: Arc<Config>,
+    data_store: Arc<dyn DataStore + Send + Sync>,
+    charger_pool: ChargerPool,
+    sender: UnboundedSender<String>,
+    receiver: Mutex<UnboundedReceiver<String>>,
+    /// The phases each evse was last detected on, together with the transaction they belong to.
+    /// A node that hasn't seen the car draw current yet falls back to every connected phase.
+    detected_phases: Mutex<BTreeMap<Uuid, (Uuid, Vec<usize>)>>,
+}
+
+impl LoadBalancer {
+    pub fn new(
+        config: Arc<Config>,
+        data_store: Arc<dyn DataStore + Send + Sync>,
+        charger_pool: &ChargerPool,
+    ) -> Self {
+        let (sender, receiver) = unbounded_channel();
+        Self {
+            config,
+            data_store,
+            charger_pool: charger_pool.clone(),
+            sender,
+            receiver: Mutex::new(receiver),
+            detected_phases: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    pub fn event_handler(&self) -> LoadBalancerEventHandler {
+        LoadBalancerEventHandler::new(self.sender.clone())
+    }
+
+    pub async fn start(&self) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let interval_secs = self.config.load_balancer_interval_secs();
+        info!(interval_secs = interval_secs, "starting load balancer");
+
+        let mut interval = interval(Duration::from_secs(interval_secs));
+        let mut receiver = self.receiver.lock().await;
+
+        loop {
+            select! {
+                _ = interval.tick() => {
+                    match self.data_store.get_sites().await {
+                        Ok(sites) => {
+                            for site in sites {
+                                self.balance_site(&site).await;
+                            }
+                        }
+                        Err(error) => {
+                            error!(error_message = error.to_string(), "could not get sites");
+                        }
+                    }
+                }
+                Some(charger_id) = receiver.recv() => {
+                    match self.data_store.get_site_by_charger_id(&charger_id).await {
+                        Ok(Some(site)) => self.balance_site(&site).await,
+                        Ok(None) => {}
+                        Err(error) => {
+                            error!(
+                                charger_id = charger_id,
+                                error_message = error.to_string(),
+                                "could not get site of charger"
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    async fn balance_site(&self, site: &Site) {
+        if let Err(error) = self.try_balance_site(site).await {
+            error!(
+                site_id = site.id.to_string(),
+                error_message = error.to_string(),
+                "could not balance site"
+            );
+        }
+    }
+
+    async fn try_balance_site(
+        &self,
+        site: &Site,
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let mut has_local_charger = false;
+        for charger_id in &site.charger_ids {
+            has_local_charger |= self.charger_pool.get(charger_id).await.is_some();
+        }
+        if !has_local_charger {
+            return Ok(());
+        }
+
+        let mut evses = Vec::new();
+        let mut loads = Vec::new();
+        let mut idle_evses = Vec::new();
+        let mut detected_phases = self.detected_phases.lock().await;
+
+        for charger_id in &site.charger_ids {
+            let Some(charger) = self.data_store.get_charger_data_by_id(charger_id).await? else {
+                warn!(
+                    site_id = site.id.to_string(),
+                    charger_id = charger_id,
+                    "site contains unknown charger"
+                );
+                continue;
+            };
+            let desired_profiles = self
+                .data_store
+                .get_desired_charging_profiles(charger_id)
+                .await?;
+
+            let phase_rotation = charger
+                .ocpp1_6configuration
+                .as_ref()
+                .and_then(|configuration| {
+                    configuration.get_configuration(Ocpp1_6Configuration::CONNECTOR_PHASE_ROTATION)
+                })
+                .and_then(|value| value.value.as_deref());
+
+            for evse in &charger.evses {
+                let rotation = phase_rotation
+                    .and_then(|value| {
+                        PhaseRotation::from_ocpp_1_6_configuration(value, evse.ocpp_evse_id)
+                    })
+                    .unwrap_or_default();
+                let previous_allocation = previous_allocation(evse, &desired_profiles);
+
+                if let Some(transaction) = self
+                    .data_store
+                    .get_ongoing_transaction(charger_id, evse.id)
+                    .await?
+                {
+                    let previous_phases = detected_phases
+                        .get(&evse.id)
+                        .filter(|(transaction_id, _)| *transaction_id == transaction.id)
+                        .map(|(_, phases)| phases.as_slice());
+                    let phases = detect_evse_phases(evse, rotation, previous_phases);
+                    detected_phases.insert(evse.id, (transaction.id, phases.clone()));
+
+                    loads.push(EvseLoad {
+                        evse_id: evse.id,
+                        phases,
+                        measured_current: measured_current(evse),
+                        previous_allocation,
+                        transaction_started_at: transaction.start_time,
+                    });
+                } else {
+                    detected_phases.remove(&evse.id);
+                    idle_evses.push(IdleEvse {
+                        evse_id: evse.id,
+                        phases: detect_evse_phases(evse, rotation, None),
+                    });
+                }
+
+                evses.push(SiteEvse {
+                    charger_id: charger_id.clone(),
+                    evse_id: evse.id,
+                    previous_allocation,
+                });
+            }
+        }
+
+        drop(detected_phases);
+
+        let allocations = allocate_site_current(site, &loads, &idle_evses);
+
+        for evse in evses {
+            let Some(allocation) = allocations.get(&evse.evse_id).copied() else {
+                continue;
+            };
+
+            if evse
+                .previous_allocation
+                .is_some_and(|previous| (previous - allocation).abs() < MIN_LIMIT_CHANGE)
+            {
+                continue;
+            }
+
+            let Some(charger) = self.charger_pool.get(&evse.charger_id).await else {
+                continue;
+            };
+            let mut lock = charger.lock().await;
+            if let Err(status) = lock
+                .set_evse_limit(evse.evse_id, Some(EvseLimit::Amperes(allocation)))
+                .await
+            {
+                warn!(
+                    site_id = site.id.to_string(),
+                    charger_id = evse.charger_id,
+                    evse_id = evse.evse_id.to_string(),
+                    error_message = status.message(),
+                    "could not apply load balancing limit"
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The current per phase of the evse limit profile we last set on the evse
+fn previous_allocation(
+    evse: &EvseData,
+    desired_profiles: &[DesiredChargingProfile],
+) -> Option<f32> {
+    let profile_id = EvseLimit::TX_DEFAULT_PROFILE_ID_OFFSET + evse.ocpp_evse_id as i32;
+    let desired = desired_profiles
+        .iter()
+        .find(|desired| desired.profile.id == profile_id)?;
+    let limit = desired.profile.schedule.periods.first()?.limit;
+
+    Some(match desired.profile.schedule.charging_rate_unit {
+        ChargingRateUnit::Ampere => limit,
+        ChargingRateUnit::Watt => EvseLimit::Kilowatts(limit / 1000.0)
+            .to_amperes(evse_phase_count(evse), evse_voltage(evse)),
+    })
+}
+
+/// The highest current measured on any phase of the evse
+fn measured_current(evse: &EvseData) -> f32 {
+    [
+        &evse.ampere_output.l1,
+        &evse.ampere_output.l2,
+        &evse.ampere_output.l3,
+    ]
+    .into_iter()
+    .filter(|metric| metric.measured_at.is_some())
+    .map(|metric| metric.value)
+    .fold(0.0, f32::max)
+}
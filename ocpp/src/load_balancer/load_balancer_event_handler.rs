@@ -0,0 +1,32 @@
+use crate::event::{EventHandler, EventPayload};
+use tokio::sync::mpsc::UnboundedSender;
+use tracing::warn;
+
+/// Asks the load balancer to redistribute the site of a charger whenever a transaction
+/// starts or stops on it
+#[derive(Debug, Clone)]
+pub struct LoadBalancerEventHandler {
+    sender: UnboundedSender<String>,
+}
+
+impl LoadBalancerEventHandler {
+    pub fn new(sender: UnboundedSender<String>) -> Self {
+        Self { sender }
+    }
+}
+
+#[async_trait::async_trait]
+impl EventHandler for LoadBalancerEventHandler {
+    async fn send_event(&self, payload: EventPayload) {
+        let changes_load = matches!(
+            payload,
+            EventPayload::TransactionStartedEvent(_) | EventPayload::TransactionStoppedEvent(_)
+        );
+        if changes_load && self.sender.send(payload.charger_id().to_string()).is_err() {
+            warn!(
+                charger_id = payload.charger_id(),
+                "load balancer is not running, could not rebalance site"
+            );
+        }
+    }
+}
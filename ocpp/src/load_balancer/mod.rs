@@ -0,0 +1,11 @@
+mod allocate_site_current;
+mod detect_evse_phases;
+mod load_balancer;
+mod load_balancer_event_handler;
+mod phase_rotation;
+
+pub use self::allocate_site_current::{EvseLoad, IdleEvse, allocate_site_current};
+pub use self::detect_evse_phases::detect_evse_phases;
+pub use self::load_balancer::LoadBalancer;
+pub use self::load_balancer_event_handler::LoadBalancerEventHandler;
+pub use self::phase_rotation::PhaseRotation;
@@ -0,0 +1,101 @@
+/// How the phases of a charger are wired to the phases of the site, as reported by the OCPP 1.6
+/// `ConnectorPhaseRotation` configuration key
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PhaseRotation {
+    /// The site phase (0 = L1, 1 = L2, 2 = L3) each charger phase is connected to
+    site_phases: [usize; 3],
+}
+
+impl PhaseRotation {
+    pub const STANDARD: PhaseRotation = PhaseRotation {
+        site_phases: [0, 1, 2],
+    };
+
+    /// Parses a rotation like `RST` or `TRS`, where R, S and T are the site phases L1, L2 and L3
+    /// connected to the charger phases L1, L2 and L3 respectively
+    pub fn parse(value: &str) -> Option<Self> {
+        let mut site_phases = [0; 3];
+        let mut chars = value.trim().chars();
+        for site_phase in site_phases.iter_mut() {
+            *site_phase = match chars.next()? {
+                'R' => 0,
+                'S' => 1,
+                'T' => 2,
+                _ => return None,
+            };
+        }
+        if chars.next().is_some()
+            || site_phases[0] == site_phases[1]
+            || site_phases[1] == site_phases[2]
+            || site_phases[0] == site_phases[2]
+        {
+            return None;
+        }
+        Some(Self { site_phases })
+    }
+
+    /// Finds the rotation of a connector in a `ConnectorPhaseRotation` value like
+    /// `0.RST,1.RTS,2.STR`, falling back to the rotation of connector 0 which applies to the
+    /// whole charger
+    pub fn from_ocpp_1_6_configuration(value: &str, connector_id: u32) -> Option<Self> {
+        let rotations: Vec<(u32, &str)> = value
+            .split(',')
+            .filter_map(|entry| {
+                let (id, rotation) = entry.trim().split_once('.')?;
+                Some((id.parse().ok()?, rotation))
+            })
+            .collect();
+
+        [connector_id, 0].into_iter().find_map(|id| {
+            rotations
+                .iter()
+                .find(|(rotation_id, _)| *rotation_id == id)
+                .and_then(|(_, rotation)| Self::parse(rotation))
+        })
+    }
+
+    pub fn site_phase(&self, charger_phase: usize) -> usize {
+        self.site_phases[charger_phase]
+    }
+}
+
+impl Default for PhaseRotation {
+    fn default() -> Self {
+        Self::STANDARD
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rotation() {
+        let rotation = PhaseRotation::parse("TRS").unwrap();
+
+        assert_eq!(rotation.site_phase(0), 2);
+        assert_eq!(rotation.site_phase(1), 0);
+        assert_eq!(rotation.site_phase(2), 1);
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_rotations() {
+        assert_eq!(PhaseRotation::parse("Unknown"), None);
+        assert_eq!(PhaseRotation::parse("NotApplicable"), None);
+        assert_eq!(PhaseRotation::parse("RRS"), None);
+    }
+
+    #[test]
+    fn test_connector_rotation_from_configuration() {
+        let rotation = PhaseRotation::from_ocpp_1_6_configuration("0.RST,1.RTS,2.STR", 2);
+
+        assert_eq!(rotation, PhaseRotation::parse("STR"));
+    }
+
+    #[test]
+    fn test_falls_back_to_connector_zero() {
+        let rotation = PhaseRotation::from_ocpp_1_6_configuration("0.TSR,1.Unknown", 1);
+
+        assert_eq!(rotation, PhaseRotation::parse("TSR"));
+    }
+}
@@ -1,14 +1,21 @@
 #![allow(clippy::module_inception)]
 
 mod charger;
+mod data_transfer;
 mod server;
 
 mod event;
 
+mod load_balancer;
+
 mod network_interface;
 
+mod ocpp1_6_security;
+
 use crate::charger::{ChargerFactory, ChargerPool};
+use crate::data_transfer::{DataTransferRegistry, ForwardingDataTransferHandler};
 use crate::event::EventManager;
+use crate::load_balancer::LoadBalancer;
 use crate::network_interface::json::OcppJsonNetworkInterface;
 use crate::ocpp_csms_server_client::csms_server_client_client::CsmsServerClientClient;
 use crate::server::start_server;
@@ -16,6 +23,7 @@ use shared::data_store::MongoDataStore;
 use shared::{configure_tracing, read_config};
 use std::env;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::try_join;
 use tracing::{info, warn};
 
@@ -75,10 +83,36 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>
         }
     };
 
-    let event_manager = EventManager::from_config(&config).await?;
-
     let charger_pool = ChargerPool::new();
 
+    let load_balancer = LoadBalancer::new(
+        Arc::clone(&config),
+        Arc::clone(&data_store) as Arc<dyn shared::data_store::DataStore + Send + Sync>,
+        &charger_pool,
+    );
+
+    let event_manager = EventManager::from_config(&config)
+        .await?
+        .with_handler(Box::new(load_balancer.event_handler()));
+
+    // Vendor specific handlers are registered here, anything else is relayed to the client
+    let data_transfer_registry = match &csms_server_client {
+        Some(client) => DataTransferRegistry::builder()
+            .with_fallback(Box::new(ForwardingDataTransferHandler::new(
+                client.clone(),
+                Duration::from_secs(
+                    config
+                        .ocpp
+                        .clone()
+                        .unwrap_or_default()
+                        .message_timeout_secs
+                        .unwrap_or(30),
+                ),
+            )))
+            .build(),
+        None => DataTransferRegistry::new(),
+    };
+
     let charger_factory = ChargerFactory::new(
         Arc::clone(&config),
         Arc::clone(&data_store) as Arc<dyn shared::data_store::DataStore + Send + Sync>,
@@ -87,12 +121,22 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>
         &event_manager,
         &charger_pool,
         &csms_server_client,
+        &data_transfer_registry,
     );
 
-    let interface =
-        OcppJsonNetworkInterface::new(&config, &charger_pool, charger_factory, &host, &port);
+    let interface = OcppJsonNetworkInterface::new(
+        &config,
+        &(Arc::clone(&data_store) as Arc<dyn shared::data_store::DataStore + Send + Sync>),
+        charger_factory,
+        &host,
+        &port,
+    );
 
-    try_join!(interface.start(), start_server(&charger_pool))?;
+    try_join!(
+        interface.start(),
+        start_server(&charger_pool),
+        load_balancer.start()
+    )?;
 
     Ok(())
 }
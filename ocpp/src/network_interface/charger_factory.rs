@@ -15,6 +15,12 @@ pub trait ChargerFactory<T: Send + Sync> {
         charger: &Arc<tokio::sync::Mutex<T>>,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
 
+    /// Called periodically while frames keep arriving from the charger
+    async fn on_seen(
+        &self,
+        charger_id: &str,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
     async fn on_disconnected(
         &self,
         charger: &Arc<tokio::sync::Mutex<T>>,
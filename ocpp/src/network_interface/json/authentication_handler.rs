@@ -1,4 +1,5 @@
 use poem::Response;
+use shared::data::ClientCertificate;
 
 #[async_trait::async_trait]
 pub trait AuthenticationHandler {
@@ -6,4 +7,11 @@ pub trait AuthenticationHandler {
         &mut self,
         password: &Option<String>,
     ) -> Result<(), Response>;
+
+    /// Called instead of `authenticate_with_password` when the charger presented a client
+    /// certificate that was verified against the configured CAs (security profile 3)
+    async fn authenticate_with_certificate(
+        &mut self,
+        certificate: &ClientCertificate,
+    ) -> Result<(), Response>;
 }
@@ -0,0 +1,76 @@
+use crate::network_interface::json::ocpp_network_interface_handle::OcppNetworkInterfaceHandle;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// Keeps the handle of every open websocket connection, so a charger that connects again can
+/// close its previous connection. Lookups don't go through the charger, whose lock can be held
+/// by a call waiting on the very connection that is being replaced
+#[derive(Clone, Default)]
+pub struct ConnectionRegistry {
+    connections: Arc<Mutex<HashMap<String, Arc<OcppNetworkInterfaceHandle>>>>,
+}
+
+impl ConnectionRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, charger_id: &str) -> Option<Arc<OcppNetworkInterfaceHandle>> {
+        self.connections.lock().unwrap().get(charger_id).cloned()
+    }
+
+    /// Returns the connection that was registered for the charger before, if any
+    pub fn insert(
+        &self,
+        charger_id: &str,
+        handle: &Arc<OcppNetworkInterfaceHandle>,
+    ) -> Option<Arc<OcppNetworkInterfaceHandle>> {
+        self.connections
+            .lock()
+            .unwrap()
+            .insert(charger_id.to_string(), Arc::clone(handle))
+            .filter(|previous| !Arc::ptr_eq(previous, handle))
+    }
+
+    /// Only removes the connection if it is still the registered one, a connection that was
+    /// replaced must not remove its successor
+    pub fn remove(&self, charger_id: &str, handle: &Arc<OcppNetworkInterfaceHandle>) {
+        let mut connections = self.connections.lock().unwrap();
+        if connections
+            .get(charger_id)
+            .is_some_and(|current| Arc::ptr_eq(current, handle))
+        {
+            connections.remove(charger_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::Config;
+
+    #[test]
+    fn test_replaced_connection_does_not_remove_its_successor() {
+        let config = Arc::new(Config::default());
+        let registry = ConnectionRegistry::new();
+        let previous = Arc::new(OcppNetworkInterfaceHandle::new(
+            Arc::clone(&config),
+            "CP001",
+        ));
+        let current = Arc::new(OcppNetworkInterfaceHandle::new(
+            Arc::clone(&config),
+            "CP001",
+        ));
+
+        assert!(registry.insert("CP001", &previous).is_none());
+        let replaced = registry.insert("CP001", &current).unwrap();
+        assert!(Arc::ptr_eq(&replaced, &previous));
+
+        registry.remove("CP001", &previous);
+        assert!(Arc::ptr_eq(&registry.get("CP001").unwrap(), &current));
+
+        registry.remove("CP001", &current);
+        assert!(registry.get("CP001").is_none());
+    }
+}
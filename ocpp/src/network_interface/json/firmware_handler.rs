@@ -0,0 +1,273 @@
+use crate::network_interface::json::parse_byte_range::{ByteRange, parse_byte_range};
+use chrono::Utc;
+use futures::stream::try_unfold;
+use poem::http::{StatusCode, header};
+use poem::web::{Data, Path};
+use poem::{Body, Request, Response, handler};
+use shared::data::FirmwareDownload;
+use shared::data_store::DataStore;
+use std::sync::Arc;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+/// Images are streamed in chunks of this size, so large images don't have to fit in memory
+const CHUNK_SIZE: u64 = 1024 * 1024;
+
+/// Serves a hosted firmware image to the charger the download token was issued to
+#[handler]
+pub async fn firmware_handler(
+    request: &Request,
+    data_store: Data<&Arc<dyn DataStore + Send + Sync>>,
+    Path((token, _file_name)): Path<(String, String)>,
+) -> Response {
+    let download_token = match data_store.get_firmware_download_token(&token).await {
+        Ok(Some(download_token)) => download_token,
+        Ok(None) => {
+            warn!("firmware download with unknown token");
+            return status_response(StatusCode::NOT_FOUND);
+        }
+        Err(error) => {
+            error!(
+                error_message = error.to_string(),
+                "could not get firmware download token"
+            );
+            return status_response(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+    if download_token.is_expired() {
+        warn!(
+            charger_id = download_token.charger_id,
+            image_id = download_token.image_id.to_string(),
+            "firmware download with expired token"
+        );
+        return status_response(StatusCode::FORBIDDEN);
+    }
+
+    let image = match data_store.get_firmware_image(download_token.image_id).await {
+        Ok(Some(image)) => image,
+        Ok(None) => return status_response(StatusCode::NOT_FOUND),
+        Err(error) => {
+            error!(
+                error_message = error.to_string(),
+                "could not get firmware image"
+            );
+            return status_response(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    let range = request
+        .headers()
+        .get(header::RANGE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| parse_byte_range(value, image.size));
+    let (status, start, end) = match range {
+        Some(ByteRange::Satisfiable { start, end }) => (StatusCode::PARTIAL_CONTENT, start, end),
+        Some(ByteRange::Unsatisfiable) => {
+            return Response::builder()
+                .status(StatusCode::RANGE_NOT_SATISFIABLE)
+                .header(header::CONTENT_RANGE, format!("bytes */{}", image.size))
+                .finish();
+        }
+        None => (StatusCode::OK, 0, image.size.saturating_sub(1)),
+    };
+
+    info!(
+        charger_id = download_token.charger_id,
+        image_id = image.id.to_string(),
+        range_start = start,
+        range_end = end,
+        "charger is downloading firmware"
+    );
+    let download = FirmwareDownload {
+        id: Uuid::new_v4(),
+        image_id: image.id,
+        charger_id: download_token.charger_id.clone(),
+        range_start: start,
+        range_end: end,
+        remote_address: Some(request.remote_addr().to_string()),
+        downloaded_at: Utc::now(),
+    };
+    if let Err(error) = data_store.save_firmware_download(&download).await {
+        error!(
+            error_message = error.to_string(),
+            "could not save firmware download"
+        );
+    }
+
+    let mut response = Response::builder()
+        .status(status)
+        .header(header::CONTENT_TYPE, "application/octet-stream")
+        .header(header::ACCEPT_RANGES, "bytes")
+        .header(header::ETAG, format!("\"{}\"", image.sha256));
+    if status == StatusCode::PARTIAL_CONTENT {
+        response = response.header(
+            header::CONTENT_RANGE,
+            format!("bytes {}-{}/{}", start, end, image.size),
+        );
+    }
+    if image.size == 0 {
+        return response.header(header::CONTENT_LENGTH, 0).finish();
+    }
+
+    let data_store = Arc::clone(data_store.0);
+    let image_id = image.id;
+    let chunks = try_unfold(start, move |offset| {
+        let data_store = Arc::clone(&data_store);
+        async move {
+            if offset > end {
+                return Ok(None);
+            }
+            let length = CHUNK_SIZE.min(end + 1 - offset);
+            let chunk = data_store
+                .get_firmware_image_data(image_id, offset, length)
+                .await
+                .map_err(std::io::Error::other)?
+                .ok_or_else(|| std::io::Error::other("firmware image data is missing"))?;
+            Ok::<_, std::io::Error>(Some((chunk, offset + length)))
+        }
+    });
+
+    response
+        .header(header::CONTENT_LENGTH, end + 1 - start)
+        .body(Body::from_bytes_stream(chunks))
+}
+
+fn status_response(status: StatusCode) -> Response {
+    Response::builder().status(status).finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeDelta;
+    use poem::{Endpoint, EndpointExt, Route, get};
+    use shared::data::{FirmwareDownloadToken, FirmwareImage};
+    use shared::data_store::MockDataStore;
+
+    const IMAGE_DATA: &[u8] = b"0123456789";
+
+    fn image() -> FirmwareImage {
+        FirmwareImage {
+            id: Uuid::new_v4(),
+            vendor: "Vendor".to_string(),
+            model: "Model".to_string(),
+            version: "1.0.0".to_string(),
+            file_name: "firmware.bin".to_string(),
+            size: IMAGE_DATA.len() as u64,
+            sha256: "sha256".to_string(),
+            md5: "md5".to_string(),
+            created_at: Utc::now(),
+        }
+    }
+
+    fn data_store(expires_at: chrono::DateTime<Utc>) -> MockDataStore {
+        let image = image();
+        let image_id = image.id;
+        let mut data_store = MockDataStore::new();
+        data_store
+            .expect_get_firmware_download_token()
+            .returning(move |token| {
+                Ok((token == "token").then(|| FirmwareDownloadToken {
+                    token: token.to_string(),
+                    image_id,
+                    charger_id: "charger".to_string(),
+                    expires_at,
+                }))
+            });
+        data_store
+            .expect_get_firmware_image()
+            .returning(move |_| Ok(Some(image.clone())));
+        data_store
+            .expect_get_firmware_image_data()
+            .returning(|_, offset, length| {
+                Ok(Some(
+                    IMAGE_DATA[offset as usize..(offset + length) as usize].to_vec(),
+                ))
+            });
+        data_store
+    }
+
+    async fn download(data_store: MockDataStore, token: &str, range: Option<&str>) -> Response {
+        let data_store: Arc<dyn DataStore + Send + Sync> = Arc::new(data_store);
+        let app = Route::new().at(
+            "/firmware/:token/:file_name",
+            get(firmware_handler).data(data_store),
+        );
+        let mut request = Request::builder().uri_str(format!("/firmware/{token}/firmware.bin"));
+        if let Some(range) = range {
+            request = request.header(header::RANGE, range);
+        }
+        app.call(request.finish()).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_whole_image_is_served() {
+        let mut data_store = data_store(Utc::now() + TimeDelta::hours(1));
+        data_store
+            .expect_save_firmware_download()
+            .withf(|download| download.range_start == 0 && download.range_end == 9)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let response = download(data_store, "token", None).await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.into_body().into_vec().await.unwrap(),
+            IMAGE_DATA.to_vec()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_range_is_served() {
+        let mut data_store = data_store(Utc::now() + TimeDelta::hours(1));
+        data_store
+            .expect_save_firmware_download()
+            .withf(|download| download.range_start == 2 && download.range_end == 5)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let response = download(data_store, "token", Some("bytes=2-5")).await;
+
+        assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+        assert_eq!(
+            response.headers().get(header::CONTENT_RANGE).unwrap(),
+            "bytes 2-5/10"
+        );
+        assert_eq!(
+            response.into_body().into_vec().await.unwrap(),
+            b"2345".to_vec()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_range_past_the_end_is_not_satisfiable() {
+        let data_store = data_store(Utc::now() + TimeDelta::hours(1));
+
+        let response = download(data_store, "token", Some("bytes=10-")).await;
+
+        assert_eq!(response.status(), StatusCode::RANGE_NOT_SATISFIABLE);
+        assert_eq!(
+            response.headers().get(header::CONTENT_RANGE).unwrap(),
+            "bytes */10"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unknown_token_is_not_found() {
+        let data_store = data_store(Utc::now() + TimeDelta::hours(1));
+
+        let response = download(data_store, "other", None).await;
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_expired_token_is_forbidden() {
+        let data_store = data_store(Utc::now() - TimeDelta::seconds(1));
+
+        let response = download(data_store, "token", None).await;
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
@@ -0,0 +1,110 @@
+use crate::network_interface::json::connection_registry::ConnectionRegistry;
+use crate::network_interface::json::handle_new_request::CONNECTION_TAKEOVERS;
+use poem::Response;
+use shared::{Config, ReconnectPolicy};
+use tonic::codegen::http::StatusCode;
+use tracing::{info, warn};
+
+/// Decides what happens to a charger that connects while its previous connection is still open,
+/// either the new connection is rejected or the previous one is closed
+pub async fn apply_reconnect_policy(
+    config: &Config,
+    connections: &ConnectionRegistry,
+    id: &str,
+) -> Result<(), Response> {
+    let Some(previous) = connections.get(id) else {
+        return Ok(());
+    };
+
+    let reconnect_policy = config
+        .ocpp
+        .clone()
+        .unwrap_or_default()
+        .reconnect_policy
+        .unwrap_or_default();
+    match reconnect_policy {
+        ReconnectPolicy::Reject => {
+            warn!(charger_id = id, "charger already connected");
+            Err(Response::builder()
+                .status(StatusCode::CONFLICT)
+                .body(format!("Charger with ID {} already connected", id)))
+        }
+        ReconnectPolicy::Replace => {
+            info!(
+                charger_id = id,
+                "charger already connected, replacing the previous connection"
+            );
+            CONNECTION_TAKEOVERS.inc();
+            previous.close().await;
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network_interface::json::ocpp_network_interface_handle::OcppNetworkInterfaceHandle;
+    use futures::FutureExt;
+    use shared::OcppConfig;
+    use std::sync::Arc;
+
+    fn config(reconnect_policy: ReconnectPolicy) -> Config {
+        Config {
+            ocpp: Some(OcppConfig {
+                reconnect_policy: Some(reconnect_policy),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn connected(connections: &ConnectionRegistry) -> Arc<OcppNetworkInterfaceHandle> {
+        let handle = Arc::new(OcppNetworkInterfaceHandle::new(
+            Arc::new(Config::default()),
+            "CP001",
+        ));
+        connections.insert("CP001", &handle);
+        handle
+    }
+
+    #[tokio::test]
+    async fn test_first_connection_is_accepted() {
+        let connections = ConnectionRegistry::new();
+
+        assert!(
+            apply_reconnect_policy(&config(ReconnectPolicy::Reject), &connections, "CP001")
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_replace_closes_the_previous_connection() {
+        let connections = ConnectionRegistry::new();
+        let previous = connected(&connections);
+        let takeovers = CONNECTION_TAKEOVERS.get();
+
+        apply_reconnect_policy(&config(ReconnectPolicy::Replace), &connections, "CP001")
+            .await
+            .unwrap();
+
+        assert!(previous.closed().now_or_never().is_some());
+        assert!(CONNECTION_TAKEOVERS.get() > takeovers);
+    }
+
+    #[tokio::test]
+    async fn test_reject_keeps_the_previous_connection() {
+        let connections = ConnectionRegistry::new();
+        let previous = connected(&connections);
+
+        let response =
+            apply_reconnect_policy(&config(ReconnectPolicy::Reject), &connections, "CP001")
+                .await
+                .unwrap_err();
+
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        assert!(previous.closed().now_or_never().is_none());
+        assert!(Arc::ptr_eq(&connections.get("CP001").unwrap(), &previous));
+    }
+}
@@ -1,7 +1,8 @@
-use crate::charger::ChargerPool;
 use crate::network_interface::charger_factory::ChargerFactory;
 use crate::network_interface::json::authentication_handler::AuthenticationHandler;
+use crate::network_interface::json::connection_registry::ConnectionRegistry;
 use crate::network_interface::json::extract_password::extract_password;
+use crate::network_interface::json::handle_new_request::apply_reconnect_policy::apply_reconnect_policy;
 use crate::network_interface::json::handle_new_request::handle_new_websocket_connection::handle_new_websocket_connection;
 use crate::network_interface::json::ocpp_network_interface_handle::OcppNetworkInterfaceHandle;
 use crate::network_interface::json::validate_protocol::validate_protocol;
@@ -15,6 +16,7 @@ use poem::http::{HeaderMap, HeaderValue};
 use poem::web::websocket::WebSocket;
 use poem::{IntoResponse, Response};
 use shared::Config;
+use shared::data::ClientCertificate;
 use std::sync::Arc;
 use tonic::codegen::http::StatusCode;
 use tracing::{info, warn};
@@ -28,22 +30,16 @@ pub async fn handle_new_request<
         + 'static,
 >(
     config: &Arc<Config>,
-    charger_pool: &ChargerPool,
+    connections: &ConnectionRegistry,
     ws: WebSocket,
     headers: &HeaderMap,
+    client_certificate: Option<ClientCertificate>,
     id: String,
     charger_factory: &Arc<dyn ChargerFactory<T> + Send + Sync>,
 ) -> Result<Response, Response> {
     let password = extract_password(headers)?;
     let protocol = validate_protocol(headers)?;
 
-    if charger_pool.get(&id).await.is_some() {
-        warn!(charger_id = id, "charger already connected");
-        return Err(Response::builder()
-            .status(StatusCode::CONFLICT)
-            .body(format!("Charger with ID {} already connected", id)));
-    }
-
     let handle = Arc::new(OcppNetworkInterfaceHandle::new(Arc::clone(config), &id));
 
     let mut charger = charger_factory
@@ -67,7 +63,12 @@ pub async fn handle_new_request<
                 .body(format!("Failed to create charger: {}", err))
         })?;
 
-    charger.authenticate_with_password(&password).await?;
+    match &client_certificate {
+        Some(certificate) => charger.authenticate_with_certificate(certificate).await?,
+        None => charger.authenticate_with_password(&password).await?,
+    }
+
+    apply_reconnect_policy(config, connections, &id).await?;
 
     info!(
         charger_id = &id,
@@ -76,6 +77,7 @@ pub async fn handle_new_request<
     );
 
     let charger_factory = Arc::clone(charger_factory);
+    let connections = connections.clone();
     let config = Arc::clone(config);
     let mut response = ws
         .protocols(vec!["ocpp1.6", "ocpp2.0.1"])
@@ -86,6 +88,7 @@ pub async fn handle_new_request<
                 protocol,
                 charger,
                 &charger_factory,
+                &connections,
                 &handle,
                 socket,
             )
@@ -1,6 +1,9 @@
 use crate::network_interface::OcppProtocol;
 use crate::network_interface::charger_factory::ChargerFactory;
+use crate::network_interface::json::connection_registry::ConnectionRegistry;
+use crate::network_interface::json::handle_new_request::CONNECTION_TAKEOVERS;
 use crate::network_interface::json::handle_new_request::handle_websocket_message::handle_websocket_message;
+use crate::network_interface::json::handle_new_request::keep_alive::keep_alive;
 use crate::network_interface::json::ocpp_network_interface_handle::OcppNetworkInterfaceHandle;
 use crate::network_interface::network_interface_handle::NetworkInterfaceHandle;
 use crate::network_interface::ocpp1_6_request_receiver::Ocpp16RequestReceiver;
@@ -9,10 +12,12 @@ use futures::{StreamExt, TryStreamExt};
 use poem::web::websocket::WebSocketStream;
 use shared::Config;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use tokio::sync::Mutex;
 use tracing::info;
 use tracing::warn;
 
+#[allow(clippy::too_many_arguments)]
 pub async fn handle_new_websocket_connection<
     T: Ocpp16RequestReceiver + Ocpp2_0_1RequestReceiver + Send + Sync + 'static,
 >(
@@ -21,6 +26,7 @@ pub async fn handle_new_websocket_connection<
     protocol: OcppProtocol,
     charger: T,
     charger_factory: &Arc<dyn ChargerFactory<T> + Send + Sync>,
+    connections: &ConnectionRegistry,
     handle: &Arc<OcppNetworkInterfaceHandle>,
     socket: WebSocketStream,
 ) {
@@ -32,6 +38,11 @@ pub async fn handle_new_websocket_connection<
     let (sink, stream) = socket.split();
 
     handle.attach_sink(sink).await;
+    if let Some(previous) = connections.insert(id, handle) {
+        // Another connection of the same charger got in between authenticating and upgrading
+        CONNECTION_TAKEOVERS.inc();
+        previous.close().await;
+    }
     let charger = Arc::new(Mutex::new(charger));
 
     if let Err(error) = charger_factory.on_connected(&charger).await {
@@ -42,34 +53,55 @@ pub async fn handle_new_websocket_connection<
             "failed to handle charger connection"
         );
         let _ = handle.disconnect().await;
+        let _ = charger_factory.on_disconnected(&charger).await;
+        connections.remove(id, handle);
         return;
     }
 
-    if let Err(err) = stream
+    let frame_received = AtomicBool::new(false);
+    let receive = stream
         .map_err(Into::<Box<dyn std::error::Error + Send + Sync>>::into)
         .try_for_each(|message| {
+            frame_received.store(true, Ordering::Relaxed);
             let charger = Arc::clone(&charger);
             let config = Arc::clone(&config);
             async move {
                 handle_websocket_message(config, id, protocol, charger, handle, message).await?;
                 Ok(())
             }
-        })
-        .await
-    {
+        });
+
+    let result = tokio::select! {
+        result = receive => result,
+        error = keep_alive(&config, id, charger_factory, handle.as_ref(), &frame_received) => {
+            let _ = handle.disconnect().await;
+            Err(error)
+        }
+        _ = handle.closed() => Err("replaced by a new connection".into()),
+    };
+
+    if let Err(err) = result {
         info!(
             charger_id = &id,
             ocpp_protocol = protocol.to_string(),
             error_message = err.to_string(),
             "connection closed with error"
         );
-        let _ = charger_factory.on_disconnected(&charger).await;
     } else {
         info!(
             charger_id = &id,
             ocpp_protocol = protocol.to_string(),
             "connection closed"
         );
-        let _ = charger_factory.on_disconnected(&charger).await;
     }
+
+    if let Err(error) = charger_factory.on_disconnected(&charger).await {
+        warn!(
+            charger_id = &id,
+            ocpp_protocol = protocol.to_string(),
+            error_message = error.to_string(),
+            "failed to handle charger disconnection"
+        );
+    }
+    connections.remove(id, handle);
 }
@@ -0,0 +1,175 @@
+use crate::network_interface::charger_factory::ChargerFactory;
+use crate::network_interface::network_interface_handle::NetworkInterfaceHandle;
+use shared::Config;
+use std::error::Error;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tokio::time::{Instant, MissedTickBehavior};
+use tracing::warn;
+
+/// Frames arrive far more often than the connection info needs refreshing, it's written at most
+/// this often
+const RECORD_SEEN_INTERVAL: Duration = Duration::from_secs(300);
+
+/// Pings the charger on every interval until it stays silent for too many intervals in a row,
+/// any inbound frame counts as a sign of life. Only returns once the connection is considered dead
+pub async fn keep_alive<T: Send + Sync>(
+    config: &Config,
+    id: &str,
+    charger_factory: &Arc<dyn ChargerFactory<T> + Send + Sync>,
+    handle: &(dyn NetworkInterfaceHandle + Send + Sync),
+    frame_received: &AtomicBool,
+) -> Box<dyn Error + Send + Sync> {
+    let ocpp_config = config.ocpp.clone().unwrap_or_default();
+    let ping_interval_secs = ocpp_config.ping_interval_secs.unwrap_or(30).max(1);
+    let max_missed_pongs = ocpp_config.max_missed_pongs.unwrap_or(3).max(1);
+
+    let mut interval = tokio::time::interval(Duration::from_secs(ping_interval_secs));
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    // The first tick completes immediately
+    interval.tick().await;
+
+    let mut missed_pongs = 0;
+    // Connecting already recorded the charger as online
+    let mut seen_recorded_at = Instant::now();
+    loop {
+        interval.tick().await;
+
+        if frame_received.swap(false, Ordering::Relaxed) {
+            missed_pongs = 0;
+            if seen_recorded_at.elapsed() >= RECORD_SEEN_INTERVAL {
+                seen_recorded_at = Instant::now();
+                if let Err(error) = charger_factory.on_seen(id).await {
+                    warn!(
+                        charger_id = id,
+                        error_message = error.to_string(),
+                        "failed to record that the charger was seen"
+                    );
+                }
+            }
+        } else {
+            missed_pongs += 1;
+            if missed_pongs >= max_missed_pongs {
+                return format!(
+                    "nothing received from the charger for {} seconds",
+                    u64::from(missed_pongs) * ping_interval_secs
+                )
+                .into();
+            }
+        }
+
+        if let Err(error) = handle.send_ping().await {
+            return error;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network_interface::charger_factory::MockChargerFactory;
+    use crate::network_interface::network_interface_handle::MockNetworkInterfaceHandle;
+    use shared::OcppConfig;
+    use tokio::time::sleep;
+
+    fn config() -> Config {
+        Config {
+            ocpp: Some(OcppConfig {
+                ping_interval_secs: Some(10),
+                max_missed_pongs: Some(3),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn charger_factory(on_seen_times: usize) -> Arc<dyn ChargerFactory<()> + Send + Sync> {
+        let mut charger_factory = MockChargerFactory::new();
+        charger_factory
+            .expect_on_seen()
+            .times(on_seen_times)
+            .returning(|_| Ok(()));
+        Arc::new(charger_factory)
+    }
+
+    fn handle() -> MockNetworkInterfaceHandle {
+        let mut handle = MockNetworkInterfaceHandle::new();
+        handle.expect_send_ping().returning(|| Ok(()));
+        handle
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_silent_charger_is_dead_after_missing_pongs() {
+        let started_at = Instant::now();
+
+        let error = keep_alive(
+            &config(),
+            "CP001",
+            &charger_factory(0),
+            &handle(),
+            &AtomicBool::new(false),
+        )
+        .await;
+
+        assert_eq!(
+            error.to_string(),
+            "nothing received from the charger for 30 seconds"
+        );
+        assert_eq!(started_at.elapsed(), Duration::from_secs(30));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_frame_resets_missed_pongs() {
+        let (config, charger_factory, handle) = (config(), charger_factory(0), handle());
+        let started_at = Instant::now();
+        let frame_received = AtomicBool::new(false);
+
+        let (error, _) = tokio::join!(
+            keep_alive(&config, "CP001", &charger_factory, &handle, &frame_received),
+            async {
+                sleep(Duration::from_secs(25)).await;
+                frame_received.store(true, Ordering::Relaxed);
+            }
+        );
+
+        assert!(error.to_string().starts_with("nothing received"));
+        assert_eq!(started_at.elapsed(), Duration::from_secs(60));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_seen_is_recorded_at_most_every_interval() {
+        let (config, charger_factory, handle) = (config(), charger_factory(2), handle());
+        let frame_received = AtomicBool::new(false);
+
+        tokio::join!(
+            keep_alive(&config, "CP001", &charger_factory, &handle, &frame_received),
+            async {
+                for _ in 0..122 {
+                    frame_received.store(true, Ordering::Relaxed);
+                    sleep(Duration::from_secs(5)).await;
+                }
+            }
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_failed_ping_ends_keep_alive() {
+        let mut handle = MockNetworkInterfaceHandle::new();
+        handle
+            .expect_send_ping()
+            .times(1)
+            .returning(|| Err("connection reset".into()));
+
+        let error = keep_alive(
+            &config(),
+            "CP001",
+            &charger_factory(0),
+            &handle,
+            &AtomicBool::new(true),
+        )
+        .await;
+
+        assert_eq!(error.to_string(), "connection reset");
+    }
+}
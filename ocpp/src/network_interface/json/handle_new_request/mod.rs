@@ -1,6 +1,8 @@
+mod apply_reconnect_policy;
 mod handle_new_request;
 mod handle_new_websocket_connection;
 mod handle_websocket_message;
+mod keep_alive;
 mod ocpp1_6;
 mod ocpp2_0_1;
 
@@ -15,8 +17,13 @@ lazy_static! {
         &["protocol", "action"]
     )
     .unwrap();
+    pub static ref CONNECTION_TAKEOVERS: IntCounter = register_int_counter!(
+        "ocpp_csms_server_connection_takeovers",
+        "Number of times a charger connected again and its previous connection was closed"
+    )
+    .unwrap();
 }
 
 pub use self::handle_new_request::handle_new_request;
 use lazy_static::lazy_static;
-use prometheus::{HistogramVec, register_histogram_vec};
+use prometheus::{HistogramVec, IntCounter, register_histogram_vec, register_int_counter};
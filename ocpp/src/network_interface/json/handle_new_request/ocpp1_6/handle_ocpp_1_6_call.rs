@@ -1,5 +1,6 @@
 use crate::network_interface::OcppProtocol;
 use crate::network_interface::json::handle_new_request::ocpp1_6::perform_ocpp_1_6_call::perform_ocpp_1_6_call;
+use crate::network_interface::json::handle_new_request::ocpp1_6::perform_ocpp_1_6_data_transfer::perform_ocpp_1_6_data_transfer;
 use crate::network_interface::json::handle_new_request::{
     OCPP_CALL_RESULT, OCPP_CALLS, OCPP_ERROR,
 };
@@ -36,18 +37,20 @@ pub async fn handle_ocpp_1_6_call<T: Ocpp16RequestReceiver + Send + Sync + 'stat
             .unwrap_or(30),
     );
 
-    let response: Result<Value, OCPP1_6Error> = {
-        let mut lock = charger.lock().await;
-
-        info!(
-            charger_id = charger_id,
-            protocol = OcppProtocol::Ocpp1_6.to_string(),
-            message_id = &message_id,
-            action = &action,
-            payload = payload.to_string(),
-            "Incoming call <--"
-        );
+    info!(
+        charger_id = charger_id,
+        protocol = OcppProtocol::Ocpp1_6.to_string(),
+        message_id = &message_id,
+        action = &action,
+        payload = payload.to_string(),
+        "Incoming call <--"
+    );
 
+    let response: Result<Value, OCPP1_6Error> = if action == "DataTransfer" {
+        let registry = charger.lock().await.data_transfer_registry();
+        perform_ocpp_1_6_data_transfer(duration, &registry, charger_id, payload).await?
+    } else {
+        let mut lock = charger.lock().await;
         perform_ocpp_1_6_call(duration, lock.deref_mut(), &action, payload).await?
     };
 
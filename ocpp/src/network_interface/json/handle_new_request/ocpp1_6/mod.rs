@@ -5,5 +5,6 @@ mod handle_ocpp_1_6_request;
 mod handle_ocpp_1_6_websocket_message;
 mod parse_ocpp_1_6_error_payload;
 mod perform_ocpp_1_6_call;
+mod perform_ocpp_1_6_data_transfer;
 
 pub use self::handle_ocpp_1_6_websocket_message::handle_ocpp_1_6_websocket_message;
@@ -25,13 +25,6 @@ pub async fn perform_ocpp_1_6_call<T: Ocpp16RequestReceiver + Send + Sync + 'sta
             )
             .await
         }
-        "DataTransfer" => {
-            handle_ocpp_1_6_request(
-                duration,
-                charger.data_transfer(serde_json::from_value(payload)?),
-            )
-            .await
-        }
         "DiagnosticsStatusNotification" => {
             handle_ocpp_1_6_request(
                 duration,
@@ -53,6 +46,13 @@ pub async fn perform_ocpp_1_6_call<T: Ocpp16RequestReceiver + Send + Sync + 'sta
             )
             .await
         }
+        "LogStatusNotification" => {
+            handle_ocpp_1_6_request(
+                duration,
+                charger.log_status_notification(serde_json::from_value(payload)?),
+            )
+            .await
+        }
         "MeterValues" => {
             handle_ocpp_1_6_request(
                 duration,
@@ -60,6 +60,27 @@ pub async fn perform_ocpp_1_6_call<T: Ocpp16RequestReceiver + Send + Sync + 'sta
             )
             .await
         }
+        "SecurityEventNotification" => {
+            handle_ocpp_1_6_request(
+                duration,
+                charger.security_event_notification(serde_json::from_value(payload)?),
+            )
+            .await
+        }
+        "SignCertificate" => {
+            handle_ocpp_1_6_request(
+                duration,
+                charger.sign_certificate(serde_json::from_value(payload)?),
+            )
+            .await
+        }
+        "SignedFirmwareStatusNotification" => {
+            handle_ocpp_1_6_request(
+                duration,
+                charger.signed_firmware_status_notification(serde_json::from_value(payload)?),
+            )
+            .await
+        }
         "StartTransaction" => {
             handle_ocpp_1_6_request(
                 duration,
@@ -93,11 +114,22 @@ pub async fn perform_ocpp_1_6_call<T: Ocpp16RequestReceiver + Send + Sync + 'sta
 mod tests {
     use super::*;
     use crate::network_interface::ocpp1_6_request_receiver::MockOcpp16RequestReceiver;
+    use crate::ocpp1_6_security::messages::log_status_notification::{
+        LogStatusNotificationRequest, LogStatusNotificationResponse,
+    };
+    use crate::ocpp1_6_security::messages::security_event_notification::{
+        SecurityEventNotificationRequest, SecurityEventNotificationResponse,
+    };
+    use crate::ocpp1_6_security::messages::sign_certificate::{
+        SignCertificateRequest, SignCertificateResponse,
+    };
+    use crate::ocpp1_6_security::messages::signed_firmware_status_notification::{
+        SignedFirmwareStatusNotificationRequest, SignedFirmwareStatusNotificationResponse,
+    };
     use rust_ocpp::v1_6::messages::authorize::{AuthorizeRequest, AuthorizeResponse};
     use rust_ocpp::v1_6::messages::boot_notification::{
         BootNotificationRequest, BootNotificationResponse,
     };
-    use rust_ocpp::v1_6::messages::data_transfer::{DataTransferRequest, DataTransferResponse};
     use rust_ocpp::v1_6::messages::diagnostics_status_notification::{
         DiagnosticsStatusNotificationRequest, DiagnosticsStatusNotificationResponse,
     };
@@ -150,23 +182,6 @@ mod tests {
         mock.checkpoint();
     }
 
-    #[tokio::test]
-    async fn test_data_transfer() {
-        let mut mock = MockOcpp16RequestReceiver::new();
-        mock.expect_data_transfer()
-            .return_once(|_| Ok(DataTransferResponse::default()));
-        perform_ocpp_1_6_call(
-            Duration::from_secs(1),
-            &mut mock,
-            "DataTransfer",
-            serde_json::to_value(DataTransferRequest::default()).unwrap(),
-        )
-        .await
-        .unwrap()
-        .unwrap();
-        mock.checkpoint();
-    }
-
     #[tokio::test]
     async fn test_diagnostics_status_notification() {
         let mut mock = MockOcpp16RequestReceiver::new();
@@ -218,6 +233,23 @@ mod tests {
         mock.checkpoint();
     }
 
+    #[tokio::test]
+    async fn test_log_status_notification() {
+        let mut mock = MockOcpp16RequestReceiver::new();
+        mock.expect_log_status_notification()
+            .return_once(|_| Ok(LogStatusNotificationResponse::default()));
+        perform_ocpp_1_6_call(
+            Duration::from_secs(1),
+            &mut mock,
+            "LogStatusNotification",
+            serde_json::to_value(LogStatusNotificationRequest::default()).unwrap(),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        mock.checkpoint();
+    }
+
     #[tokio::test]
     async fn test_meter_values() {
         let mut mock = MockOcpp16RequestReceiver::new();
@@ -235,6 +267,57 @@ mod tests {
         mock.checkpoint();
     }
 
+    #[tokio::test]
+    async fn test_security_event_notification() {
+        let mut mock = MockOcpp16RequestReceiver::new();
+        mock.expect_security_event_notification()
+            .return_once(|_| Ok(SecurityEventNotificationResponse::default()));
+        perform_ocpp_1_6_call(
+            Duration::from_secs(1),
+            &mut mock,
+            "SecurityEventNotification",
+            serde_json::to_value(SecurityEventNotificationRequest::default()).unwrap(),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        mock.checkpoint();
+    }
+
+    #[tokio::test]
+    async fn test_sign_certificate() {
+        let mut mock = MockOcpp16RequestReceiver::new();
+        mock.expect_sign_certificate()
+            .return_once(|_| Ok(SignCertificateResponse::default()));
+        perform_ocpp_1_6_call(
+            Duration::from_secs(1),
+            &mut mock,
+            "SignCertificate",
+            serde_json::to_value(SignCertificateRequest::default()).unwrap(),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        mock.checkpoint();
+    }
+
+    #[tokio::test]
+    async fn test_signed_firmware_status_notification() {
+        let mut mock = MockOcpp16RequestReceiver::new();
+        mock.expect_signed_firmware_status_notification()
+            .return_once(|_| Ok(SignedFirmwareStatusNotificationResponse::default()));
+        perform_ocpp_1_6_call(
+            Duration::from_secs(1),
+            &mut mock,
+            "SignedFirmwareStatusNotification",
+            serde_json::to_value(SignedFirmwareStatusNotificationRequest::default()).unwrap(),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        mock.checkpoint();
+    }
+
     #[tokio::test]
     async fn test_start_transaction() {
         let mut mock = MockOcpp16RequestReceiver::new();
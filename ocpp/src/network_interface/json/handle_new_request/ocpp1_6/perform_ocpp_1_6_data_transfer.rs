@@ -0,0 +1,79 @@
+use crate::data_transfer::{DataTransferMessage, DataTransferRegistry};
+use crate::network_interface::json::handle_new_request::ocpp1_6::handle_ocpp_1_6_request::handle_ocpp_1_6_request;
+use ocpp_client::ocpp_1_6::OCPP1_6Error;
+use rust_ocpp::v1_6::messages::data_transfer::{DataTransferRequest, DataTransferResponse};
+use serde_json::Value;
+use std::time::Duration;
+
+/// Handles a DataTransfer without the charger lock, a backend answering a relayed message may
+/// send the charger an outbound DataTransfer, which takes the lock
+pub async fn perform_ocpp_1_6_data_transfer(
+    duration: Duration,
+    registry: &DataTransferRegistry,
+    charger_id: &str,
+    payload: Value,
+) -> Result<Result<Value, OCPP1_6Error>, Box<dyn std::error::Error + Send + Sync>> {
+    let request: DataTransferRequest = serde_json::from_value(payload)?;
+    let message = DataTransferMessage {
+        charger_id: charger_id.to_string(),
+        vendor_id: request.vendor_string,
+        message_id: request.message_id,
+        data: request.data,
+    };
+
+    Ok(handle_ocpp_1_6_request(duration, async {
+        let reply = registry.handle(&message).await;
+
+        Ok(DataTransferResponse {
+            status: reply.status.into(),
+            data: reply.data,
+        })
+    })
+    .await)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_transfer::{DataTransferHandler, DataTransferReply, DataTransferStatus};
+    use serde_json::json;
+    use std::error::Error;
+
+    #[derive(Debug)]
+    struct EchoHandler;
+
+    #[async_trait::async_trait]
+    impl DataTransferHandler for EchoHandler {
+        async fn handle(
+            &self,
+            message: &DataTransferMessage,
+        ) -> Result<DataTransferReply, Box<dyn Error + Send + Sync + 'static>> {
+            Ok(DataTransferReply {
+                status: DataTransferStatus::Accepted,
+                data: Some(format!("{}:{}", message.charger_id, message.vendor_id)),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_data_transfer() {
+        let registry = DataTransferRegistry::builder()
+            .with_fallback(Box::new(EchoHandler))
+            .build();
+
+        let response = perform_ocpp_1_6_data_transfer(
+            Duration::from_secs(1),
+            &registry,
+            "charger",
+            json!({"vendorId": "com.vendor", "messageId": "Meter"}),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(
+            response,
+            json!({"status": "Accepted", "data": "charger:com.vendor"})
+        );
+    }
+}
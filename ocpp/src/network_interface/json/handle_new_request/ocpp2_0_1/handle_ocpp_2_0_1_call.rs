@@ -1,5 +1,6 @@
 use crate::network_interface::OcppProtocol;
 use crate::network_interface::json::handle_new_request::ocpp2_0_1::perform_ocpp_2_0_1_call::perform_ocpp_2_0_1_call;
+use crate::network_interface::json::handle_new_request::ocpp2_0_1::perform_ocpp_2_0_1_data_transfer::perform_ocpp_2_0_1_data_transfer;
 use crate::network_interface::json::handle_new_request::{
     OCPP_CALL_RESULT, OCPP_CALLS, OCPP_ERROR,
 };
@@ -36,18 +37,20 @@ pub async fn handle_ocpp_2_0_1_call<T: Ocpp2_0_1RequestReceiver + Send + Sync +
             .unwrap_or(30),
     );
 
-    let response: Result<Value, OCPP2_0_1Error> = {
-        let mut lock = charger.lock().await;
-
-        info!(
-            charger_id = charger_id,
-            protocol = OcppProtocol::Ocpp1_6.to_string(),
-            message_id = &message_id,
-            action = &action,
-            payload = payload.to_string(),
-            "Incoming call <--"
-        );
+    info!(
+        charger_id = charger_id,
+        protocol = OcppProtocol::Ocpp1_6.to_string(),
+        message_id = &message_id,
+        action = &action,
+        payload = payload.to_string(),
+        "Incoming call <--"
+    );
 
+    let response: Result<Value, OCPP2_0_1Error> = if action == "DataTransfer" {
+        let registry = charger.lock().await.data_transfer_registry();
+        perform_ocpp_2_0_1_data_transfer(duration, &registry, charger_id, payload).await?
+    } else {
+        let mut lock = charger.lock().await;
         perform_ocpp_2_0_1_call(duration, lock.deref_mut(), &action, payload).await?
     };
 
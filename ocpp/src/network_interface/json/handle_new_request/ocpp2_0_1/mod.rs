@@ -5,5 +5,6 @@ mod handle_ocpp_2_0_1_error;
 mod handle_ocpp_2_0_1_request;
 mod parse_ocpp_2_0_1_error_payload;
 mod perform_ocpp_2_0_1_call;
+mod perform_ocpp_2_0_1_data_transfer;
 
 pub use handle_2_0_1_websocket_message::handle_ocpp_2_0_1_websocket_message;
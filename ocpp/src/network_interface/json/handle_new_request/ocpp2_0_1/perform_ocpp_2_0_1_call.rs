@@ -32,13 +32,6 @@ pub async fn perform_ocpp_2_0_1_call<T: Ocpp2_0_1RequestReceiver + Send + Sync +
             )
             .await
         }
-        "DataTransfer" => {
-            handle_ocpp_2_0_1_request(
-                duration,
-                charger.data_transfer(serde_json::from_value(payload)?),
-            )
-            .await
-        }
         "FirmwareStatusNotification" => {
             handle_ocpp_2_0_1_request(
                 duration,
@@ -0,0 +1,34 @@
+use crate::data_transfer::{DataTransferMessage, DataTransferRegistry};
+use crate::network_interface::json::handle_new_request::ocpp2_0_1::handle_ocpp_2_0_1_request::handle_ocpp_2_0_1_request;
+use ocpp_client::ocpp_2_0_1::OCPP2_0_1Error;
+use rust_ocpp::v2_0_1::messages::datatransfer::{DataTransferRequest, DataTransferResponse};
+use serde_json::Value;
+use std::time::Duration;
+
+/// Handles a DataTransfer without the charger lock, a backend answering a relayed message may
+/// send the charger an outbound DataTransfer, which takes the lock
+pub async fn perform_ocpp_2_0_1_data_transfer(
+    duration: Duration,
+    registry: &DataTransferRegistry,
+    charger_id: &str,
+    payload: Value,
+) -> Result<Result<Value, OCPP2_0_1Error>, Box<dyn std::error::Error + Send + Sync>> {
+    let request: DataTransferRequest = serde_json::from_value(payload)?;
+    let message = DataTransferMessage {
+        charger_id: charger_id.to_string(),
+        vendor_id: request.vendor_id,
+        message_id: request.message_id,
+        data: request.data,
+    };
+
+    Ok(handle_ocpp_2_0_1_request(duration, async {
+        let reply = registry.handle(&message).await;
+
+        Ok(DataTransferResponse {
+            status: reply.status.into(),
+            data: reply.data,
+            status_info: None,
+        })
+    })
+    .await)
+}
@@ -1,11 +1,15 @@
 mod ocpp_json_network_interface;
 
 mod extract_password;
+mod firmware_handler;
 mod handle_new_request;
 mod metrics_handler;
 mod ocpp_handler;
+mod parse_byte_range;
+mod tls;
 
 mod authentication_handler;
+mod connection_registry;
 mod ocpp_network_interface_handle;
 mod validate_protocol;
 
@@ -1,21 +1,23 @@
-use crate::charger::ChargerPool;
 use crate::network_interface::charger_factory::ChargerFactory;
 use crate::network_interface::json::authentication_handler::AuthenticationHandler;
+use crate::network_interface::json::connection_registry::ConnectionRegistry;
 use crate::network_interface::json::handle_new_request::handle_new_request;
+use crate::network_interface::json::tls::ClientCertificateRegistry;
 use crate::network_interface::ocpp1_6_request_receiver::Ocpp16RequestReceiver;
 use crate::network_interface::ocpp2_0_1_request_receiver::Ocpp2_0_1RequestReceiver;
 use poem::http::HeaderMap;
 use poem::web::websocket::WebSocket;
 use poem::web::{Data, Path};
-use poem::{Response, handler};
+use poem::{Request, Response, handler};
 use shared::Config;
 use std::sync::Arc;
 use tracing::info;
 
 type DataType<'a, T> = Data<&'a (
     Arc<Config>,
-    ChargerPool,
+    ConnectionRegistry,
     Arc<dyn ChargerFactory<T> + Send + Sync>,
+    ClientCertificateRegistry,
 )>;
 
 #[handler]
@@ -29,11 +31,24 @@ pub async fn ocpp_handler<
 >(
     ws: WebSocket,
     headers: &HeaderMap,
+    req: &Request,
     data: DataType<'_, T>,
     Path(id): Path<String>,
 ) -> Response {
     info!(charger_id = id, "receiving connection from charger");
-    handle_new_request::<T>(&data.0.0, &data.0.1, ws, headers, id, &data.0.2)
-        .await
-        .unwrap_or_else(|r| r)
+    let client_certificate = data
+        .0
+        .3
+        .get(req.scheme(), req.local_addr(), req.remote_addr());
+    handle_new_request::<T>(
+        &data.0.0,
+        &data.0.1,
+        ws,
+        headers,
+        client_certificate,
+        id,
+        &data.0.2,
+    )
+    .await
+    .unwrap_or_else(|r| r)
 }
@@ -1,14 +1,19 @@
-use crate::charger::ChargerPool;
 use crate::network_interface::charger_factory::ChargerFactory;
 use crate::network_interface::json::authentication_handler::AuthenticationHandler;
+use crate::network_interface::json::connection_registry::ConnectionRegistry;
+use crate::network_interface::json::firmware_handler::firmware_handler;
 use crate::network_interface::json::metrics_handler::metrics_handler;
 use crate::network_interface::json::ocpp_handler::ocpp_handler;
+use crate::network_interface::json::tls::{
+    ClientCertificateRegistry, TlsListener, watch_server_config,
+};
 use crate::network_interface::network_interface::NetworkInterface;
 use crate::network_interface::ocpp1_6_request_receiver::Ocpp16RequestReceiver;
 use crate::network_interface::ocpp2_0_1_request_receiver::Ocpp2_0_1RequestReceiver;
-use poem::listener::TcpListener;
+use poem::listener::{Listener, TcpListener};
 use poem::{EndpointExt, Route, Server, get};
 use shared::Config;
+use shared::data_store::DataStore;
 use std::net::SocketAddr;
 use std::sync::Arc;
 use tokio::signal::unix::{SignalKind, signal};
@@ -16,7 +21,7 @@ use tracing::info;
 
 pub struct OcppJsonNetworkInterface<T> {
     config: Arc<Config>,
-    charger_pool: ChargerPool,
+    data_store: Arc<dyn DataStore + Send + Sync>,
     charger_factory: Arc<dyn ChargerFactory<T> + Send + Sync>,
     host: String,
     port: String,
@@ -33,14 +38,14 @@ impl<
 {
     pub fn new<F: ChargerFactory<T> + Send + Sync + 'static>(
         config: &Arc<Config>,
-        charger_pool: &ChargerPool,
+        data_store: &Arc<dyn DataStore + Send + Sync>,
         charger_factory: F,
         host: &str,
         port: &str,
     ) -> Self {
         Self {
             config: Arc::clone(config),
-            charger_pool: charger_pool.clone(),
+            data_store: Arc::clone(data_store),
             charger_factory: Arc::new(charger_factory),
             host: host.to_string(),
             port: port.to_string(),
@@ -48,22 +53,55 @@ impl<
     }
 
     pub async fn start(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        let app = Route::new().at("/metrics", get(metrics_handler)).at(
-            "/:id",
-            get(ocpp_handler::<T>::default()).data((
-                Arc::clone(&self.config),
-                self.charger_pool.clone(),
-                Arc::clone(&self.charger_factory),
-            )),
-        );
+        let client_certificates = ClientCertificateRegistry::new();
+        let app = Route::new()
+            .at("/metrics", get(metrics_handler))
+            .at(
+                "/firmware/:token/:file_name",
+                get(firmware_handler).data(Arc::clone(&self.data_store)),
+            )
+            .at(
+                "/:id",
+                get(ocpp_handler::<T>::default()).data((
+                    Arc::clone(&self.config),
+                    ConnectionRegistry::new(),
+                    Arc::clone(&self.charger_factory),
+                    client_certificates.clone(),
+                )),
+            );
 
-        info!(
-            "starting 'OCPP Json Interface' at http://{}:{}",
-            self.host, self.port
-        );
         let addr: SocketAddr = format!("{}:{}", self.host, self.port).parse().unwrap();
 
-        Server::new(TcpListener::bind(addr))
+        let tls = self.config.ocpp.as_ref().and_then(|ocpp| ocpp.tls.clone());
+        let listener = match tls {
+            None => {
+                info!("starting 'OCPP Json Interface' at http://{}", addr);
+                TcpListener::bind(addr).boxed()
+            }
+            Some(tls) => {
+                let tls_addr = SocketAddr::new(addr.ip(), tls.port.unwrap_or(addr.port()));
+                let plain_listener_enabled = tls.plain_listener_enabled();
+                if plain_listener_enabled && tls_addr == addr {
+                    return Err("'ocpp.tls.port' has to differ from the OCPP port when the plain listener is enabled".into());
+                }
+
+                let tls_listener = TlsListener::bind(
+                    tls_addr,
+                    watch_server_config(tls).await?,
+                    &client_certificates,
+                );
+                info!("starting 'OCPP Json Interface' at https://{}", tls_addr);
+
+                if plain_listener_enabled {
+                    info!("starting 'OCPP Json Interface' at http://{}", addr);
+                    TcpListener::bind(addr).combine(tls_listener).boxed()
+                } else {
+                    tls_listener.boxed()
+                }
+            }
+        };
+
+        Server::new(listener)
             .run_with_graceful_shutdown(
                 app,
                 async {
@@ -2,6 +2,25 @@ use crate::network_interface::network_interface_handle::NetworkInterfaceHandle;
 use crate::network_interface::{
     Ocpp2_0_1NetworkInterfaceHandle, Ocpp16NetworkInterfaceHandle, OcppProtocol,
 };
+use crate::ocpp1_6_security::messages::certificate_signed::{
+    CertificateSignedRequest, CertificateSignedResponse,
+};
+use crate::ocpp1_6_security::messages::delete_certificate::{
+    DeleteCertificateRequest, DeleteCertificateResponse,
+};
+use crate::ocpp1_6_security::messages::extended_trigger_message::{
+    ExtendedTriggerMessageRequest, ExtendedTriggerMessageResponse,
+};
+use crate::ocpp1_6_security::messages::get_installed_certificate_ids::{
+    GetInstalledCertificateIdsRequest, GetInstalledCertificateIdsResponse,
+};
+use crate::ocpp1_6_security::messages::get_log::{GetLogRequest, GetLogResponse};
+use crate::ocpp1_6_security::messages::install_certificate::{
+    InstallCertificateRequest, InstallCertificateResponse,
+};
+use crate::ocpp1_6_security::messages::signed_update_firmware::{
+    SignedUpdateFirmwareRequest, SignedUpdateFirmwareResponse,
+};
 use futures::SinkExt;
 use futures::stream::SplitSink;
 use ocpp_client::ocpp_1_6::OCPP1_6Error;
@@ -17,9 +36,20 @@ use rust_ocpp::v1_6::messages::change_availability::{
 use rust_ocpp::v1_6::messages::change_configuration::{
     ChangeConfigurationRequest, ChangeConfigurationResponse,
 };
+use rust_ocpp::v1_6::messages::clear_cache::{ClearCacheRequest, ClearCacheResponse};
+use rust_ocpp::v1_6::messages::clear_charging_profile::{
+    ClearChargingProfileRequest, ClearChargingProfileResponse,
+};
+use rust_ocpp::v1_6::messages::data_transfer::{DataTransferRequest, DataTransferResponse};
+use rust_ocpp::v1_6::messages::get_composite_schedule::{
+    GetCompositeScheduleRequest, GetCompositeScheduleResponse,
+};
 use rust_ocpp::v1_6::messages::get_configuration::{
     GetConfigurationRequest, GetConfigurationResponse,
 };
+use rust_ocpp::v1_6::messages::get_local_list_version::{
+    GetLocalListVersionRequest, GetLocalListVersionResponse,
+};
 use rust_ocpp::v1_6::messages::remote_start_transaction::{
     RemoteStartTransactionRequest, RemoteStartTransactionResponse,
 };
@@ -28,7 +58,15 @@ use rust_ocpp::v1_6::messages::remote_stop_transaction::{
 };
 use rust_ocpp::v1_6::messages::reserve_now::{ReserveNowRequest, ReserveNowResponse};
 use rust_ocpp::v1_6::messages::reset::{ResetRequest, ResetResponse};
+use rust_ocpp::v1_6::messages::send_local_list::{SendLocalListRequest, SendLocalListResponse};
+use rust_ocpp::v1_6::messages::set_charging_profile::{
+    SetChargingProfileRequest, SetChargingProfileResponse,
+};
 use rust_ocpp::v1_6::messages::trigger_message::{TriggerMessageRequest, TriggerMessageResponse};
+use rust_ocpp::v1_6::messages::unlock_connector::{
+    UnlockConnectorRequest, UnlockConnectorResponse,
+};
+use rust_ocpp::v1_6::messages::update_firmware::{UpdateFirmwareRequest, UpdateFirmwareResponse};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 use serde_json::Value;
@@ -38,7 +76,7 @@ use std::error::Error;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::oneshot::Sender;
-use tokio::sync::{MappedMutexGuard, Mutex, MutexGuard, oneshot};
+use tokio::sync::{MappedMutexGuard, Mutex, MutexGuard, Notify, oneshot};
 use tokio::time::timeout;
 use tracing::{info, warn};
 use uuid::Uuid;
@@ -49,6 +87,7 @@ pub struct OcppNetworkInterfaceHandle {
     sink: Mutex<Option<SplitSink<WebSocketStream, Message>>>,
     ocpp_1_6_message_queue: Mutex<BTreeMap<String, Sender<Result<Value, OCPP1_6Error>>>>,
     ocpp_2_0_1_message_queue: Mutex<BTreeMap<String, Sender<Result<Value, OCPP2_0_1Error>>>>,
+    closed: Notify,
 }
 
 impl OcppNetworkInterfaceHandle {
@@ -59,6 +98,7 @@ impl OcppNetworkInterfaceHandle {
             sink: Mutex::new(None),
             ocpp_1_6_message_queue: Mutex::new(BTreeMap::new()),
             ocpp_2_0_1_message_queue: Mutex::new(BTreeMap::new()),
+            closed: Notify::new(),
         }
     }
 
@@ -85,6 +125,42 @@ impl OcppNetworkInterfaceHandle {
         Ok(())
     }
 
+    /// Closes the connection from the server side. Calls still waiting for a response fail right
+    /// away instead of timing out, and the connection task is told to stop reading
+    pub async fn close(&self) {
+        self.ocpp_1_6_message_queue.lock().await.clear();
+        self.ocpp_2_0_1_message_queue.lock().await.clear();
+        let _ = self.disconnect().await;
+        self.closed.notify_one();
+    }
+
+    /// Registers a call that is waiting for the charger to respond
+    async fn expect_ocpp_1_6_response(
+        &self,
+        message_id: &str,
+    ) -> oneshot::Receiver<Result<Value, OCPP1_6Error>> {
+        let (sender, receiver) = oneshot::channel();
+        let mut lock = self.ocpp_1_6_message_queue.lock().await;
+        lock.insert(message_id.to_string(), sender);
+        receiver
+    }
+
+    /// Registers a call that is waiting for the charger to respond
+    async fn expect_ocpp_2_0_1_response(
+        &self,
+        message_id: &str,
+    ) -> oneshot::Receiver<Result<Value, OCPP2_0_1Error>> {
+        let (sender, receiver) = oneshot::channel();
+        let mut lock = self.ocpp_2_0_1_message_queue.lock().await;
+        lock.insert(message_id.to_string(), sender);
+        receiver
+    }
+
+    /// Completes once `close` has been called
+    pub async fn closed(&self) {
+        self.closed.notified().await;
+    }
+
     pub async fn response_ocpp_1_6(&self, message_id: &str, result: Result<Value, OCPP1_6Error>) {
         let mut lock = self.ocpp_1_6_message_queue.lock().await;
         match result {
@@ -159,13 +235,53 @@ impl OcppNetworkInterfaceHandle {
             sink.send(Text(raw_payload)).await?;
         }
 
-        let (sender, receiver) = oneshot::channel();
+        let receiver = self.expect_ocpp_1_6_response(&message_id.to_string()).await;
+
+        let timeout_duration = Duration::from_secs(
+            self.config
+                .ocpp
+                .clone()
+                .unwrap_or_default()
+                .message_timeout_secs
+                .unwrap_or(30),
+        );
+
+        info!("Waiting for response");
+        match timeout(timeout_duration, receiver).await?? {
+            Ok(val) => {
+                let result = serde_json::from_value(val)?;
+                Ok(Ok(result))
+            }
+            Err(err) => Ok(Err(err)),
+        }
+    }
+
+    async fn send_ocpp_2_0_1<T: Serialize, R: DeserializeOwned>(
+        &self,
+        action: &str,
+        request: T,
+    ) -> Result<Result<R, OCPP2_0_1Error>, Box<dyn std::error::Error + Send + Sync + 'static>> {
+        let message_id = Uuid::new_v4();
+        let raw_payload = serde_json::to_string(&(2, message_id, action, request))?;
+
+        info!(
+            charger_id = self.charger_id,
+            protocol = OcppProtocol::Ocpp2_0_1.to_string(),
+            message_id = &message_id.to_string(),
+            action = action,
+            raw_payload = &raw_payload,
+            "Sending call -->"
+        );
 
         {
-            let mut lock = self.ocpp_1_6_message_queue.lock().await;
-            lock.insert(message_id.to_string(), sender);
+            let mut sink = self.use_sink().await?;
+            sink.send(Text(raw_payload)).await?;
         }
 
+        let receiver = self
+            .expect_ocpp_2_0_1_response(&message_id.to_string())
+            .await;
+
         let timeout_duration = Duration::from_secs(
             self.config
                 .ocpp
@@ -193,6 +309,12 @@ impl NetworkInterfaceHandle for OcppNetworkInterfaceHandle {
         sink.close().await?;
         Ok(())
     }
+
+    async fn send_ping(&self) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let mut sink = self.use_sink().await?;
+        sink.send(Message::Ping(Vec::new())).await?;
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -284,7 +406,262 @@ impl Ocpp16NetworkInterfaceHandle for OcppNetworkInterfaceHandle {
     {
         self.send_ocpp_1_6("ReserveNow", request).await
     }
+
+    async fn send_set_charging_profile(
+        &self,
+        request: SetChargingProfileRequest,
+    ) -> Result<
+        Result<SetChargingProfileResponse, OCPP1_6Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    > {
+        self.send_ocpp_1_6("SetChargingProfile", request).await
+    }
+
+    async fn send_clear_charging_profile(
+        &self,
+        request: ClearChargingProfileRequest,
+    ) -> Result<
+        Result<ClearChargingProfileResponse, OCPP1_6Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    > {
+        self.send_ocpp_1_6("ClearChargingProfile", request).await
+    }
+
+    async fn send_get_composite_schedule(
+        &self,
+        request: GetCompositeScheduleRequest,
+    ) -> Result<
+        Result<GetCompositeScheduleResponse, OCPP1_6Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    > {
+        self.send_ocpp_1_6("GetCompositeSchedule", request).await
+    }
+
+    async fn send_send_local_list(
+        &self,
+        request: SendLocalListRequest,
+    ) -> Result<
+        Result<SendLocalListResponse, OCPP1_6Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    > {
+        self.send_ocpp_1_6("SendLocalList", request).await
+    }
+
+    async fn send_get_local_list_version(
+        &self,
+        request: GetLocalListVersionRequest,
+    ) -> Result<
+        Result<GetLocalListVersionResponse, OCPP1_6Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    > {
+        self.send_ocpp_1_6("GetLocalListVersion", request).await
+    }
+
+    async fn send_update_firmware(
+        &self,
+        request: UpdateFirmwareRequest,
+    ) -> Result<
+        Result<UpdateFirmwareResponse, OCPP1_6Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    > {
+        self.send_ocpp_1_6("UpdateFirmware", request).await
+    }
+
+    async fn send_clear_cache(
+        &self,
+        request: ClearCacheRequest,
+    ) -> Result<
+        Result<ClearCacheResponse, OCPP1_6Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    > {
+        self.send_ocpp_1_6("ClearCache", request).await
+    }
+
+    async fn send_unlock_connector(
+        &self,
+        request: UnlockConnectorRequest,
+    ) -> Result<
+        Result<UnlockConnectorResponse, OCPP1_6Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    > {
+        self.send_ocpp_1_6("UnlockConnector", request).await
+    }
+
+    async fn send_data_transfer(
+        &self,
+        request: DataTransferRequest,
+    ) -> Result<
+        Result<DataTransferResponse, OCPP1_6Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    > {
+        self.send_ocpp_1_6("DataTransfer", request).await
+    }
+
+    async fn send_certificate_signed(
+        &self,
+        request: CertificateSignedRequest,
+    ) -> Result<
+        Result<CertificateSignedResponse, OCPP1_6Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    > {
+        self.send_ocpp_1_6("CertificateSigned", request).await
+    }
+
+    async fn send_extended_trigger_message(
+        &self,
+        request: ExtendedTriggerMessageRequest,
+    ) -> Result<
+        Result<ExtendedTriggerMessageResponse, OCPP1_6Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    > {
+        self.send_ocpp_1_6("ExtendedTriggerMessage", request).await
+    }
+
+    async fn send_install_certificate(
+        &self,
+        request: InstallCertificateRequest,
+    ) -> Result<
+        Result<InstallCertificateResponse, OCPP1_6Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    > {
+        self.send_ocpp_1_6("InstallCertificate", request).await
+    }
+
+    async fn send_get_installed_certificate_ids(
+        &self,
+        request: GetInstalledCertificateIdsRequest,
+    ) -> Result<
+        Result<GetInstalledCertificateIdsResponse, OCPP1_6Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    > {
+        self.send_ocpp_1_6("GetInstalledCertificateIds", request)
+            .await
+    }
+
+    async fn send_delete_certificate(
+        &self,
+        request: DeleteCertificateRequest,
+    ) -> Result<
+        Result<DeleteCertificateResponse, OCPP1_6Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    > {
+        self.send_ocpp_1_6("DeleteCertificate", request).await
+    }
+
+    async fn send_get_log(
+        &self,
+        request: GetLogRequest,
+    ) -> Result<
+        Result<GetLogResponse, OCPP1_6Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    > {
+        self.send_ocpp_1_6("GetLog", request).await
+    }
+
+    async fn send_signed_update_firmware(
+        &self,
+        request: SignedUpdateFirmwareRequest,
+    ) -> Result<
+        Result<SignedUpdateFirmwareResponse, OCPP1_6Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    > {
+        self.send_ocpp_1_6("SignedUpdateFirmware", request).await
+    }
 }
 
 #[async_trait::async_trait]
-impl Ocpp2_0_1NetworkInterfaceHandle for OcppNetworkInterfaceHandle {}
+impl Ocpp2_0_1NetworkInterfaceHandle for OcppNetworkInterfaceHandle {
+    async fn send_set_charging_profile(
+        &self,
+        request: rust_ocpp::v2_0_1::messages::set_charging_profile::SetChargingProfileRequest,
+    ) -> Result<
+        Result<
+            rust_ocpp::v2_0_1::messages::set_charging_profile::SetChargingProfileResponse,
+            OCPP2_0_1Error,
+        >,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    > {
+        self.send_ocpp_2_0_1("SetChargingProfile", request).await
+    }
+
+    async fn send_clear_charging_profile(
+        &self,
+        request: rust_ocpp::v2_0_1::messages::clear_charging_profile::ClearChargingProfileRequest,
+    ) -> Result<
+        Result<
+            rust_ocpp::v2_0_1::messages::clear_charging_profile::ClearChargingProfileResponse,
+            OCPP2_0_1Error,
+        >,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    > {
+        self.send_ocpp_2_0_1("ClearChargingProfile", request).await
+    }
+
+    async fn send_update_firmware(
+        &self,
+        request: rust_ocpp::v2_0_1::messages::update_firmware::UpdateFirmwareRequest,
+    ) -> Result<
+        Result<
+            rust_ocpp::v2_0_1::messages::update_firmware::UpdateFirmwareResponse,
+            OCPP2_0_1Error,
+        >,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    > {
+        self.send_ocpp_2_0_1("UpdateFirmware", request).await
+    }
+
+    async fn send_unlock_connector(
+        &self,
+        request: rust_ocpp::v2_0_1::messages::unlock_connector::UnlockConnectorRequest,
+    ) -> Result<
+        Result<
+            rust_ocpp::v2_0_1::messages::unlock_connector::UnlockConnectorResponse,
+            OCPP2_0_1Error,
+        >,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    > {
+        self.send_ocpp_2_0_1("UnlockConnector", request).await
+    }
+
+    async fn send_trigger_message(
+        &self,
+        request: rust_ocpp::v2_0_1::messages::trigger_message::TriggerMessageRequest,
+    ) -> Result<
+        Result<
+            rust_ocpp::v2_0_1::messages::trigger_message::TriggerMessageResponse,
+            OCPP2_0_1Error,
+        >,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    > {
+        self.send_ocpp_2_0_1("TriggerMessage", request).await
+    }
+
+    async fn send_data_transfer(
+        &self,
+        request: rust_ocpp::v2_0_1::messages::datatransfer::DataTransferRequest,
+    ) -> Result<
+        Result<rust_ocpp::v2_0_1::messages::datatransfer::DataTransferResponse, OCPP2_0_1Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    > {
+        self.send_ocpp_2_0_1("DataTransfer", request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::FutureExt;
+
+    #[tokio::test]
+    async fn test_close_fails_pending_calls() {
+        let handle = OcppNetworkInterfaceHandle::new(Arc::new(Config::default()), "CP001");
+        let ocpp_1_6_call = handle.expect_ocpp_1_6_response("1").await;
+        let ocpp_2_0_1_call = handle.expect_ocpp_2_0_1_response("2").await;
+
+        handle.close().await;
+
+        assert!(ocpp_1_6_call.await.is_err());
+        assert!(ocpp_2_0_1_call.await.is_err());
+        assert!(handle.closed().now_or_never().is_some());
+    }
+}
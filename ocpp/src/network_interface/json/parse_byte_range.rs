@@ -0,0 +1,154 @@
+/// The part of a file requested with a `Range` header
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteRange {
+    /// The first and last byte to serve, inclusive
+    Satisfiable {
+        start: u64,
+        end: u64,
+    },
+    Unsatisfiable,
+}
+
+/// Parses a single `bytes` range of a file with the given size.
+///
+/// Returns `None` for headers we don't support, such as multiple ranges, in which case the header
+/// is ignored and the whole file is served, as allowed by RFC 9110.
+pub fn parse_byte_range(value: &str, size: u64) -> Option<ByteRange> {
+    let range = value.trim().strip_prefix("bytes=")?.trim();
+    if range.contains(',') {
+        return None;
+    }
+    let (start, end) = range.split_once('-')?;
+    let (start, end) = (start.trim(), end.trim());
+
+    if start.is_empty() {
+        // A suffix range, the last n bytes of the file
+        let length: u64 = end.parse().ok()?;
+        if length == 0 || size == 0 {
+            return Some(ByteRange::Unsatisfiable);
+        }
+        return Some(ByteRange::Satisfiable {
+            start: size.saturating_sub(length),
+            end: size - 1,
+        });
+    }
+
+    let start: u64 = start.parse().ok()?;
+    let end: Option<u64> = if end.is_empty() {
+        None
+    } else {
+        Some(end.parse().ok()?)
+    };
+    if end.is_some_and(|end| end < start) {
+        return None;
+    }
+    if start >= size {
+        return Some(ByteRange::Unsatisfiable);
+    }
+
+    Some(ByteRange::Satisfiable {
+        start,
+        end: end.map_or(size - 1, |end| end.min(size - 1)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_closed_range() {
+        assert_eq!(
+            parse_byte_range("bytes=0-499", 1000),
+            Some(ByteRange::Satisfiable { start: 0, end: 499 })
+        );
+    }
+
+    #[test]
+    fn parses_an_open_range() {
+        assert_eq!(
+            parse_byte_range("bytes=500-", 1000),
+            Some(ByteRange::Satisfiable {
+                start: 500,
+                end: 999
+            })
+        );
+    }
+
+    #[test]
+    fn parses_a_suffix_range() {
+        assert_eq!(
+            parse_byte_range("bytes=-100", 1000),
+            Some(ByteRange::Satisfiable {
+                start: 900,
+                end: 999
+            })
+        );
+        assert_eq!(
+            parse_byte_range("bytes=-2000", 1000),
+            Some(ByteRange::Satisfiable { start: 0, end: 999 })
+        );
+    }
+
+    #[test]
+    fn clamps_the_end_to_the_file_size() {
+        assert_eq!(
+            parse_byte_range("bytes=900-2000", 1000),
+            Some(ByteRange::Satisfiable {
+                start: 900,
+                end: 999
+            })
+        );
+    }
+
+    #[test]
+    fn ranges_past_the_end_are_unsatisfiable() {
+        assert_eq!(
+            parse_byte_range("bytes=1000-", 1000),
+            Some(ByteRange::Unsatisfiable)
+        );
+        assert_eq!(
+            parse_byte_range("bytes=-0", 1000),
+            Some(ByteRange::Unsatisfiable)
+        );
+    }
+
+    #[test]
+    fn unsupported_ranges_are_ignored() {
+        assert_eq!(parse_byte_range("bytes=0-1,5-6", 1000), None);
+        assert_eq!(parse_byte_range("items=0-1", 1000), None);
+        assert_eq!(parse_byte_range("bytes=5-1", 1000), None);
+        assert_eq!(parse_byte_range("bytes=a-b", 1000), None);
+    }
+
+    #[test]
+    fn whitespace_around_the_range_is_ignored() {
+        assert_eq!(
+            parse_byte_range(" bytes= 1 - 2 ", 1000),
+            Some(ByteRange::Satisfiable { start: 1, end: 2 })
+        );
+    }
+
+    #[test]
+    fn empty_files_have_no_satisfiable_range() {
+        assert_eq!(
+            parse_byte_range("bytes=0-", 0),
+            Some(ByteRange::Unsatisfiable)
+        );
+        assert_eq!(
+            parse_byte_range("bytes=-10", 0),
+            Some(ByteRange::Unsatisfiable)
+        );
+    }
+
+    #[test]
+    fn a_range_of_the_last_byte_is_served() {
+        assert_eq!(
+            parse_byte_range("bytes=999-999", 1000),
+            Some(ByteRange::Satisfiable {
+                start: 999,
+                end: 999
+            })
+        );
+    }
+}
@@ -0,0 +1,198 @@
+use poem::http::uri::Scheme;
+use poem::web::{LocalAddr, RemoteAddr};
+use shared::data::ClientCertificate;
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Connections are identified by their local and remote address, the plain and the TLS listener
+/// can see the same remote address
+type ConnectionKey = (SocketAddr, SocketAddr);
+
+/// Hands the client certificate of a TLS connection over to the requests that arrive on it.
+/// The handshake happens in the listener, which can only pass on the addresses of the connection.
+#[derive(Clone, Default)]
+pub struct ClientCertificateRegistry {
+    next_id: Arc<AtomicU64>,
+    certificates: Arc<Mutex<HashMap<ConnectionKey, (u64, ClientCertificate)>>>,
+}
+
+impl ClientCertificateRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The certificate stays registered until the returned entry is dropped, which the TLS
+    /// listener does when the connection closes
+    pub fn insert(
+        &self,
+        local_addr: SocketAddr,
+        remote_addr: SocketAddr,
+        certificate: ClientCertificate,
+    ) -> ClientCertificateEntry {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let key = (local_addr, remote_addr);
+        self.certificates
+            .lock()
+            .unwrap()
+            .insert(key, (id, certificate));
+
+        ClientCertificateEntry {
+            registry: self.clone(),
+            key,
+            id,
+        }
+    }
+
+    /// Only requests that arrived over TLS have a client certificate
+    pub fn get(
+        &self,
+        scheme: &Scheme,
+        local_addr: &LocalAddr,
+        remote_addr: &RemoteAddr,
+    ) -> Option<ClientCertificate> {
+        if *scheme != Scheme::HTTPS {
+            return None;
+        }
+        let key = (
+            *local_addr.as_socket_addr()?,
+            *remote_addr.as_socket_addr()?,
+        );
+
+        self.certificates
+            .lock()
+            .unwrap()
+            .get(&key)
+            .map(|(_, certificate)| certificate.clone())
+    }
+}
+
+/// Removes the certificate of a connection from the registry when dropped
+pub struct ClientCertificateEntry {
+    registry: ClientCertificateRegistry,
+    key: ConnectionKey,
+    id: u64,
+}
+
+impl Drop for ClientCertificateEntry {
+    fn drop(&mut self) {
+        let mut certificates = self.registry.certificates.lock().unwrap();
+        // A new connection from the same address may already have replaced this one
+        if certificates.get(&self.key).map(|(id, _)| *id) == Some(self.id) {
+            certificates.remove(&self.key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn certificate(common_name: &str) -> ClientCertificate {
+        ClientCertificate {
+            common_name: common_name.to_string(),
+            serial_number: "01".to_string(),
+            expires_at: Utc::now(),
+        }
+    }
+
+    fn common_name(certificate: Option<ClientCertificate>) -> Option<String> {
+        certificate.map(|certificate| certificate.common_name)
+    }
+
+    fn addr(addr: &str) -> SocketAddr {
+        addr.parse().unwrap()
+    }
+
+    #[test]
+    fn test_certificate_is_registered_while_the_connection_is_open() {
+        let registry = ClientCertificateRegistry::new();
+        let local_addr = LocalAddr(addr("0.0.0.0:8443").into());
+        let remote_addr = RemoteAddr(addr("10.0.0.1:40000").into());
+
+        let entry = registry.insert(
+            addr("0.0.0.0:8443"),
+            addr("10.0.0.1:40000"),
+            certificate("CP001"),
+        );
+
+        assert_eq!(
+            common_name(registry.get(&Scheme::HTTPS, &local_addr, &remote_addr)),
+            Some("CP001".to_string())
+        );
+        assert_eq!(
+            common_name(registry.get(&Scheme::HTTPS, &local_addr, &remote_addr)),
+            Some("CP001".to_string())
+        );
+        assert_eq!(
+            registry.get(
+                &Scheme::HTTPS,
+                &local_addr,
+                &RemoteAddr(addr("10.0.0.1:40001").into())
+            ),
+            None
+        );
+
+        drop(entry);
+
+        assert_eq!(
+            registry.get(&Scheme::HTTPS, &local_addr, &remote_addr),
+            None
+        );
+    }
+
+    #[test]
+    fn test_plain_requests_never_get_a_certificate() {
+        let registry = ClientCertificateRegistry::new();
+        let _entry = registry.insert(
+            addr("0.0.0.0:8443"),
+            addr("10.0.0.1:40000"),
+            certificate("CP001"),
+        );
+
+        assert_eq!(
+            registry.get(
+                &Scheme::HTTP,
+                &LocalAddr(addr("0.0.0.0:8443").into()),
+                &RemoteAddr(addr("10.0.0.1:40000").into())
+            ),
+            None
+        );
+        assert_eq!(
+            registry.get(
+                &Scheme::HTTP,
+                &LocalAddr(addr("0.0.0.0:8080").into()),
+                &RemoteAddr(addr("10.0.0.1:40000").into())
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_closing_a_replaced_connection_keeps_the_new_certificate() {
+        let registry = ClientCertificateRegistry::new();
+        let old = registry.insert(
+            addr("0.0.0.0:8443"),
+            addr("10.0.0.1:40000"),
+            certificate("CP001"),
+        );
+        let _new = registry.insert(
+            addr("0.0.0.0:8443"),
+            addr("10.0.0.1:40000"),
+            certificate("CP002"),
+        );
+
+        drop(old);
+
+        assert_eq!(
+            common_name(registry.get(
+                &Scheme::HTTPS,
+                &LocalAddr(addr("0.0.0.0:8443").into()),
+                &RemoteAddr(addr("10.0.0.1:40000").into())
+            )),
+            Some("CP002".to_string())
+        );
+    }
+}
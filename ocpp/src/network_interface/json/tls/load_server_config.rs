@@ -0,0 +1,64 @@
+use rustls::ServerConfig;
+use rustls::crypto::ring::default_provider;
+use rustls::server::WebPkiClientVerifier;
+use rustls::server::danger::ClientCertVerifier;
+use rustls::{RootCertStore, version};
+use shared::OcppTlsConfig;
+use std::sync::Arc;
+use std::time::SystemTime;
+use tokio::fs;
+
+/// Loads the server certificate, and the CAs client certificates are verified against when
+/// configured. Presenting a client certificate is optional, so chargers that authenticate with
+/// a password can use the same listener.
+pub async fn load_server_config(
+    tls: &OcppTlsConfig,
+) -> Result<Arc<ServerConfig>, Box<dyn std::error::Error + Send + Sync>> {
+    let provider = Arc::new(default_provider());
+
+    let cert_chain = rustls_pemfile::certs(&mut fs::read(&tls.cert_path).await?.as_slice())
+        .collect::<Result<Vec<_>, _>>()?;
+    let key = rustls_pemfile::private_key(&mut fs::read(&tls.key_path).await?.as_slice())?
+        .ok_or_else(|| format!("No private key found in {}", tls.key_path))?;
+
+    let builder = ServerConfig::builder_with_provider(Arc::clone(&provider))
+        .with_protocol_versions(&[&version::TLS12, &version::TLS13])?;
+    let builder = match &tls.client_ca_path {
+        Some(client_ca_path) => {
+            let mut roots = RootCertStore::empty();
+            for certificate in
+                rustls_pemfile::certs(&mut fs::read(client_ca_path).await?.as_slice())
+            {
+                roots.add(certificate?)?;
+            }
+            let verifier: Arc<dyn ClientCertVerifier> =
+                WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider)
+                    .allow_unauthenticated()
+                    .build()?;
+            builder.with_client_cert_verifier(verifier)
+        }
+        None => builder.with_no_client_auth(),
+    };
+
+    let mut config = builder.with_single_cert(cert_chain, key)?;
+    config.alpn_protocols = vec![b"http/1.1".to_vec()];
+    Ok(Arc::new(config))
+}
+
+/// The last time any of the certificate, key or CA files was changed
+pub async fn tls_files_modified_at(
+    tls: &OcppTlsConfig,
+) -> Result<SystemTime, Box<dyn std::error::Error + Send + Sync>> {
+    let mut modified_at = SystemTime::UNIX_EPOCH;
+    for path in [
+        Some(&tls.cert_path),
+        Some(&tls.key_path),
+        tls.client_ca_path.as_ref(),
+    ]
+    .into_iter()
+    .flatten()
+    {
+        modified_at = modified_at.max(fs::metadata(path).await?.modified()?);
+    }
+    Ok(modified_at)
+}
@@ -0,0 +1,10 @@
+mod client_certificate_registry;
+mod load_server_config;
+mod parse_client_certificate;
+mod tls_connection;
+mod tls_listener;
+mod watch_server_config;
+
+pub use self::client_certificate_registry::ClientCertificateRegistry;
+pub use self::tls_listener::TlsListener;
+pub use self::watch_server_config::watch_server_config;
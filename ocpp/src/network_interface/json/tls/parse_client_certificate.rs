@@ -0,0 +1,57 @@
+use chrono::{TimeZone, Utc};
+use shared::data::ClientCertificate;
+use x509_parser::prelude::{FromDer, X509Certificate};
+
+/// Reads the details of a DER encoded certificate that are needed to identify the charger
+pub fn parse_client_certificate(
+    der: &[u8],
+) -> Result<ClientCertificate, Box<dyn std::error::Error + Send + Sync>> {
+    let (_, certificate) = X509Certificate::from_der(der)?;
+
+    let common_name = certificate
+        .subject()
+        .iter_common_name()
+        .next()
+        .ok_or("The certificate has no common name")?
+        .as_str()?
+        .to_string();
+    let expires_at = Utc
+        .timestamp_opt(certificate.validity().not_after.timestamp(), 0)
+        .single()
+        .ok_or("The certificate has an invalid expiry")?;
+
+    Ok(ClientCertificate {
+        common_name,
+        serial_number: hex::encode(certificate.raw_serial()),
+        expires_at,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CLIENT_CERTIFICATE: &[u8] = include_bytes!("test_certs/client.pem");
+
+    #[test]
+    fn test_reads_common_name_serial_and_expiry() {
+        let der = rustls_pemfile::certs(&mut &CLIENT_CERTIFICATE[..])
+            .next()
+            .unwrap()
+            .unwrap();
+
+        let certificate = parse_client_certificate(&der).unwrap();
+
+        assert_eq!(certificate.common_name, "CP001");
+        assert_eq!(certificate.serial_number, "1234abcd");
+        assert_eq!(
+            certificate.expires_at,
+            Utc.with_ymd_and_hms(2126, 9, 25, 10, 19, 5).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_rejects_invalid_der() {
+        assert!(parse_client_certificate(b"not a certificate").is_err());
+    }
+}
@@ -0,0 +1,160 @@
+use crate::network_interface::json::tls::client_certificate_registry::ClientCertificateRegistry;
+use crate::network_interface::json::tls::parse_client_certificate::parse_client_certificate;
+use crate::network_interface::json::tls::tls_connection::TlsConnection;
+use poem::http::uri::Scheme;
+use poem::listener::{Acceptor, Listener};
+use poem::web::{LocalAddr, RemoteAddr};
+use rustls::ServerConfig;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio::sync::{mpsc, watch};
+use tokio::time::timeout;
+use tracing::warn;
+
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Accepts TLS connections with the latest server configuration. Handshakes run in the
+/// background, so a slow charger doesn't hold up the others, and the client certificate of each
+/// connection is recorded in the registry.
+pub struct TlsListener {
+    addr: SocketAddr,
+    server_config: watch::Receiver<Arc<ServerConfig>>,
+    client_certificates: ClientCertificateRegistry,
+}
+
+impl TlsListener {
+    pub fn bind(
+        addr: SocketAddr,
+        server_config: watch::Receiver<Arc<ServerConfig>>,
+        client_certificates: &ClientCertificateRegistry,
+    ) -> Self {
+        Self {
+            addr,
+            server_config,
+            client_certificates: client_certificates.clone(),
+        }
+    }
+}
+
+impl Listener for TlsListener {
+    type Acceptor = TlsAcceptor;
+
+    async fn into_acceptor(self) -> io::Result<Self::Acceptor> {
+        let listener = TcpListener::bind(self.addr).await?;
+        let local_addr = listener.local_addr()?;
+        let (sender, receiver) = mpsc::channel(64);
+        tokio::spawn(accept_connections(
+            listener,
+            local_addr,
+            self.server_config,
+            self.client_certificates,
+            sender,
+        ));
+        Ok(TlsAcceptor {
+            local_addr,
+            receiver,
+        })
+    }
+}
+
+pub struct TlsAcceptor {
+    local_addr: SocketAddr,
+    receiver: mpsc::Receiver<(TlsConnection, SocketAddr)>,
+}
+
+impl Acceptor for TlsAcceptor {
+    type Io = TlsConnection;
+
+    fn local_addr(&self) -> Vec<LocalAddr> {
+        vec![LocalAddr(self.local_addr.into())]
+    }
+
+    async fn accept(&mut self) -> io::Result<(Self::Io, LocalAddr, RemoteAddr, Scheme)> {
+        let (stream, remote_addr) = self
+            .receiver
+            .recv()
+            .await
+            .ok_or_else(|| io::Error::other("The TLS listener stopped"))?;
+        Ok((
+            stream,
+            LocalAddr(self.local_addr.into()),
+            RemoteAddr(remote_addr.into()),
+            Scheme::HTTPS,
+        ))
+    }
+}
+
+async fn accept_connections(
+    listener: TcpListener,
+    local_addr: SocketAddr,
+    server_config: watch::Receiver<Arc<ServerConfig>>,
+    client_certificates: ClientCertificateRegistry,
+    sender: mpsc::Sender<(TlsConnection, SocketAddr)>,
+) {
+    while !sender.is_closed() {
+        let (stream, remote_addr) = match listener.accept().await {
+            Ok(connection) => connection,
+            Err(error) => {
+                warn!(
+                    error_message = error.to_string(),
+                    "Failed to accept connection"
+                );
+                continue;
+            }
+        };
+
+        let acceptor = tokio_rustls::TlsAcceptor::from(Arc::clone(&server_config.borrow()));
+        let client_certificates = client_certificates.clone();
+        let sender = sender.clone();
+        tokio::spawn(async move {
+            let stream = match timeout(HANDSHAKE_TIMEOUT, acceptor.accept(stream)).await {
+                Ok(Ok(stream)) => stream,
+                Ok(Err(error)) => {
+                    warn!(
+                        remote_addr = remote_addr.to_string(),
+                        error_message = error.to_string(),
+                        "TLS handshake failed"
+                    );
+                    return;
+                }
+                Err(_) => {
+                    warn!(
+                        remote_addr = remote_addr.to_string(),
+                        "TLS handshake timed out"
+                    );
+                    return;
+                }
+            };
+
+            // The certificate chain has already been verified against the CAs during the
+            // handshake, only its details are needed here
+            let client_certificate = match stream
+                .get_ref()
+                .1
+                .peer_certificates()
+                .and_then(|certificates| certificates.first())
+                .map(|certificate| parse_client_certificate(certificate))
+            {
+                None => None,
+                Some(Ok(certificate)) => {
+                    Some(client_certificates.insert(local_addr, remote_addr, certificate))
+                }
+                Some(Err(error)) => {
+                    warn!(
+                        remote_addr = remote_addr.to_string(),
+                        error_message = error.to_string(),
+                        "Failed to read the client certificate"
+                    );
+                    return;
+                }
+            };
+
+            let _ = sender
+                .send((TlsConnection::new(stream, client_certificate), remote_addr))
+                .await;
+        });
+    }
+}
@@ -0,0 +1,144 @@
+use crate::network_interface::json::tls::load_server_config::{
+    load_server_config, tls_files_modified_at,
+};
+use rustls::ServerConfig;
+use shared::OcppTlsConfig;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+use tokio::time::sleep;
+use tracing::{info, warn};
+
+/// Loads the TLS configuration, and reloads it every time the certificate, key or CA files
+/// change. New connections use the latest configuration, so renewing the certificate doesn't
+/// drop any charger.
+pub async fn watch_server_config(
+    tls: OcppTlsConfig,
+) -> Result<watch::Receiver<Arc<ServerConfig>>, Box<dyn std::error::Error + Send + Sync>> {
+    let (sender, receiver) = watch::channel(load_server_config(&tls).await?);
+    let mut last_modified_at = tls_files_modified_at(&tls).await?;
+
+    tokio::spawn(async move {
+        while !sender.is_closed() {
+            sleep(Duration::from_secs(tls.reload_interval_secs())).await;
+
+            let modified_at = match tls_files_modified_at(&tls).await {
+                Ok(modified_at) => modified_at,
+                Err(error) => {
+                    warn!(
+                        error_message = error.to_string(),
+                        "Failed to check the TLS certificate for changes"
+                    );
+                    continue;
+                }
+            };
+            if modified_at == last_modified_at {
+                continue;
+            }
+
+            match load_server_config(&tls).await {
+                Ok(config) => {
+                    info!(cert_path = tls.cert_path, "Reloaded the TLS certificate");
+                    last_modified_at = modified_at;
+                    sender.send_replace(config);
+                }
+                Err(error) => {
+                    warn!(
+                        error_message = error.to_string(),
+                        "Failed to reload the TLS certificate, keeping the current one"
+                    );
+                }
+            }
+        }
+    });
+
+    Ok(receiver)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+    use tempfile::TempDir;
+    use tokio::fs;
+    use tokio::time::timeout;
+
+    const TEST_CERTS: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/src/network_interface/json/tls/test_certs"
+    );
+
+    async fn tls_config(dir: &TempDir) -> OcppTlsConfig {
+        for file in ["server.pem", "server.key"] {
+            fs::copy(Path::new(TEST_CERTS).join(file), dir.path().join(file))
+                .await
+                .unwrap();
+        }
+        OcppTlsConfig {
+            cert_path: dir.path().join("server.pem").display().to_string(),
+            key_path: dir.path().join("server.key").display().to_string(),
+            client_ca_path: None,
+            port: None,
+            plain_listener_enabled: None,
+            reload_interval_secs: Some(1),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reloads_renewed_certificate() {
+        let dir = TempDir::new().unwrap();
+        let mut receiver = watch_server_config(tls_config(&dir).await).await.unwrap();
+        let initial = Arc::clone(&receiver.borrow_and_update());
+
+        fs::copy(
+            Path::new(TEST_CERTS).join("server_renewed.pem"),
+            dir.path().join("server.pem"),
+        )
+        .await
+        .unwrap();
+
+        timeout(Duration::from_secs(5), receiver.changed())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!Arc::ptr_eq(&initial, &receiver.borrow()));
+    }
+
+    #[tokio::test]
+    async fn test_keeps_current_certificate_when_reload_fails() {
+        let dir = TempDir::new().unwrap();
+        let mut receiver = watch_server_config(tls_config(&dir).await).await.unwrap();
+        receiver.borrow_and_update();
+
+        fs::write(dir.path().join("server.pem"), "not a certificate")
+            .await
+            .unwrap();
+
+        assert!(
+            timeout(Duration::from_millis(2500), receiver.changed())
+                .await
+                .is_err()
+        );
+
+        fs::copy(
+            Path::new(TEST_CERTS).join("server_renewed.pem"),
+            dir.path().join("server.pem"),
+        )
+        .await
+        .unwrap();
+
+        timeout(Duration::from_secs(5), receiver.changed())
+            .await
+            .unwrap()
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_fails_without_certificate() {
+        let dir = TempDir::new().unwrap();
+        let mut tls = tls_config(&dir).await;
+        tls.cert_path = dir.path().join("missing.pem").display().to_string();
+
+        assert!(watch_server_config(tls).await.is_err());
+    }
+}
@@ -17,3 +17,8 @@ pub use self::ocpp1_6_request_receiver::Ocpp16RequestReceiver;
 pub use self::ocpp2_0_1_network_interface_handle::Ocpp2_0_1NetworkInterfaceHandle;
 pub use self::ocpp2_0_1_request_receiver::Ocpp2_0_1RequestReceiver;
 pub use self::protocol_handle::ProtocolHandle;
+
+#[cfg(test)]
+pub use self::ocpp1_6_network_interface_handle::MockOcpp16NetworkInterfaceHandle;
+#[cfg(test)]
+pub use self::ocpp2_0_1_network_interface_handle::MockOcpp2_0_1NetworkInterfaceHandle;
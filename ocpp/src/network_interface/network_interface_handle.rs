@@ -3,4 +3,7 @@
 pub trait NetworkInterfaceHandle {
     /// Disconnect from the network interface.
     async fn disconnect(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Check that the other end is still there, it answers with a pong.
+    async fn send_ping(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
 }
@@ -1,4 +1,23 @@
 use crate::network_interface::NetworkInterfaceHandle;
+use crate::ocpp1_6_security::messages::certificate_signed::{
+    CertificateSignedRequest, CertificateSignedResponse,
+};
+use crate::ocpp1_6_security::messages::delete_certificate::{
+    DeleteCertificateRequest, DeleteCertificateResponse,
+};
+use crate::ocpp1_6_security::messages::extended_trigger_message::{
+    ExtendedTriggerMessageRequest, ExtendedTriggerMessageResponse,
+};
+use crate::ocpp1_6_security::messages::get_installed_certificate_ids::{
+    GetInstalledCertificateIdsRequest, GetInstalledCertificateIdsResponse,
+};
+use crate::ocpp1_6_security::messages::get_log::{GetLogRequest, GetLogResponse};
+use crate::ocpp1_6_security::messages::install_certificate::{
+    InstallCertificateRequest, InstallCertificateResponse,
+};
+use crate::ocpp1_6_security::messages::signed_update_firmware::{
+    SignedUpdateFirmwareRequest, SignedUpdateFirmwareResponse,
+};
 use ocpp_client::ocpp_1_6::OCPP1_6Error;
 use rust_ocpp::v1_6::messages::cancel_reservation::{
     CancelReservationRequest, CancelReservationResponse,
@@ -9,9 +28,20 @@ use rust_ocpp::v1_6::messages::change_availability::{
 use rust_ocpp::v1_6::messages::change_configuration::{
     ChangeConfigurationRequest, ChangeConfigurationResponse,
 };
+use rust_ocpp::v1_6::messages::clear_cache::{ClearCacheRequest, ClearCacheResponse};
+use rust_ocpp::v1_6::messages::clear_charging_profile::{
+    ClearChargingProfileRequest, ClearChargingProfileResponse,
+};
+use rust_ocpp::v1_6::messages::data_transfer::{DataTransferRequest, DataTransferResponse};
+use rust_ocpp::v1_6::messages::get_composite_schedule::{
+    GetCompositeScheduleRequest, GetCompositeScheduleResponse,
+};
 use rust_ocpp::v1_6::messages::get_configuration::{
     GetConfigurationRequest, GetConfigurationResponse,
 };
+use rust_ocpp::v1_6::messages::get_local_list_version::{
+    GetLocalListVersionRequest, GetLocalListVersionResponse,
+};
 use rust_ocpp::v1_6::messages::remote_start_transaction::{
     RemoteStartTransactionRequest, RemoteStartTransactionResponse,
 };
@@ -20,7 +50,15 @@ use rust_ocpp::v1_6::messages::remote_stop_transaction::{
 };
 use rust_ocpp::v1_6::messages::reserve_now::{ReserveNowRequest, ReserveNowResponse};
 use rust_ocpp::v1_6::messages::reset::{ResetRequest, ResetResponse};
+use rust_ocpp::v1_6::messages::send_local_list::{SendLocalListRequest, SendLocalListResponse};
+use rust_ocpp::v1_6::messages::set_charging_profile::{
+    SetChargingProfileRequest, SetChargingProfileResponse,
+};
 use rust_ocpp::v1_6::messages::trigger_message::{TriggerMessageRequest, TriggerMessageResponse};
+use rust_ocpp::v1_6::messages::unlock_connector::{
+    UnlockConnectorRequest, UnlockConnectorResponse,
+};
+use rust_ocpp::v1_6::messages::update_firmware::{UpdateFirmwareRequest, UpdateFirmwareResponse};
 
 #[async_trait::async_trait]
 pub trait Ocpp16NetworkInterfaceHandle: NetworkInterfaceHandle {
@@ -95,24 +133,169 @@ pub trait Ocpp16NetworkInterfaceHandle: NetworkInterfaceHandle {
         Result<ReserveNowResponse, OCPP1_6Error>,
         Box<dyn std::error::Error + Send + Sync + 'static>,
     >;
+
+    async fn send_set_charging_profile(
+        &self,
+        request: SetChargingProfileRequest,
+    ) -> Result<
+        Result<SetChargingProfileResponse, OCPP1_6Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    >;
+
+    async fn send_clear_charging_profile(
+        &self,
+        request: ClearChargingProfileRequest,
+    ) -> Result<
+        Result<ClearChargingProfileResponse, OCPP1_6Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    >;
+
+    async fn send_get_composite_schedule(
+        &self,
+        request: GetCompositeScheduleRequest,
+    ) -> Result<
+        Result<GetCompositeScheduleResponse, OCPP1_6Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    >;
+
+    async fn send_send_local_list(
+        &self,
+        request: SendLocalListRequest,
+    ) -> Result<
+        Result<SendLocalListResponse, OCPP1_6Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    >;
+
+    async fn send_get_local_list_version(
+        &self,
+        request: GetLocalListVersionRequest,
+    ) -> Result<
+        Result<GetLocalListVersionResponse, OCPP1_6Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    >;
+
+    async fn send_update_firmware(
+        &self,
+        request: UpdateFirmwareRequest,
+    ) -> Result<
+        Result<UpdateFirmwareResponse, OCPP1_6Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    >;
+
+    async fn send_clear_cache(
+        &self,
+        request: ClearCacheRequest,
+    ) -> Result<
+        Result<ClearCacheResponse, OCPP1_6Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    >;
+
+    async fn send_unlock_connector(
+        &self,
+        request: UnlockConnectorRequest,
+    ) -> Result<
+        Result<UnlockConnectorResponse, OCPP1_6Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    >;
+
+    async fn send_data_transfer(
+        &self,
+        request: DataTransferRequest,
+    ) -> Result<
+        Result<DataTransferResponse, OCPP1_6Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    >;
+
+    async fn send_certificate_signed(
+        &self,
+        request: CertificateSignedRequest,
+    ) -> Result<
+        Result<CertificateSignedResponse, OCPP1_6Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    >;
+
+    async fn send_extended_trigger_message(
+        &self,
+        request: ExtendedTriggerMessageRequest,
+    ) -> Result<
+        Result<ExtendedTriggerMessageResponse, OCPP1_6Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    >;
+
+    async fn send_install_certificate(
+        &self,
+        request: InstallCertificateRequest,
+    ) -> Result<
+        Result<InstallCertificateResponse, OCPP1_6Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    >;
+
+    async fn send_get_installed_certificate_ids(
+        &self,
+        request: GetInstalledCertificateIdsRequest,
+    ) -> Result<
+        Result<GetInstalledCertificateIdsResponse, OCPP1_6Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    >;
+
+    async fn send_delete_certificate(
+        &self,
+        request: DeleteCertificateRequest,
+    ) -> Result<
+        Result<DeleteCertificateResponse, OCPP1_6Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    >;
+
+    async fn send_get_log(
+        &self,
+        request: GetLogRequest,
+    ) -> Result<
+        Result<GetLogResponse, OCPP1_6Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    >;
+
+    async fn send_signed_update_firmware(
+        &self,
+        request: SignedUpdateFirmwareRequest,
+    ) -> Result<
+        Result<SignedUpdateFirmwareResponse, OCPP1_6Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    >;
 }
 #[cfg(test)]
 mockall::mock! {
     pub Ocpp16NetworkInterfaceHandle {}
     #[async_trait::async_trait]
     impl Ocpp16NetworkInterfaceHandle for Ocpp16NetworkInterfaceHandle {
-        async fn send_get_configuration(&self, request: GetConfigurationRequest) -> Result<Result<GetConfigurationResponse, OCPP1_6Error>, Box<dyn Error + Send + Sync + 'static>>;
-        async fn send_change_configuration(&self, request: ChangeConfigurationRequest) -> Result<Result<ChangeConfigurationResponse, OCPP1_6Error>, Box<dyn Error + Send + Sync + 'static>>;
-        async fn send_remote_start_transaction(&self, request: RemoteStartTransactionRequest) -> Result<Result<RemoteStartTransactionResponse, OCPP1_6Error>, Box<dyn Error + Send + Sync + 'static>>;
-        async fn send_remote_stop_transaction(&self, request: RemoteStopTransactionRequest) -> Result<Result<RemoteStopTransactionResponse, OCPP1_6Error>, Box<dyn Error + Send + Sync + 'static>>;
-        async fn send_trigger_message(&self, request: TriggerMessageRequest) -> Result<Result<TriggerMessageResponse, OCPP1_6Error>, Box<dyn Error + Send + Sync + 'static>>;
-        async fn send_reset(&self, request: ResetRequest) -> Result<Result<ResetResponse, OCPP1_6Error>, Box<dyn Error + Send + Sync + 'static>>;
-        async fn send_cancel_reservation(&self, request: CancelReservationRequest) -> Result<Result<CancelReservationResponse, OCPP1_6Error>, Box<dyn Error + Send + Sync + 'static>>;
-        async fn send_change_availability(&self, request: ChangeAvailabilityRequest) -> Result<Result<ChangeAvailabilityResponse, OCPP1_6Error>, Box<dyn Error + Send + Sync + 'static>>;
-        async fn send_reserve_now(&self, request: ReserveNowRequest) -> Result<Result<ReserveNowResponse, OCPP1_6Error>, Box<dyn Error + Send + Sync + 'static>>;
+        async fn send_get_configuration(&self, request: GetConfigurationRequest) -> Result<Result<GetConfigurationResponse, OCPP1_6Error>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+        async fn send_change_configuration(&self, request: ChangeConfigurationRequest) -> Result<Result<ChangeConfigurationResponse, OCPP1_6Error>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+        async fn send_remote_start_transaction(&self, request: RemoteStartTransactionRequest) -> Result<Result<RemoteStartTransactionResponse, OCPP1_6Error>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+        async fn send_remote_stop_transaction(&self, request: RemoteStopTransactionRequest) -> Result<Result<RemoteStopTransactionResponse, OCPP1_6Error>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+        async fn send_trigger_message(&self, request: TriggerMessageRequest) -> Result<Result<TriggerMessageResponse, OCPP1_6Error>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+        async fn send_reset(&self, request: ResetRequest) -> Result<Result<ResetResponse, OCPP1_6Error>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+        async fn send_cancel_reservation(&self, request: CancelReservationRequest) -> Result<Result<CancelReservationResponse, OCPP1_6Error>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+        async fn send_change_availability(&self, request: ChangeAvailabilityRequest) -> Result<Result<ChangeAvailabilityResponse, OCPP1_6Error>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+        async fn send_reserve_now(&self, request: ReserveNowRequest) -> Result<Result<ReserveNowResponse, OCPP1_6Error>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+        async fn send_set_charging_profile(&self, request: SetChargingProfileRequest) -> Result<Result<SetChargingProfileResponse, OCPP1_6Error>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+        async fn send_clear_charging_profile(&self, request: ClearChargingProfileRequest) -> Result<Result<ClearChargingProfileResponse, OCPP1_6Error>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+        async fn send_get_composite_schedule(&self, request: GetCompositeScheduleRequest) -> Result<Result<GetCompositeScheduleResponse, OCPP1_6Error>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+        async fn send_send_local_list(&self, request: SendLocalListRequest) -> Result<Result<SendLocalListResponse, OCPP1_6Error>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+        async fn send_get_local_list_version(&self, request: GetLocalListVersionRequest) -> Result<Result<GetLocalListVersionResponse, OCPP1_6Error>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+        async fn send_update_firmware(&self, request: UpdateFirmwareRequest) -> Result<Result<UpdateFirmwareResponse, OCPP1_6Error>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+        async fn send_clear_cache(&self, request: ClearCacheRequest) -> Result<Result<ClearCacheResponse, OCPP1_6Error>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+        async fn send_unlock_connector(&self, request: UnlockConnectorRequest) -> Result<Result<UnlockConnectorResponse, OCPP1_6Error>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+        async fn send_data_transfer(&self, request: DataTransferRequest) -> Result<Result<DataTransferResponse, OCPP1_6Error>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+        async fn send_certificate_signed(&self, request: CertificateSignedRequest) -> Result<Result<CertificateSignedResponse, OCPP1_6Error>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+        async fn send_extended_trigger_message(&self, request: ExtendedTriggerMessageRequest) -> Result<Result<ExtendedTriggerMessageResponse, OCPP1_6Error>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+        async fn send_install_certificate(&self, request: InstallCertificateRequest) -> Result<Result<InstallCertificateResponse, OCPP1_6Error>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+        async fn send_get_installed_certificate_ids(&self, request: GetInstalledCertificateIdsRequest) -> Result<Result<GetInstalledCertificateIdsResponse, OCPP1_6Error>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+        async fn send_delete_certificate(&self, request: DeleteCertificateRequest) -> Result<Result<DeleteCertificateResponse, OCPP1_6Error>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+        async fn send_get_log(&self, request: GetLogRequest) -> Result<Result<GetLogResponse, OCPP1_6Error>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+        async fn send_signed_update_firmware(&self, request: SignedUpdateFirmwareRequest) -> Result<Result<SignedUpdateFirmwareResponse, OCPP1_6Error>, Box<dyn std::error::Error + Send + Sync + 'static>>;
     }
     #[async_trait::async_trait]
     impl NetworkInterfaceHandle for Ocpp16NetworkInterfaceHandle {
         async fn disconnect(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+        async fn send_ping(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
     }
 }
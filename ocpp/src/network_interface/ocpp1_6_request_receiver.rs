@@ -1,9 +1,21 @@
+use crate::data_transfer::DataTransferRegistry;
+use crate::ocpp1_6_security::messages::log_status_notification::{
+    LogStatusNotificationRequest, LogStatusNotificationResponse,
+};
+use crate::ocpp1_6_security::messages::security_event_notification::{
+    SecurityEventNotificationRequest, SecurityEventNotificationResponse,
+};
+use crate::ocpp1_6_security::messages::sign_certificate::{
+    SignCertificateRequest, SignCertificateResponse,
+};
+use crate::ocpp1_6_security::messages::signed_firmware_status_notification::{
+    SignedFirmwareStatusNotificationRequest, SignedFirmwareStatusNotificationResponse,
+};
 use ocpp_client::ocpp_1_6::OCPP1_6Error;
 use rust_ocpp::v1_6::messages::authorize::{AuthorizeRequest, AuthorizeResponse};
 use rust_ocpp::v1_6::messages::boot_notification::{
     BootNotificationRequest, BootNotificationResponse,
 };
-use rust_ocpp::v1_6::messages::data_transfer::{DataTransferRequest, DataTransferResponse};
 use rust_ocpp::v1_6::messages::diagnostics_status_notification::{
     DiagnosticsStatusNotificationRequest, DiagnosticsStatusNotificationResponse,
 };
@@ -40,10 +52,8 @@ pub trait Ocpp16RequestReceiver {
         request: BootNotificationRequest,
     ) -> Result<BootNotificationResponse, OCPP1_6Error>;
 
-    async fn data_transfer(
-        &mut self,
-        _request: DataTransferRequest,
-    ) -> Result<DataTransferResponse, OCPP1_6Error>;
+    /// DataTransfer is dispatched to this registry without holding the charger lock
+    fn data_transfer_registry(&self) -> DataTransferRegistry;
 
     async fn diagnostics_status_notification(
         &mut self,
@@ -60,11 +70,31 @@ pub trait Ocpp16RequestReceiver {
         _request: HeartbeatRequest,
     ) -> Result<HeartbeatResponse, OCPP1_6Error>;
 
+    async fn log_status_notification(
+        &mut self,
+        request: LogStatusNotificationRequest,
+    ) -> Result<LogStatusNotificationResponse, OCPP1_6Error>;
+
     async fn meter_values(
         &mut self,
         request: MeterValuesRequest,
     ) -> Result<MeterValuesResponse, OCPP1_6Error>;
 
+    async fn security_event_notification(
+        &mut self,
+        request: SecurityEventNotificationRequest,
+    ) -> Result<SecurityEventNotificationResponse, OCPP1_6Error>;
+
+    async fn sign_certificate(
+        &mut self,
+        request: SignCertificateRequest,
+    ) -> Result<SignCertificateResponse, OCPP1_6Error>;
+
+    async fn signed_firmware_status_notification(
+        &mut self,
+        request: SignedFirmwareStatusNotificationRequest,
+    ) -> Result<SignedFirmwareStatusNotificationResponse, OCPP1_6Error>;
+
     async fn start_transaction(
         &mut self,
         request: StartTransactionRequest,
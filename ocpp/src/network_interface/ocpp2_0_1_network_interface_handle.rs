@@ -1,16 +1,84 @@
 use crate::network_interface::NetworkInterfaceHandle;
+use ocpp_client::ocpp_2_0_1::OCPP2_0_1Error;
+use rust_ocpp::v2_0_1::messages::clear_charging_profile::{
+    ClearChargingProfileRequest, ClearChargingProfileResponse,
+};
+use rust_ocpp::v2_0_1::messages::datatransfer::{DataTransferRequest, DataTransferResponse};
+use rust_ocpp::v2_0_1::messages::set_charging_profile::{
+    SetChargingProfileRequest, SetChargingProfileResponse,
+};
+use rust_ocpp::v2_0_1::messages::trigger_message::{TriggerMessageRequest, TriggerMessageResponse};
+use rust_ocpp::v2_0_1::messages::unlock_connector::{
+    UnlockConnectorRequest, UnlockConnectorResponse,
+};
+use rust_ocpp::v2_0_1::messages::update_firmware::{UpdateFirmwareRequest, UpdateFirmwareResponse};
 
 #[async_trait::async_trait]
-pub trait Ocpp2_0_1NetworkInterfaceHandle: NetworkInterfaceHandle {}
+pub trait Ocpp2_0_1NetworkInterfaceHandle: NetworkInterfaceHandle {
+    async fn send_set_charging_profile(
+        &self,
+        request: SetChargingProfileRequest,
+    ) -> Result<
+        Result<SetChargingProfileResponse, OCPP2_0_1Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    >;
+
+    async fn send_clear_charging_profile(
+        &self,
+        request: ClearChargingProfileRequest,
+    ) -> Result<
+        Result<ClearChargingProfileResponse, OCPP2_0_1Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    >;
+
+    async fn send_update_firmware(
+        &self,
+        request: UpdateFirmwareRequest,
+    ) -> Result<
+        Result<UpdateFirmwareResponse, OCPP2_0_1Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    >;
+
+    async fn send_unlock_connector(
+        &self,
+        request: UnlockConnectorRequest,
+    ) -> Result<
+        Result<UnlockConnectorResponse, OCPP2_0_1Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    >;
+
+    async fn send_trigger_message(
+        &self,
+        request: TriggerMessageRequest,
+    ) -> Result<
+        Result<TriggerMessageResponse, OCPP2_0_1Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    >;
+
+    async fn send_data_transfer(
+        &self,
+        request: DataTransferRequest,
+    ) -> Result<
+        Result<DataTransferResponse, OCPP2_0_1Error>,
+        Box<dyn std::error::Error + Send + Sync + 'static>,
+    >;
+}
 
 #[cfg(test)]
 mockall::mock! {
     pub Ocpp2_0_1NetworkInterfaceHandle {}
     #[async_trait::async_trait]
     impl Ocpp2_0_1NetworkInterfaceHandle for Ocpp2_0_1NetworkInterfaceHandle {
+        async fn send_set_charging_profile(&self, request: SetChargingProfileRequest) -> Result<Result<SetChargingProfileResponse, OCPP2_0_1Error>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+        async fn send_clear_charging_profile(&self, request: ClearChargingProfileRequest) -> Result<Result<ClearChargingProfileResponse, OCPP2_0_1Error>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+        async fn send_update_firmware(&self, request: UpdateFirmwareRequest) -> Result<Result<UpdateFirmwareResponse, OCPP2_0_1Error>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+        async fn send_unlock_connector(&self, request: UnlockConnectorRequest) -> Result<Result<UnlockConnectorResponse, OCPP2_0_1Error>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+        async fn send_trigger_message(&self, request: TriggerMessageRequest) -> Result<Result<TriggerMessageResponse, OCPP2_0_1Error>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+        async fn send_data_transfer(&self, request: DataTransferRequest) -> Result<Result<DataTransferResponse, OCPP2_0_1Error>, Box<dyn std::error::Error + Send + Sync + 'static>>;
     }
     #[async_trait::async_trait]
     impl NetworkInterfaceHandle for Ocpp2_0_1NetworkInterfaceHandle {
         async fn disconnect(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+        async fn send_ping(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
     }
 }
@@ -1,3 +1,4 @@
+use crate::data_transfer::DataTransferRegistry;
 use ocpp_client::ocpp_2_0_1::OCPP2_0_1Error;
 use rust_ocpp::v2_0_1::messages::authorize::{AuthorizeRequest, AuthorizeResponse};
 use rust_ocpp::v2_0_1::messages::boot_notification::{
@@ -6,7 +7,6 @@ use rust_ocpp::v2_0_1::messages::boot_notification::{
 use rust_ocpp::v2_0_1::messages::cleared_charging_limit::{
     ClearedChargingLimitRequest, ClearedChargingLimitResponse,
 };
-use rust_ocpp::v2_0_1::messages::datatransfer::{DataTransferRequest, DataTransferResponse};
 use rust_ocpp::v2_0_1::messages::firmware_status_notification::{
     FirmwareStatusNotificationRequest, FirmwareStatusNotificationResponse,
 };
@@ -89,10 +89,8 @@ pub trait Ocpp2_0_1RequestReceiver {
         request: ClearedChargingLimitRequest,
     ) -> Result<ClearedChargingLimitResponse, OCPP2_0_1Error>;
 
-    async fn data_transfer(
-        &mut self,
-        request: DataTransferRequest,
-    ) -> Result<DataTransferResponse, OCPP2_0_1Error>;
+    /// DataTransfer is dispatched to this registry without holding the charger lock
+    fn data_transfer_registry(&self) -> DataTransferRegistry;
 
     async fn firmware_status_notification(
         &mut self,
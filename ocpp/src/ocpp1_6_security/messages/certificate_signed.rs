@@ -0,0 +1,15 @@
+use crate::ocpp1_6_security::types::CertificateSignedStatus;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CertificateSignedRequest {
+    /// PEM encoded certificate chain, the charger certificate first
+    pub certificate_chain: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CertificateSignedResponse {
+    pub status: CertificateSignedStatus,
+}
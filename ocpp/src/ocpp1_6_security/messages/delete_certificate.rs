@@ -0,0 +1,14 @@
+use crate::ocpp1_6_security::types::{CertificateHashData, DeleteCertificateStatus};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteCertificateRequest {
+    pub certificate_hash_data: CertificateHashData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteCertificateResponse {
+    pub status: DeleteCertificateStatus,
+}
@@ -0,0 +1,17 @@
+use crate::ocpp1_6_security::types::ExtendedMessageTrigger;
+use rust_ocpp::v1_6::types::TriggerMessageStatus;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtendedTriggerMessageRequest {
+    pub requested_message: ExtendedMessageTrigger,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connector_id: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtendedTriggerMessageResponse {
+    pub status: TriggerMessageStatus,
+}
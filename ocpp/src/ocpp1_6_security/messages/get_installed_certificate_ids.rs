@@ -0,0 +1,18 @@
+use crate::ocpp1_6_security::types::{
+    CertificateHashData, CertificateUse, GetInstalledCertificateStatus,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetInstalledCertificateIdsRequest {
+    pub certificate_type: CertificateUse,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetInstalledCertificateIdsResponse {
+    pub status: GetInstalledCertificateStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub certificate_hash_data: Option<Vec<CertificateHashData>>,
+}
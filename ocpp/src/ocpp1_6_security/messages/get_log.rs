@@ -0,0 +1,23 @@
+use crate::ocpp1_6_security::types::{LogParameters, LogStatus, LogType};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetLogRequest {
+    pub log: LogParameters,
+    pub log_type: LogType,
+    pub request_id: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retries: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_interval: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetLogResponse {
+    pub status: LogStatus,
+    /// The name of the file the log is uploaded as
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
+}
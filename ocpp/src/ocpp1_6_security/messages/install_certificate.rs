@@ -0,0 +1,16 @@
+use crate::ocpp1_6_security::types::{CertificateStatus, CertificateUse};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct InstallCertificateRequest {
+    pub certificate_type: CertificateUse,
+    /// PEM encoded certificate
+    pub certificate: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct InstallCertificateResponse {
+    pub status: CertificateStatus,
+}
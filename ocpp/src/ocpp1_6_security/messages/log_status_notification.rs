@@ -0,0 +1,13 @@
+use crate::ocpp1_6_security::types::UploadLogStatus;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LogStatusNotificationRequest {
+    pub status: UploadLogStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct LogStatusNotificationResponse {}
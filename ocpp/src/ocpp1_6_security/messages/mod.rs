@@ -0,0 +1,11 @@
+pub mod certificate_signed;
+pub mod delete_certificate;
+pub mod extended_trigger_message;
+pub mod get_installed_certificate_ids;
+pub mod get_log;
+pub mod install_certificate;
+pub mod log_status_notification;
+pub mod security_event_notification;
+pub mod sign_certificate;
+pub mod signed_firmware_status_notification;
+pub mod signed_update_firmware;
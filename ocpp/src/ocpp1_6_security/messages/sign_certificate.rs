@@ -0,0 +1,15 @@
+use crate::ocpp1_6_security::types::GenericStatus;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SignCertificateRequest {
+    /// PEM encoded certificate signing request
+    pub csr: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SignCertificateResponse {
+    pub status: GenericStatus,
+}
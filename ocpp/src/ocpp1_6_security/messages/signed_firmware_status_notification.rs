@@ -0,0 +1,14 @@
+use rust_ocpp::v2_0_1::enumerations::firmware_status_enum_type::FirmwareStatusEnumType;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SignedFirmwareStatusNotificationRequest {
+    /// The security extension uses the firmware statuses OCPP 2.0.1 adopted
+    pub status: FirmwareStatusEnumType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct SignedFirmwareStatusNotificationResponse {}
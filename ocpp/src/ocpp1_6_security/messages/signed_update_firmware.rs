@@ -0,0 +1,20 @@
+use crate::ocpp1_6_security::types::SignedFirmware;
+use rust_ocpp::v2_0_1::enumerations::update_firmware_status_enum_type::UpdateFirmwareStatusEnumType;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SignedUpdateFirmwareRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retries: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_interval: Option<i32>,
+    pub request_id: i32,
+    pub firmware: SignedFirmware,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SignedUpdateFirmwareResponse {
+    pub status: UpdateFirmwareStatusEnumType,
+}
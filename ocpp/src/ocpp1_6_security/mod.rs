@@ -0,0 +1,5 @@
+//! Messages of the OCPP 1.6 security extension, described in the "Improved security for OCPP
+//! 1.6-J" whitepaper. The extension isn't part of rust_ocpp, so its messages are defined here.
+
+pub mod messages;
+pub mod types;
@@ -0,0 +1,12 @@
+use crate::ocpp1_6_security::types::HashAlgorithm;
+use serde::{Deserialize, Serialize};
+
+/// Identifies a certificate by hashes of its issuer and its serial number
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CertificateHashData {
+    pub hash_algorithm: HashAlgorithm,
+    pub issuer_name_hash: String,
+    pub issuer_key_hash: String,
+    pub serial_number: String,
+}
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+/// The result of installing a certificate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum CertificateStatus {
+    #[default]
+    Accepted,
+    Failed,
+    Rejected,
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// The messages ExtendedTriggerMessage can request, which include the messages of the security extension
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ExtendedMessageTrigger {
+    #[default]
+    BootNotification,
+    LogStatusNotification,
+    FirmwareStatusNotification,
+    Heartbeat,
+    MeterValues,
+    SignChargePointCertificate,
+    StatusNotification,
+}
@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum GetInstalledCertificateStatus {
+    #[default]
+    Accepted,
+    NotFound,
+}
@@ -0,0 +1,13 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LogParameters {
+    /// Where the charger uploads the log to
+    pub remote_location: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oldest_timestamp: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest_timestamp: Option<DateTime<Utc>>,
+}
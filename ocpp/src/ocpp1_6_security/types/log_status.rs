@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum LogStatus {
+    #[default]
+    Accepted,
+    Rejected,
+    AcceptedCanceled,
+}
@@ -0,0 +1,29 @@
+mod certificate_hash_data;
+mod certificate_signed_status;
+mod certificate_status;
+mod certificate_use;
+mod delete_certificate_status;
+mod extended_message_trigger;
+mod generic_status;
+mod get_installed_certificate_status;
+mod hash_algorithm;
+mod log_parameters;
+mod log_status;
+mod log_type;
+mod signed_firmware;
+mod upload_log_status;
+
+pub use self::certificate_hash_data::CertificateHashData;
+pub use self::certificate_signed_status::CertificateSignedStatus;
+pub use self::certificate_status::CertificateStatus;
+pub use self::certificate_use::CertificateUse;
+pub use self::delete_certificate_status::DeleteCertificateStatus;
+pub use self::extended_message_trigger::ExtendedMessageTrigger;
+pub use self::generic_status::GenericStatus;
+pub use self::get_installed_certificate_status::GetInstalledCertificateStatus;
+pub use self::hash_algorithm::HashAlgorithm;
+pub use self::log_parameters::LogParameters;
+pub use self::log_status::LogStatus;
+pub use self::log_type::LogType;
+pub use self::signed_firmware::SignedFirmware;
+pub use self::upload_log_status::UploadLogStatus;
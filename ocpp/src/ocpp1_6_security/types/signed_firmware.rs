@@ -0,0 +1,15 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SignedFirmware {
+    pub location: String,
+    pub retrieve_date_time: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub install_date_time: Option<DateTime<Utc>>,
+    /// PEM encoded certificate of the key the firmware was signed with
+    pub signing_certificate: String,
+    /// Base64 encoded signature of the firmware
+    pub signature: String,
+}
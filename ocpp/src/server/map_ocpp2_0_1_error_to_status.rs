@@ -0,0 +1,6 @@
+use ocpp_client::ocpp_2_0_1::OCPP2_0_1Error;
+use tonic::Status;
+
+pub fn map_ocpp2_0_1_error_to_status(error: OCPP2_0_1Error) -> tonic::Status {
+    Status::internal(error.to_string())
+}
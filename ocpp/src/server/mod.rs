@@ -1,6 +1,8 @@
 mod map_ocpp1_6_error_to_status;
+mod map_ocpp2_0_1_error_to_status;
 mod ocpp_service;
 mod start_server;
 
 pub use self::map_ocpp1_6_error_to_status::map_ocpp1_6_error_to_status;
+pub use self::map_ocpp2_0_1_error_to_status::map_ocpp2_0_1_error_to_status;
 pub use self::start_server::start_server;
@@ -1,18 +1,34 @@
-use crate::charger::ChargerPool;
+use crate::charger::{ChargerPool, EvseLimit, FirmwareUpdateOptions};
 use crate::ocpp_csms_server::ocpp_server::Ocpp;
 use crate::ocpp_csms_server::reboot_charger_request::RebootType;
+use crate::ocpp_csms_server::set_evse_limit_request;
 use crate::ocpp_csms_server::{
-    CancelOutletReservationRequest, CancelOutletReservationResponse,
+    CancelOutletReservationRequest, CancelOutletReservationResponse, CertificateHashData,
+    CertificateSignedRequest, CertificateSignedResponse, CertificateSignedStatus, CertificateUse,
     ChangeChargerAvailabilityRequest, ChangeChargerAvailabilityResponse,
     ChangeConnectorAvailabilityRequest, ChangeConnectorAvailabilityResponse,
     ChangeEvseAvailabilityRequest, ChangeEvseAvailabilityResponse,
     ChangeOcpp16configurationValueRequest, ChangeOcpp16configurationValueResponse,
-    ClearChargerCacheRequest, ClearChargerCacheResponse, CreateRfidScanSessionRequest,
-    CreateRfidScanSessionResponse, RebootChargerRequest, RebootChargerResponse,
-    RfidScanSessionStatus, StartTransactionRequest, StartTransactionResponse,
-    StopTransactionRequest, StopTransactionResponse,
+    ChargingProfileKind, ChargingProfilePurpose, ChargingRateUnit, ClearChargerCacheRequest,
+    ClearChargerCacheResponse, ClearChargingProfileRequest, ClearChargingProfileResponse,
+    ConfigurationStatus, CreateRfidScanSessionRequest, CreateRfidScanSessionResponse,
+    DataTransferRequest, DataTransferResponse, DataTransferStatus, DeleteCertificateRequest,
+    DeleteCertificateResponse, DeleteCertificateStatus, ExtendedTriggerMessageRequest,
+    ExtendedTriggerMessageResponse, ExtendedTriggerableMessage, GetCompositeScheduleRequest,
+    GetCompositeScheduleResponse, GetInstalledCertificateIdsRequest,
+    GetInstalledCertificateIdsResponse, GetInstalledCertificateIdsStatus,
+    GetLocalListVersionRequest, GetLocalListVersionResponse, GetLogRequest, GetLogResponse,
+    HashAlgorithm, InstallCertificateRequest, InstallCertificateResponse, InstallCertificateStatus,
+    LocalAuthorizationStatus, LocalListUpdateType, LogStatus, LogType, RebootChargerRequest,
+    RebootChargerResponse, RecurrencyKind, RfidScanSessionStatus, SendLocalListRequest,
+    SendLocalListResponse, SetChargingProfileRequest, SetChargingProfileResponse,
+    SetEvseLimitRequest, SetEvseLimitResponse, StartTransactionRequest, StartTransactionResponse,
+    StopTransactionRequest, StopTransactionResponse, TriggerMessageRequest, TriggerMessageResponse,
+    TriggerMessageStatus, TriggerableMessage, UnlockConnectorRequest, UnlockConnectorResponse,
+    UnlockStatus, UpdateChargerSettingsRequest, UpdateChargerSettingsResponse,
+    UpdateFirmwareRequest, UpdateFirmwareResponse,
 };
-use chrono::{TimeDelta, Utc};
+use chrono::{DateTime, TimeDelta, Utc};
 use shared::data::ConnectorStatus;
 use std::ops::Add;
 use tonic::{Request, Response, Status};
@@ -39,11 +55,15 @@ impl Ocpp for OcppService {
         let payload = request.into_inner();
         let evse_id = Uuid::parse_str(&payload.evse_id)
             .map_err(|_| Status::invalid_argument("Invalid evse_id"))?;
+        let charging_profile = payload
+            .charging_profile
+            .map(shared::data::ChargingProfile::try_from)
+            .transpose()?;
 
         match self.charger_pool.get(&payload.charger_id).await {
             Some(charger) => {
                 let mut lock = charger.lock().await;
-                let transaction = lock.start_transaction(evse_id).await?;
+                let transaction = lock.start_transaction(evse_id, charging_profile).await?;
                 Ok(Response::new(StartTransactionResponse {
                     transaction: Some(transaction.into()),
                 }))
@@ -156,18 +176,381 @@ impl Ocpp for OcppService {
         }
     }
 
+    async fn unlock_connector(
+        &self,
+        request: Request<UnlockConnectorRequest>,
+    ) -> Result<Response<UnlockConnectorResponse>, Status> {
+        let payload = request.into_inner();
+        let evse_id = Uuid::parse_str(&payload.evse_id)
+            .map_err(|_| Status::invalid_argument("Invalid evse_id"))?;
+        let connector_id = Uuid::parse_str(&payload.connector_id)
+            .map_err(|_| Status::invalid_argument("Invalid connector_id"))?;
+        match self.charger_pool.get(&payload.charger_id).await {
+            Some(charger) => {
+                let mut lock = charger.lock().await;
+                let status = lock
+                    .unlock_connector(evse_id, connector_id, payload.override_permanent_lock)
+                    .await?;
+
+                Ok(Response::new(UnlockConnectorResponse {
+                    status: match status {
+                        rust_ocpp::v1_6::types::UnlockStatus::Unlocked => UnlockStatus::Unlocked,
+                        rust_ocpp::v1_6::types::UnlockStatus::UnlockFailed => {
+                            UnlockStatus::UnlockFailed
+                        }
+                        rust_ocpp::v1_6::types::UnlockStatus::NotSupported => {
+                            UnlockStatus::NotSupported
+                        }
+                    }
+                    .into(),
+                }))
+            }
+            None => Err(Status::not_found(
+                "A charger with this id is not connected to this instance",
+            )),
+        }
+    }
+
+    async fn trigger_message(
+        &self,
+        request: Request<TriggerMessageRequest>,
+    ) -> Result<Response<TriggerMessageResponse>, Status> {
+        let payload = request.into_inner();
+        let message = match TriggerableMessage::try_from(payload.message)
+            .map_err(|_| Status::invalid_argument("Invalid message"))?
+        {
+            TriggerableMessage::Unspecified => {
+                return Err(Status::invalid_argument("Missing message"));
+            }
+            TriggerableMessage::BootNotification => {
+                crate::charger::TriggerableMessage::BootNotification
+            }
+            TriggerableMessage::DiagnosticsStatusNotification => {
+                crate::charger::TriggerableMessage::DiagnosticsStatusNotification
+            }
+            TriggerableMessage::FirmwareStatusNotification => {
+                crate::charger::TriggerableMessage::FirmwareStatusNotification
+            }
+            TriggerableMessage::Heartbeat => crate::charger::TriggerableMessage::Heartbeat,
+            TriggerableMessage::MeterValues => crate::charger::TriggerableMessage::MeterValues,
+            TriggerableMessage::StatusNotification => {
+                crate::charger::TriggerableMessage::StatusNotification
+            }
+        };
+        let evse_id = parse_optional_evse_id(payload.evse_id.as_deref())?;
+
+        match self.charger_pool.get(&payload.charger_id).await {
+            Some(charger) => {
+                let mut lock = charger.lock().await;
+                let status = lock.trigger_message(message, evse_id).await?;
+
+                Ok(Response::new(TriggerMessageResponse {
+                    status: match status {
+                        rust_ocpp::v1_6::types::TriggerMessageStatus::Accepted => {
+                            TriggerMessageStatus::Accepted
+                        }
+                        rust_ocpp::v1_6::types::TriggerMessageStatus::Rejected => {
+                            TriggerMessageStatus::Rejected
+                        }
+                        rust_ocpp::v1_6::types::TriggerMessageStatus::NotImplemented => {
+                            TriggerMessageStatus::NotImplemented
+                        }
+                    }
+                    .into(),
+                }))
+            }
+            None => Err(Status::not_found(
+                "A charger with this id is not connected to this instance",
+            )),
+        }
+    }
+
+    async fn data_transfer(
+        &self,
+        request: Request<DataTransferRequest>,
+    ) -> Result<Response<DataTransferResponse>, Status> {
+        let payload = request.into_inner();
+        if payload.vendor_id.is_empty() {
+            return Err(Status::invalid_argument("Missing vendor id"));
+        }
+
+        match self.charger_pool.get(&payload.charger_id).await {
+            Some(charger) => {
+                let mut lock = charger.lock().await;
+                let reply = lock
+                    .data_transfer(&payload.vendor_id, payload.message_id, payload.data)
+                    .await?;
+
+                Ok(Response::new(DataTransferResponse {
+                    status: match reply.status {
+                        crate::data_transfer::DataTransferStatus::Accepted => {
+                            DataTransferStatus::Accepted
+                        }
+                        crate::data_transfer::DataTransferStatus::Rejected => {
+                            DataTransferStatus::Rejected
+                        }
+                        crate::data_transfer::DataTransferStatus::UnknownMessageId => {
+                            DataTransferStatus::UnknownMessageId
+                        }
+                        crate::data_transfer::DataTransferStatus::UnknownVendorId => {
+                            DataTransferStatus::UnknownVendorId
+                        }
+                    }
+                    .into(),
+                    data: reply.data,
+                }))
+            }
+            None => Err(Status::not_found(
+                "A charger with this id is not connected to this instance",
+            )),
+        }
+    }
+
+    async fn certificate_signed(
+        &self,
+        request: Request<CertificateSignedRequest>,
+    ) -> Result<Response<CertificateSignedResponse>, Status> {
+        let payload = request.into_inner();
+        if payload.certificate_chain.is_empty() {
+            return Err(Status::invalid_argument("Missing certificate chain"));
+        }
+
+        match self.charger_pool.get(&payload.charger_id).await {
+            Some(charger) => {
+                let mut lock = charger.lock().await;
+                let status = lock.certificate_signed(payload.certificate_chain).await?;
+
+                Ok(Response::new(CertificateSignedResponse {
+                    status: CertificateSignedStatus::from(status).into(),
+                }))
+            }
+            None => Err(Status::not_found(
+                "A charger with this id is not connected to this instance",
+            )),
+        }
+    }
+
+    async fn install_certificate(
+        &self,
+        request: Request<InstallCertificateRequest>,
+    ) -> Result<Response<InstallCertificateResponse>, Status> {
+        let payload = request.into_inner();
+        let certificate_type = CertificateUse::try_from(payload.certificate_type)
+            .map_err(|_| Status::invalid_argument("Invalid certificate type"))?
+            .try_into()?;
+        if payload.certificate.is_empty() {
+            return Err(Status::invalid_argument("Missing certificate"));
+        }
+
+        match self.charger_pool.get(&payload.charger_id).await {
+            Some(charger) => {
+                let mut lock = charger.lock().await;
+                let status = lock
+                    .install_certificate(certificate_type, payload.certificate)
+                    .await?;
+
+                Ok(Response::new(InstallCertificateResponse {
+                    status: InstallCertificateStatus::from(status).into(),
+                }))
+            }
+            None => Err(Status::not_found(
+                "A charger with this id is not connected to this instance",
+            )),
+        }
+    }
+
+    async fn get_installed_certificate_ids(
+        &self,
+        request: Request<GetInstalledCertificateIdsRequest>,
+    ) -> Result<Response<GetInstalledCertificateIdsResponse>, Status> {
+        let payload = request.into_inner();
+        let certificate_type = CertificateUse::try_from(payload.certificate_type)
+            .map_err(|_| Status::invalid_argument("Invalid certificate type"))?
+            .try_into()?;
+
+        match self.charger_pool.get(&payload.charger_id).await {
+            Some(charger) => {
+                let mut lock = charger.lock().await;
+                let response = lock.get_installed_certificate_ids(certificate_type).await?;
+
+                Ok(Response::new(GetInstalledCertificateIdsResponse {
+                    status: GetInstalledCertificateIdsStatus::from(response.status).into(),
+                    certificate_hash_data: response
+                        .certificate_hash_data
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(Into::into)
+                        .collect(),
+                }))
+            }
+            None => Err(Status::not_found(
+                "A charger with this id is not connected to this instance",
+            )),
+        }
+    }
+
+    async fn delete_certificate(
+        &self,
+        request: Request<DeleteCertificateRequest>,
+    ) -> Result<Response<DeleteCertificateResponse>, Status> {
+        let payload = request.into_inner();
+        let certificate_hash_data = payload
+            .certificate_hash_data
+            .ok_or_else(|| Status::invalid_argument("Missing certificate hash data"))?
+            .try_into()?;
+
+        match self.charger_pool.get(&payload.charger_id).await {
+            Some(charger) => {
+                let mut lock = charger.lock().await;
+                let status = lock.delete_certificate(certificate_hash_data).await?;
+
+                Ok(Response::new(DeleteCertificateResponse {
+                    status: DeleteCertificateStatus::from(status).into(),
+                }))
+            }
+            None => Err(Status::not_found(
+                "A charger with this id is not connected to this instance",
+            )),
+        }
+    }
+
+    async fn extended_trigger_message(
+        &self,
+        request: Request<ExtendedTriggerMessageRequest>,
+    ) -> Result<Response<ExtendedTriggerMessageResponse>, Status> {
+        let payload = request.into_inner();
+        let message = ExtendedTriggerableMessage::try_from(payload.message)
+            .map_err(|_| Status::invalid_argument("Invalid message"))?
+            .try_into()?;
+        let evse_id = parse_optional_evse_id(payload.evse_id.as_deref())?;
+
+        match self.charger_pool.get(&payload.charger_id).await {
+            Some(charger) => {
+                let mut lock = charger.lock().await;
+                let status = lock.extended_trigger_message(message, evse_id).await?;
+
+                Ok(Response::new(ExtendedTriggerMessageResponse {
+                    status: match status {
+                        rust_ocpp::v1_6::types::TriggerMessageStatus::Accepted => {
+                            TriggerMessageStatus::Accepted
+                        }
+                        rust_ocpp::v1_6::types::TriggerMessageStatus::Rejected => {
+                            TriggerMessageStatus::Rejected
+                        }
+                        rust_ocpp::v1_6::types::TriggerMessageStatus::NotImplemented => {
+                            TriggerMessageStatus::NotImplemented
+                        }
+                    }
+                    .into(),
+                }))
+            }
+            None => Err(Status::not_found(
+                "A charger with this id is not connected to this instance",
+            )),
+        }
+    }
+
+    async fn get_log(
+        &self,
+        request: Request<GetLogRequest>,
+    ) -> Result<Response<GetLogResponse>, Status> {
+        let payload = request.into_inner();
+        if payload.remote_location.is_empty() {
+            return Err(Status::invalid_argument("Missing remote location"));
+        }
+        let log_request = crate::ocpp1_6_security::messages::get_log::GetLogRequest {
+            log: crate::ocpp1_6_security::types::LogParameters {
+                remote_location: payload.remote_location,
+                oldest_timestamp: payload
+                    .oldest_timestamp
+                    .map(timestamp_from_millis)
+                    .transpose()?,
+                latest_timestamp: payload
+                    .latest_timestamp
+                    .map(timestamp_from_millis)
+                    .transpose()?,
+            },
+            log_type: LogType::try_from(payload.log_type)
+                .map_err(|_| Status::invalid_argument("Invalid log type"))?
+                .try_into()?,
+            request_id: payload.request_id,
+            retries: payload.retries,
+            retry_interval: payload.retry_interval,
+        };
+
+        match self.charger_pool.get(&payload.charger_id).await {
+            Some(charger) => {
+                let mut lock = charger.lock().await;
+                let response = lock.get_log(log_request).await?;
+
+                Ok(Response::new(GetLogResponse {
+                    status: LogStatus::from(response.status).into(),
+                    filename: response.filename,
+                }))
+            }
+            None => Err(Status::not_found(
+                "A charger with this id is not connected to this instance",
+            )),
+        }
+    }
+
     async fn change_ocpp1_6configuration_value(
         &self,
-        _request: Request<ChangeOcpp16configurationValueRequest>,
+        request: Request<ChangeOcpp16configurationValueRequest>,
     ) -> Result<Response<ChangeOcpp16configurationValueResponse>, Status> {
-        todo!()
+        let payload = request.into_inner();
+        if payload.key.is_empty() {
+            return Err(Status::invalid_argument("Missing configuration key"));
+        }
+
+        match self.charger_pool.get(&payload.charger_id).await {
+            Some(charger) => {
+                let mut lock = charger.lock().await;
+                let status = lock
+                    .change_ocpp_1_6_configuration_value(&payload.key, &payload.value)
+                    .await?;
+
+                Ok(Response::new(ChangeOcpp16configurationValueResponse {
+                    status: match status {
+                        rust_ocpp::v1_6::types::ConfigurationStatus::Accepted => {
+                            ConfigurationStatus::Accepted
+                        }
+                        rust_ocpp::v1_6::types::ConfigurationStatus::Rejected => {
+                            ConfigurationStatus::Rejected
+                        }
+                        rust_ocpp::v1_6::types::ConfigurationStatus::RebootRequired => {
+                            ConfigurationStatus::RebootRequired
+                        }
+                        rust_ocpp::v1_6::types::ConfigurationStatus::NotSupported => {
+                            ConfigurationStatus::NotSupported
+                        }
+                    }
+                    .into(),
+                }))
+            }
+            None => Err(Status::not_found(
+                "A charger with this id is not connected to this instance",
+            )),
+        }
     }
 
     async fn clear_charger_cache(
         &self,
-        _request: Request<ClearChargerCacheRequest>,
+        request: Request<ClearChargerCacheRequest>,
     ) -> Result<Response<ClearChargerCacheResponse>, Status> {
-        todo!()
+        let payload = request.into_inner();
+
+        match self.charger_pool.get(&payload.charger_id).await {
+            Some(charger) => {
+                let mut lock = charger.lock().await;
+                lock.clear_cache().await?;
+
+                Ok(Response::new(ClearChargerCacheResponse {}))
+            }
+            None => Err(Status::not_found(
+                "A charger with this id is not connected to this instance",
+            )),
+        }
     }
 
     async fn stop_transaction(
@@ -233,6 +616,420 @@ impl Ocpp for OcppService {
             )),
         }
     }
+
+    async fn set_charging_profile(
+        &self,
+        request: Request<SetChargingProfileRequest>,
+    ) -> Result<Response<SetChargingProfileResponse>, Status> {
+        let payload = request.into_inner();
+        let evse_id = parse_optional_evse_id(payload.evse_id.as_deref())?;
+        let charging_profile = payload
+            .charging_profile
+            .ok_or_else(|| Status::invalid_argument("Missing charging_profile"))?
+            .try_into()?;
+
+        match self.charger_pool.get(&payload.charger_id).await {
+            Some(charger) => {
+                let mut lock = charger.lock().await;
+                lock.set_charging_profile(evse_id, charging_profile).await?;
+
+                Ok(Response::new(SetChargingProfileResponse {}))
+            }
+            None => Err(Status::not_found(
+                "A charger with this id is not connected to this instance",
+            )),
+        }
+    }
+
+    async fn clear_charging_profile(
+        &self,
+        request: Request<ClearChargingProfileRequest>,
+    ) -> Result<Response<ClearChargingProfileResponse>, Status> {
+        let payload = request.into_inner();
+        let evse_id = parse_optional_evse_id(payload.evse_id.as_deref())?;
+        let purpose = match ChargingProfilePurpose::try_from(payload.purpose)
+            .map_err(|_| Status::invalid_argument("Invalid purpose"))?
+        {
+            ChargingProfilePurpose::Unspecified => None,
+            purpose => Some(purpose.try_into()?),
+        };
+
+        match self.charger_pool.get(&payload.charger_id).await {
+            Some(charger) => {
+                let mut lock = charger.lock().await;
+                lock.clear_charging_profile(
+                    payload.charging_profile_id,
+                    evse_id,
+                    purpose,
+                    payload.stack_level,
+                )
+                .await?;
+
+                Ok(Response::new(ClearChargingProfileResponse {}))
+            }
+            None => Err(Status::not_found(
+                "A charger with this id is not connected to this instance",
+            )),
+        }
+    }
+
+    async fn get_composite_schedule(
+        &self,
+        request: Request<GetCompositeScheduleRequest>,
+    ) -> Result<Response<GetCompositeScheduleResponse>, Status> {
+        let payload = request.into_inner();
+        let evse_id = parse_optional_evse_id(payload.evse_id.as_deref())?;
+        let charging_rate_unit = match ChargingRateUnit::try_from(payload.charging_rate_unit)
+            .map_err(|_| Status::invalid_argument("Invalid charging_rate_unit"))?
+        {
+            ChargingRateUnit::Unspecified => None,
+            unit => Some(unit.try_into()?),
+        };
+
+        match self.charger_pool.get(&payload.charger_id).await {
+            Some(charger) => {
+                let mut lock = charger.lock().await;
+                let schedule = lock
+                    .get_composite_schedule(evse_id, payload.duration, charging_rate_unit)
+                    .await?;
+
+                Ok(Response::new(GetCompositeScheduleResponse {
+                    schedule: schedule.map(|schedule| schedule.into()),
+                }))
+            }
+            None => Err(Status::not_found(
+                "A charger with this id is not connected to this instance",
+            )),
+        }
+    }
+
+    async fn set_evse_limit(
+        &self,
+        request: Request<SetEvseLimitRequest>,
+    ) -> Result<Response<SetEvseLimitResponse>, Status> {
+        let payload = request.into_inner();
+        let evse_id = Uuid::parse_str(&payload.evse_id)
+            .map_err(|_| Status::invalid_argument("Invalid evse_id"))?;
+        let limit = match payload.limit {
+            Some(set_evse_limit_request::Limit::Kilowatts(kilowatts)) if kilowatts >= 0.0 => {
+                Some(EvseLimit::Kilowatts(kilowatts))
+            }
+            Some(set_evse_limit_request::Limit::Amperes(amperes)) if amperes >= 0.0 => {
+                Some(EvseLimit::Amperes(amperes))
+            }
+            Some(_) => return Err(Status::invalid_argument("The limit can not be negative")),
+            None => None,
+        };
+
+        match self.charger_pool.get(&payload.charger_id).await {
+            Some(charger) => {
+                let mut lock = charger.lock().await;
+                lock.set_evse_limit(evse_id, limit).await?;
+
+                Ok(Response::new(SetEvseLimitResponse {}))
+            }
+            None => Err(Status::not_found(
+                "A charger with this id is not connected to this instance",
+            )),
+        }
+    }
+
+    async fn send_local_list(
+        &self,
+        request: Request<SendLocalListRequest>,
+    ) -> Result<Response<SendLocalListResponse>, Status> {
+        let payload = request.into_inner();
+        let update_type = match LocalListUpdateType::try_from(payload.update_type)
+            .map_err(|_| Status::invalid_argument("Invalid update type"))?
+        {
+            LocalListUpdateType::Unspecified => {
+                return Err(Status::invalid_argument("Missing update type"));
+            }
+            LocalListUpdateType::Full => shared::data::LocalListUpdateType::Full,
+            LocalListUpdateType::Differential => shared::data::LocalListUpdateType::Differential,
+        };
+        let entries = payload
+            .entries
+            .into_iter()
+            .map(shared::data::LocalAuthListEntry::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match self.charger_pool.get(&payload.charger_id).await {
+            Some(charger) => {
+                let mut lock = charger.lock().await;
+                let list_version = lock.send_local_list(update_type, entries).await?;
+
+                Ok(Response::new(SendLocalListResponse { list_version }))
+            }
+            None => Err(Status::not_found(
+                "A charger with this id is not connected to this instance",
+            )),
+        }
+    }
+
+    async fn get_local_list_version(
+        &self,
+        request: Request<GetLocalListVersionRequest>,
+    ) -> Result<Response<GetLocalListVersionResponse>, Status> {
+        let payload = request.into_inner();
+
+        match self.charger_pool.get(&payload.charger_id).await {
+            Some(charger) => {
+                let mut lock = charger.lock().await;
+                let list_version = lock.get_local_list_version().await?;
+
+                Ok(Response::new(GetLocalListVersionResponse { list_version }))
+            }
+            None => Err(Status::not_found(
+                "A charger with this id is not connected to this instance",
+            )),
+        }
+    }
+
+    async fn update_firmware(
+        &self,
+        request: Request<UpdateFirmwareRequest>,
+    ) -> Result<Response<UpdateFirmwareResponse>, Status> {
+        let payload = request.into_inner();
+        let firmware_image_id = payload
+            .firmware_image_id
+            .as_deref()
+            .map(Uuid::parse_str)
+            .transpose()
+            .map_err(|_| Status::invalid_argument("Invalid firmware_image_id"))?;
+        if payload.location.is_empty() && firmware_image_id.is_none() {
+            return Err(Status::invalid_argument("Missing firmware location"));
+        }
+        if !payload.location.is_empty() && firmware_image_id.is_some() {
+            return Err(Status::invalid_argument(
+                "Either a firmware location or a firmware_image_id can be given, not both",
+            ));
+        }
+        let options = FirmwareUpdateOptions {
+            retrieve_date: payload
+                .retrieve_date
+                .map(timestamp_from_millis)
+                .transpose()?,
+            install_date: payload
+                .install_date
+                .map(timestamp_from_millis)
+                .transpose()?,
+            retries: payload.retries,
+            retry_interval: payload.retry_interval,
+            signing_certificate: payload.signing_certificate,
+            signature: payload.signature,
+        };
+
+        match self.charger_pool.get(&payload.charger_id).await {
+            Some(charger) => {
+                let mut lock = charger.lock().await;
+                let firmware_update = match firmware_image_id {
+                    Some(image_id) => {
+                        lock.update_firmware_from_image(image_id, payload.target_version, options)
+                            .await?
+                    }
+                    None => {
+                        lock.update_firmware(&payload.location, payload.target_version, options)
+                            .await?
+                    }
+                };
+
+                Ok(Response::new(UpdateFirmwareResponse {
+                    firmware_update: Some(firmware_update.into()),
+                }))
+            }
+            None => Err(Status::not_found(
+                "A charger with this id is not connected to this instance",
+            )),
+        }
+    }
+
+    async fn update_charger_settings(
+        &self,
+        request: Request<UpdateChargerSettingsRequest>,
+    ) -> Result<Response<UpdateChargerSettingsResponse>, Status> {
+        let payload = request.into_inner();
+
+        match self.charger_pool.get(&payload.charger_id).await {
+            Some(charger) => {
+                let mut lock = charger.lock().await;
+                let settings = lock
+                    .update_settings(
+                        payload.authorize_transactions,
+                        payload.permanently_lock_cable_to_charger,
+                        payload.require_client_certificate,
+                        payload
+                            .set_ocpp1_6_configuration_overrides
+                            .into_iter()
+                            .collect(),
+                        payload.remove_ocpp1_6_configuration_overrides,
+                    )
+                    .await?;
+
+                Ok(Response::new(UpdateChargerSettingsResponse {
+                    settings: Some(settings.into()),
+                }))
+            }
+            None => Err(Status::not_found(
+                "A charger with this id is not connected to this instance",
+            )),
+        }
+    }
+}
+
+fn parse_optional_evse_id(evse_id: Option<&str>) -> Result<Option<Uuid>, Status> {
+    evse_id
+        .map(Uuid::parse_str)
+        .transpose()
+        .map_err(|_| Status::invalid_argument("Invalid evse_id"))
+}
+
+fn timestamp_from_millis(value: i64) -> Result<DateTime<Utc>, Status> {
+    DateTime::from_timestamp_millis(value)
+        .ok_or_else(|| Status::invalid_argument("Invalid timestamp"))
+}
+
+impl TryFrom<CertificateUse> for crate::ocpp1_6_security::types::CertificateUse {
+    type Error = Status;
+
+    fn try_from(value: CertificateUse) -> Result<Self, Self::Error> {
+        match value {
+            CertificateUse::Unspecified => {
+                Err(Status::invalid_argument("Missing certificate type"))
+            }
+            CertificateUse::CentralSystemRootCertificate => Ok(Self::CentralSystemRootCertificate),
+            CertificateUse::ManufacturerRootCertificate => Ok(Self::ManufacturerRootCertificate),
+        }
+    }
+}
+
+impl TryFrom<CertificateHashData> for crate::ocpp1_6_security::types::CertificateHashData {
+    type Error = Status;
+
+    fn try_from(value: CertificateHashData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            hash_algorithm: match HashAlgorithm::try_from(value.hash_algorithm)
+                .map_err(|_| Status::invalid_argument("Invalid hash algorithm"))?
+            {
+                HashAlgorithm::Unspecified => {
+                    return Err(Status::invalid_argument("Missing hash algorithm"));
+                }
+                HashAlgorithm::Sha256 => crate::ocpp1_6_security::types::HashAlgorithm::Sha256,
+                HashAlgorithm::Sha384 => crate::ocpp1_6_security::types::HashAlgorithm::Sha384,
+                HashAlgorithm::Sha512 => crate::ocpp1_6_security::types::HashAlgorithm::Sha512,
+            },
+            issuer_name_hash: value.issuer_name_hash,
+            issuer_key_hash: value.issuer_key_hash,
+            serial_number: value.serial_number,
+        })
+    }
+}
+
+impl From<crate::ocpp1_6_security::types::CertificateHashData> for CertificateHashData {
+    fn from(value: crate::ocpp1_6_security::types::CertificateHashData) -> Self {
+        Self {
+            hash_algorithm: match value.hash_algorithm {
+                crate::ocpp1_6_security::types::HashAlgorithm::Sha256 => HashAlgorithm::Sha256,
+                crate::ocpp1_6_security::types::HashAlgorithm::Sha384 => HashAlgorithm::Sha384,
+                crate::ocpp1_6_security::types::HashAlgorithm::Sha512 => HashAlgorithm::Sha512,
+            }
+            .into(),
+            issuer_name_hash: value.issuer_name_hash,
+            issuer_key_hash: value.issuer_key_hash,
+            serial_number: value.serial_number,
+        }
+    }
+}
+
+impl From<crate::ocpp1_6_security::types::CertificateSignedStatus> for CertificateSignedStatus {
+    fn from(value: crate::ocpp1_6_security::types::CertificateSignedStatus) -> Self {
+        match value {
+            crate::ocpp1_6_security::types::CertificateSignedStatus::Accepted => Self::Accepted,
+            crate::ocpp1_6_security::types::CertificateSignedStatus::Rejected => Self::Rejected,
+        }
+    }
+}
+
+impl From<crate::ocpp1_6_security::types::CertificateStatus> for InstallCertificateStatus {
+    fn from(value: crate::ocpp1_6_security::types::CertificateStatus) -> Self {
+        match value {
+            crate::ocpp1_6_security::types::CertificateStatus::Accepted => Self::Accepted,
+            crate::ocpp1_6_security::types::CertificateStatus::Failed => Self::Failed,
+            crate::ocpp1_6_security::types::CertificateStatus::Rejected => Self::Rejected,
+        }
+    }
+}
+
+impl From<crate::ocpp1_6_security::types::GetInstalledCertificateStatus>
+    for GetInstalledCertificateIdsStatus
+{
+    fn from(value: crate::ocpp1_6_security::types::GetInstalledCertificateStatus) -> Self {
+        match value {
+            crate::ocpp1_6_security::types::GetInstalledCertificateStatus::Accepted => {
+                Self::Accepted
+            }
+            crate::ocpp1_6_security::types::GetInstalledCertificateStatus::NotFound => {
+                Self::NotFound
+            }
+        }
+    }
+}
+
+impl From<crate::ocpp1_6_security::types::DeleteCertificateStatus> for DeleteCertificateStatus {
+    fn from(value: crate::ocpp1_6_security::types::DeleteCertificateStatus) -> Self {
+        match value {
+            crate::ocpp1_6_security::types::DeleteCertificateStatus::Accepted => Self::Accepted,
+            crate::ocpp1_6_security::types::DeleteCertificateStatus::Failed => Self::Failed,
+            crate::ocpp1_6_security::types::DeleteCertificateStatus::NotFound => Self::NotFound,
+        }
+    }
+}
+
+impl TryFrom<ExtendedTriggerableMessage>
+    for crate::ocpp1_6_security::types::ExtendedMessageTrigger
+{
+    type Error = Status;
+
+    fn try_from(value: ExtendedTriggerableMessage) -> Result<Self, Self::Error> {
+        match value {
+            ExtendedTriggerableMessage::Unspecified => {
+                Err(Status::invalid_argument("Missing message"))
+            }
+            ExtendedTriggerableMessage::BootNotification => Ok(Self::BootNotification),
+            ExtendedTriggerableMessage::LogStatusNotification => Ok(Self::LogStatusNotification),
+            ExtendedTriggerableMessage::FirmwareStatusNotification => {
+                Ok(Self::FirmwareStatusNotification)
+            }
+            ExtendedTriggerableMessage::Heartbeat => Ok(Self::Heartbeat),
+            ExtendedTriggerableMessage::MeterValues => Ok(Self::MeterValues),
+            ExtendedTriggerableMessage::SignChargePointCertificate => {
+                Ok(Self::SignChargePointCertificate)
+            }
+            ExtendedTriggerableMessage::StatusNotification => Ok(Self::StatusNotification),
+        }
+    }
+}
+
+impl TryFrom<LogType> for crate::ocpp1_6_security::types::LogType {
+    type Error = Status;
+
+    fn try_from(value: LogType) -> Result<Self, Self::Error> {
+        match value {
+            LogType::Unspecified => Err(Status::invalid_argument("Missing log type")),
+            LogType::DiagnosticsLog => Ok(Self::DiagnosticsLog),
+            LogType::SecurityLog => Ok(Self::SecurityLog),
+        }
+    }
+}
+
+impl From<crate::ocpp1_6_security::types::LogStatus> for LogStatus {
+    fn from(value: crate::ocpp1_6_security::types::LogStatus) -> Self {
+        match value {
+            crate::ocpp1_6_security::types::LogStatus::Accepted => Self::Accepted,
+            crate::ocpp1_6_security::types::LogStatus::Rejected => Self::Rejected,
+            crate::ocpp1_6_security::types::LogStatus::AcceptedCanceled => Self::AcceptedCanceled,
+        }
+    }
 }
 
 impl From<shared::data::Transaction> for crate::ocpp_csms_server::Transaction {
@@ -268,3 +1065,245 @@ impl From<shared::data::RfidScanSession> for crate::ocpp_csms_server::RfidScanSe
         }
     }
 }
+
+impl TryFrom<ChargingProfilePurpose> for shared::data::ChargingProfilePurpose {
+    type Error = Status;
+
+    fn try_from(value: ChargingProfilePurpose) -> Result<Self, Self::Error> {
+        match value {
+            ChargingProfilePurpose::Unspecified => {
+                Err(Status::invalid_argument("Missing charging profile purpose"))
+            }
+            ChargingProfilePurpose::ChargePointMaxProfile => Ok(Self::ChargePointMaxProfile),
+            ChargingProfilePurpose::TxDefaultProfile => Ok(Self::TxDefaultProfile),
+            ChargingProfilePurpose::TxProfile => Ok(Self::TxProfile),
+        }
+    }
+}
+
+impl TryFrom<ChargingRateUnit> for shared::data::ChargingRateUnit {
+    type Error = Status;
+
+    fn try_from(value: ChargingRateUnit) -> Result<Self, Self::Error> {
+        match value {
+            ChargingRateUnit::Unspecified => {
+                Err(Status::invalid_argument("Missing charging rate unit"))
+            }
+            ChargingRateUnit::Ampere => Ok(Self::Ampere),
+            ChargingRateUnit::Watt => Ok(Self::Watt),
+        }
+    }
+}
+
+impl From<shared::data::ChargingRateUnit> for ChargingRateUnit {
+    fn from(value: shared::data::ChargingRateUnit) -> Self {
+        match value {
+            shared::data::ChargingRateUnit::Ampere => Self::Ampere,
+            shared::data::ChargingRateUnit::Watt => Self::Watt,
+        }
+    }
+}
+
+impl TryFrom<crate::ocpp_csms_server::ChargingSchedule> for shared::data::ChargingSchedule {
+    type Error = Status;
+
+    fn try_from(value: crate::ocpp_csms_server::ChargingSchedule) -> Result<Self, Self::Error> {
+        Ok(Self {
+            duration: value.duration,
+            start_schedule: value
+                .start_schedule
+                .map(timestamp_from_millis)
+                .transpose()?,
+            charging_rate_unit: ChargingRateUnit::try_from(value.charging_rate_unit)
+                .map_err(|_| Status::invalid_argument("Invalid charging rate unit"))?
+                .try_into()?,
+            periods: value
+                .periods
+                .into_iter()
+                .map(|period| shared::data::ChargingSchedulePeriod {
+                    start_period: period.start_period,
+                    limit: period.limit,
+                    number_phases: period.number_phases,
+                })
+                .collect(),
+            min_charging_rate: value.min_charging_rate,
+        })
+    }
+}
+
+impl From<shared::data::ChargingSchedule> for crate::ocpp_csms_server::ChargingSchedule {
+    fn from(value: shared::data::ChargingSchedule) -> Self {
+        Self {
+            duration: value.duration,
+            start_schedule: value.start_schedule.map(|i| i.timestamp_millis()),
+            charging_rate_unit: ChargingRateUnit::from(value.charging_rate_unit).into(),
+            periods: value
+                .periods
+                .into_iter()
+                .map(|period| crate::ocpp_csms_server::ChargingSchedulePeriod {
+                    start_period: period.start_period,
+                    limit: period.limit,
+                    number_phases: period.number_phases,
+                })
+                .collect(),
+            min_charging_rate: value.min_charging_rate,
+        }
+    }
+}
+
+impl TryFrom<crate::ocpp_csms_server::ChargingProfile> for shared::data::ChargingProfile {
+    type Error = Status;
+
+    fn try_from(value: crate::ocpp_csms_server::ChargingProfile) -> Result<Self, Self::Error> {
+        let kind = match ChargingProfileKind::try_from(value.kind)
+            .map_err(|_| Status::invalid_argument("Invalid charging profile kind"))?
+        {
+            ChargingProfileKind::Unspecified => {
+                return Err(Status::invalid_argument("Missing charging profile kind"));
+            }
+            ChargingProfileKind::Absolute => shared::data::ChargingProfileKind::Absolute,
+            ChargingProfileKind::Recurring => shared::data::ChargingProfileKind::Recurring,
+            ChargingProfileKind::Relative => shared::data::ChargingProfileKind::Relative,
+        };
+        let recurrency_kind = match RecurrencyKind::try_from(value.recurrency_kind)
+            .map_err(|_| Status::invalid_argument("Invalid recurrency kind"))?
+        {
+            RecurrencyKind::Unspecified => None,
+            RecurrencyKind::Daily => Some(shared::data::RecurrencyKind::Daily),
+            RecurrencyKind::Weekly => Some(shared::data::RecurrencyKind::Weekly),
+        };
+        if kind == shared::data::ChargingProfileKind::Recurring && recurrency_kind.is_none() {
+            return Err(Status::invalid_argument(
+                "A recurring charging profile requires a recurrency kind",
+            ));
+        }
+
+        Ok(Self {
+            id: value.id,
+            transaction_id: value.transaction_id,
+            stack_level: value.stack_level,
+            purpose: ChargingProfilePurpose::try_from(value.purpose)
+                .map_err(|_| Status::invalid_argument("Invalid charging profile purpose"))?
+                .try_into()?,
+            kind,
+            recurrency_kind,
+            valid_from: value.valid_from.map(timestamp_from_millis).transpose()?,
+            valid_to: value.valid_to.map(timestamp_from_millis).transpose()?,
+            schedule: value
+                .schedule
+                .ok_or_else(|| Status::invalid_argument("Missing charging schedule"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl TryFrom<crate::ocpp_csms_server::LocalAuthListEntry> for shared::data::LocalAuthListEntry {
+    type Error = Status;
+
+    fn try_from(value: crate::ocpp_csms_server::LocalAuthListEntry) -> Result<Self, Self::Error> {
+        // Id tags are limited to 20 characters in ocpp 1.6
+        if value.id_tag.is_empty() || value.id_tag.len() > 20 {
+            return Err(Status::invalid_argument(
+                "Id tags must be between 1 and 20 characters",
+            ));
+        }
+        if value
+            .parent_id_tag
+            .as_ref()
+            .is_some_and(|parent_id_tag| parent_id_tag.len() > 20)
+        {
+            return Err(Status::invalid_argument(
+                "Parent id tags can not be longer than 20 characters",
+            ));
+        }
+
+        let status = match LocalAuthorizationStatus::try_from(value.status)
+            .map_err(|_| Status::invalid_argument("Invalid authorization status"))?
+        {
+            LocalAuthorizationStatus::Unspecified => None,
+            LocalAuthorizationStatus::Accepted => {
+                Some(shared::data::LocalAuthorizationStatus::Accepted)
+            }
+            LocalAuthorizationStatus::Blocked => {
+                Some(shared::data::LocalAuthorizationStatus::Blocked)
+            }
+            LocalAuthorizationStatus::Expired => {
+                Some(shared::data::LocalAuthorizationStatus::Expired)
+            }
+            LocalAuthorizationStatus::Invalid => {
+                Some(shared::data::LocalAuthorizationStatus::Invalid)
+            }
+        };
+
+        Ok(Self {
+            id_tag: value.id_tag,
+            status,
+            expiry_date: value.expiry_date.map(timestamp_from_millis).transpose()?,
+            parent_id_tag: value.parent_id_tag,
+        })
+    }
+}
+
+impl From<shared::data::FirmwareUpdateStatus> for crate::ocpp_csms_server::FirmwareUpdateStatus {
+    fn from(value: shared::data::FirmwareUpdateStatus) -> Self {
+        match value {
+            shared::data::FirmwareUpdateStatus::Requested => Self::Requested,
+            shared::data::FirmwareUpdateStatus::DownloadScheduled => Self::DownloadScheduled,
+            shared::data::FirmwareUpdateStatus::Downloading => Self::Downloading,
+            shared::data::FirmwareUpdateStatus::DownloadPaused => Self::DownloadPaused,
+            shared::data::FirmwareUpdateStatus::Downloaded => Self::Downloaded,
+            shared::data::FirmwareUpdateStatus::DownloadFailed => Self::DownloadFailed,
+            shared::data::FirmwareUpdateStatus::SignatureVerified => Self::SignatureVerified,
+            shared::data::FirmwareUpdateStatus::InvalidSignature => Self::InvalidSignature,
+            shared::data::FirmwareUpdateStatus::InstallScheduled => Self::InstallScheduled,
+            shared::data::FirmwareUpdateStatus::Installing => Self::Installing,
+            shared::data::FirmwareUpdateStatus::InstallRebooting => Self::InstallRebooting,
+            shared::data::FirmwareUpdateStatus::Installed => Self::Installed,
+            shared::data::FirmwareUpdateStatus::InstallationFailed => Self::InstallationFailed,
+            shared::data::FirmwareUpdateStatus::InstallVerificationFailed => {
+                Self::InstallVerificationFailed
+            }
+            shared::data::FirmwareUpdateStatus::Confirmed => Self::Confirmed,
+            shared::data::FirmwareUpdateStatus::Failed => Self::Failed,
+        }
+    }
+}
+
+impl From<shared::data::FirmwareUpdate> for crate::ocpp_csms_server::FirmwareUpdate {
+    fn from(value: shared::data::FirmwareUpdate) -> Self {
+        Self {
+            id: value.id.to_string(),
+            charger_id: value.charger_id,
+            location: value.location,
+            target_version: value.target_version,
+            previous_version: value.previous_version,
+            status: crate::ocpp_csms_server::FirmwareUpdateStatus::from(value.status).into(),
+            status_history: value
+                .status_history
+                .into_iter()
+                .map(
+                    |change| crate::ocpp_csms_server::FirmwareUpdateStatusChange {
+                        status: crate::ocpp_csms_server::FirmwareUpdateStatus::from(change.status)
+                            .into(),
+                        timestamp: change.timestamp.timestamp_millis(),
+                    },
+                )
+                .collect(),
+            created_at: value.created_at.timestamp_millis(),
+        }
+    }
+}
+
+impl From<shared::data::ChargerSettings> for crate::ocpp_csms_server::ChargerSettings {
+    fn from(value: shared::data::ChargerSettings) -> Self {
+        Self {
+            authorize_transactions: value.authorize_transactions,
+            permanently_lock_cable_to_charger: value.permanently_lock_cable_to_charger,
+            require_client_certificate: value.require_client_certificate,
+            ocpp1_6_configuration_overrides: value
+                .ocpp1_6_configuration_overrides
+                .into_iter()
+                .collect(),
+        }
+    }
+}
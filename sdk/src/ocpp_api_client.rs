@@ -166,6 +166,7 @@ impl OcppApiClient {
         let request = ocpp_csms_server::StartTransactionRequest {
             charger_id: charger_id.to_string(),
             evse_id: evse_id.to_string(),
+            charging_profile: None,
         };
 
         let response = client.start_transaction(request).await?;
@@ -1,6 +1,8 @@
 use crate::config::amqp_config::AmqpConfig;
 use crate::config::authorize_config::AuthorizeConfig;
 use crate::config::client_config::ClientConfig;
+use crate::config::firmware_config::FirmwareConfig;
+use crate::config::load_balancer_config::LoadBalancerConfig;
 use crate::config::ocpp_config::OcppConfig;
 use serde::{Deserialize, Serialize};
 
@@ -11,6 +13,8 @@ pub struct Config {
     pub amqp: Option<AmqpConfig>,
     pub authorize: Option<AuthorizeConfig>,
     pub client: Option<ClientConfig>,
+    pub load_balancer: Option<LoadBalancerConfig>,
+    pub firmware: Option<FirmwareConfig>,
 }
 
 impl Config {
@@ -23,6 +27,24 @@ impl Config {
     pub fn client_url(&self) -> Option<&str> {
         self.client.as_ref().and_then(|c| c.url.as_deref())
     }
+
+    pub fn load_balancer_interval_secs(&self) -> u64 {
+        self.load_balancer
+            .as_ref()
+            .and_then(|c| c.interval_secs)
+            .unwrap_or(30)
+    }
+
+    pub fn firmware_public_url(&self) -> Option<&str> {
+        self.firmware.as_ref().and_then(|c| c.public_url.as_deref())
+    }
+
+    pub fn firmware_download_token_ttl_secs(&self) -> u64 {
+        self.firmware
+            .as_ref()
+            .and_then(|c| c.download_token_ttl_secs)
+            .unwrap_or(24 * 60 * 60)
+    }
 }
 
 impl Default for Config {
@@ -32,6 +54,8 @@ impl Default for Config {
             amqp: None,
             authorize: None,
             client: None,
+            load_balancer: None,
+            firmware: None,
         }
     }
 }
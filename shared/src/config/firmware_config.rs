@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FirmwareConfig {
+    /// The url chargers reach the OCPP interface at, used to build the download urls of hosted
+    /// firmware images. For example `http://csms.example.com:50051`
+    pub public_url: Option<String>,
+    /// How long a charger can download a hosted firmware image after its retrieve date
+    pub download_token_ttl_secs: Option<u64>,
+}
+
+impl Default for FirmwareConfig {
+    fn default() -> Self {
+        Self {
+            public_url: None,
+            download_token_ttl_secs: Some(24 * 60 * 60),
+        }
+    }
+}
@@ -2,10 +2,18 @@ mod amqp_config;
 mod authorize_config;
 mod client_config;
 mod config;
+mod firmware_config;
+mod load_balancer_config;
 mod ocpp_config;
+mod ocpp_tls_config;
 mod read_config;
+mod reconnect_policy;
 
 pub use self::amqp_config::AmqpConfig;
 pub use self::config::Config;
+pub use self::firmware_config::FirmwareConfig;
+pub use self::load_balancer_config::LoadBalancerConfig;
 pub use self::ocpp_config::OcppConfig;
+pub use self::ocpp_tls_config::OcppTlsConfig;
 pub use self::read_config::read_config;
+pub use self::reconnect_policy::ReconnectPolicy;
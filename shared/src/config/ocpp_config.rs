@@ -1,4 +1,6 @@
+use crate::config::{OcppTlsConfig, ReconnectPolicy};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use tracing::warn;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -6,6 +8,18 @@ pub struct OcppConfig {
     /// This will disable any charger validation. Should only be used for testing
     pub disable_charger_auth: Option<bool>,
     pub message_timeout_secs: Option<u64>,
+    /// How often a websocket ping is sent to every connected charger
+    pub ping_interval_secs: Option<u64>,
+    /// The number of ping intervals a charger may stay silent, without a pong, heartbeat or any
+    /// other frame, before its connection is closed
+    pub max_missed_pongs: Option<u32>,
+    /// Whether a charger that connects again replaces its previous connection or is rejected
+    pub reconnect_policy: Option<ReconnectPolicy>,
+    /// OCPP 1.6 configuration values pushed to every charger, replacing the built in defaults.
+    /// Chargers can override them again in their settings
+    pub ocpp1_6_configuration_defaults: Option<BTreeMap<String, String>>,
+    /// Serves `wss://` when set, otherwise only plain `ws://` is served
+    pub tls: Option<OcppTlsConfig>,
 }
 
 impl OcppConfig {
@@ -17,6 +31,13 @@ impl OcppConfig {
                 )
             }
         }
+        if let Some(tls) = &self.tls
+            && tls.plain_listener_enabled()
+        {
+            warn!(
+                "'ocpp.tls.plain_listener_enabled' is enabled, chargers can still connect without TLS"
+            )
+        }
     }
 }
 
@@ -25,6 +46,11 @@ impl Default for OcppConfig {
         Self {
             disable_charger_auth: Some(false),
             message_timeout_secs: Some(30),
+            ping_interval_secs: Some(30),
+            max_missed_pongs: Some(3),
+            reconnect_policy: Some(ReconnectPolicy::Replace),
+            ocpp1_6_configuration_defaults: None,
+            tls: None,
         }
     }
 }
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+/// Serves the OCPP websocket endpoint over TLS (`wss://`), as required by OCPP security
+/// profile 2 and 3
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OcppTlsConfig {
+    /// Path to the PEM encoded certificate chain, the server certificate first
+    pub cert_path: String,
+    /// Path to the PEM encoded private key of the server certificate
+    pub key_path: String,
+    /// Path to the PEM encoded bundle of CAs that client certificates are verified against.
+    /// Enables security profile 3, where chargers authenticate with a certificate instead of a
+    /// password
+    pub client_ca_path: Option<String>,
+    /// The port TLS connections are accepted on, defaults to the regular OCPP port
+    pub port: Option<u16>,
+    /// Keeps accepting plain `ws://` connections on the regular OCPP port, so chargers can be
+    /// migrated one by one. Requires a separate TLS port
+    pub plain_listener_enabled: Option<bool>,
+    /// How often the certificate, key and CA files are checked for changes. Changed files are
+    /// loaded for new connections, existing connections are left alone
+    pub reload_interval_secs: Option<u64>,
+}
+
+impl OcppTlsConfig {
+    pub fn reload_interval_secs(&self) -> u64 {
+        self.reload_interval_secs.unwrap_or(60).max(1)
+    }
+
+    pub fn plain_listener_enabled(&self) -> bool {
+        self.plain_listener_enabled.unwrap_or(false)
+    }
+}
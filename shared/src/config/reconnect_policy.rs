@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// What happens when a charger connects while its previous connection is still open
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReconnectPolicy {
+    /// Closes the previous connection and continues on the new one. The previous connection is
+    /// usually dead already, but not yet detected as such
+    #[default]
+    Replace,
+    /// Refuses the new connection until the previous one is closed
+    Reject,
+}
@@ -1,7 +1,7 @@
 use crate::Config;
 use crate::data::charger_settings::ChargerSettings;
 use crate::data::evse_data::EvseData;
-use crate::data::{ConnectorData, Ocpp1_6Configuration};
+use crate::data::{ClientCertificate, ConnectorData, Ocpp1_6Configuration};
 use sqlx::FromRow;
 use uuid::Uuid;
 
@@ -19,6 +19,10 @@ pub struct ChargerData {
     pub settings: ChargerSettings,
 
     pub ocpp1_6configuration: Option<Ocpp1_6Configuration>,
+    /// The version of the OCPP 1.6 local authorization list last sent to or read from the charger
+    pub local_auth_list_version: Option<i32>,
+    /// The certificate the charger last authenticated with over mutual TLS
+    pub client_certificate: Option<ClientCertificate>,
 }
 
 impl ChargerData {
@@ -36,6 +40,8 @@ impl ChargerData {
             ],
             settings: ChargerSettings::new(config),
             ocpp1_6configuration: None,
+            local_auth_list_version: None,
+            client_certificate: None,
         }
     }
 
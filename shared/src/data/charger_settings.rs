@@ -18,6 +18,13 @@ pub struct ChargerSettings {
     pub authorize_transactions: bool,
     #[serde(default)]
     pub permanently_lock_cable_to_charger: bool,
+    /// Only accepts the charger when it authenticates with a client certificate (security
+    /// profile 3), a password is rejected
+    #[serde(default)]
+    pub require_client_certificate: bool,
+    /// OCPP 1.6 configuration values of this charger that replace the defaults of the fleet
+    #[serde(default)]
+    pub ocpp1_6_configuration_overrides: BTreeMap<String, String>,
 }
 
 impl ChargerSettings {
@@ -30,9 +37,13 @@ impl ChargerSettings {
                 .default_authorize_transactions
                 .unwrap_or_default(),
             permanently_lock_cable_to_charger: false,
+            require_client_certificate: false,
+            ocpp1_6_configuration_overrides: BTreeMap::new(),
         }
     }
 
+    /// The configuration pushed to the charger before its own overrides are applied, the built
+    /// in defaults replaced by the defaults of the fleet
     pub fn get_ocpp_1_6_configuration_entries(&self, config: &Config) -> BTreeMap<String, String> {
         let mut entries = BTreeMap::new();
         entries.insert(
@@ -96,6 +107,14 @@ impl ChargerSettings {
                 .to_string(),
         );
 
+        if let Some(defaults) = config
+            .ocpp
+            .as_ref()
+            .and_then(|ocpp| ocpp.ocpp1_6_configuration_defaults.as_ref())
+        {
+            entries.extend(defaults.clone());
+        }
+
         entries
     }
 }
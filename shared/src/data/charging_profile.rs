@@ -0,0 +1,17 @@
+use crate::data::{ChargingProfileKind, ChargingProfilePurpose, ChargingSchedule, RecurrencyKind};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ChargingProfile {
+    pub id: i32,
+    /// The ocpp transaction id, only used for TxProfile
+    pub transaction_id: Option<String>,
+    pub stack_level: i32,
+    pub purpose: ChargingProfilePurpose,
+    pub kind: ChargingProfileKind,
+    pub recurrency_kind: Option<RecurrencyKind>,
+    pub valid_from: Option<DateTime<Utc>>,
+    pub valid_to: Option<DateTime<Utc>>,
+    pub schedule: ChargingSchedule,
+}
@@ -0,0 +1,30 @@
+use rust_ocpp::v1_6::types::ChargingProfileKindType;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub enum ChargingProfileKind {
+    #[default]
+    Absolute,
+    Recurring,
+    Relative,
+}
+
+impl From<ChargingProfileKindType> for ChargingProfileKind {
+    fn from(value: ChargingProfileKindType) -> Self {
+        match value {
+            ChargingProfileKindType::Absolute => Self::Absolute,
+            ChargingProfileKindType::Recurring => Self::Recurring,
+            ChargingProfileKindType::Relative => Self::Relative,
+        }
+    }
+}
+
+impl From<ChargingProfileKind> for ChargingProfileKindType {
+    fn from(value: ChargingProfileKind) -> Self {
+        match value {
+            ChargingProfileKind::Absolute => Self::Absolute,
+            ChargingProfileKind::Recurring => Self::Recurring,
+            ChargingProfileKind::Relative => Self::Relative,
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use rust_ocpp::v1_6::types::ChargingProfilePurposeType;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub enum ChargingProfilePurpose {
+    ChargePointMaxProfile,
+    #[default]
+    TxDefaultProfile,
+    TxProfile,
+}
+
+impl From<ChargingProfilePurposeType> for ChargingProfilePurpose {
+    fn from(value: ChargingProfilePurposeType) -> Self {
+        match value {
+            ChargingProfilePurposeType::ChargePointMaxProfile => Self::ChargePointMaxProfile,
+            ChargingProfilePurposeType::TxDefaultProfile => Self::TxDefaultProfile,
+            ChargingProfilePurposeType::TxProfile => Self::TxProfile,
+        }
+    }
+}
+
+impl From<ChargingProfilePurpose> for ChargingProfilePurposeType {
+    fn from(value: ChargingProfilePurpose) -> Self {
+        match value {
+            ChargingProfilePurpose::ChargePointMaxProfile => Self::ChargePointMaxProfile,
+            ChargingProfilePurpose::TxDefaultProfile => Self::TxDefaultProfile,
+            ChargingProfilePurpose::TxProfile => Self::TxProfile,
+        }
+    }
+}
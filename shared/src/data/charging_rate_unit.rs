@@ -0,0 +1,27 @@
+use rust_ocpp::v1_6::types::ChargingRateUnitType;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub enum ChargingRateUnit {
+    #[default]
+    Ampere,
+    Watt,
+}
+
+impl From<ChargingRateUnitType> for ChargingRateUnit {
+    fn from(value: ChargingRateUnitType) -> Self {
+        match value {
+            ChargingRateUnitType::A => Self::Ampere,
+            ChargingRateUnitType::W => Self::Watt,
+        }
+    }
+}
+
+impl From<ChargingRateUnit> for ChargingRateUnitType {
+    fn from(value: ChargingRateUnit) -> Self {
+        match value {
+            ChargingRateUnit::Ampere => Self::A,
+            ChargingRateUnit::Watt => Self::W,
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use crate::data::{ChargingRateUnit, ChargingSchedulePeriod};
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ChargingSchedule {
+    /// Duration of the schedule in seconds, if omitted the last period lasts indefinitely
+    pub duration: Option<i32>,
+    pub start_schedule: Option<DateTime<Utc>>,
+    pub charging_rate_unit: ChargingRateUnit,
+    pub periods: Vec<ChargingSchedulePeriod>,
+    pub min_charging_rate: Option<f32>,
+}
+
+impl From<rust_ocpp::v1_6::types::ChargingSchedule> for ChargingSchedule {
+    fn from(value: rust_ocpp::v1_6::types::ChargingSchedule) -> Self {
+        Self {
+            duration: value.duration,
+            start_schedule: value.start_schedule,
+            charging_rate_unit: value.charging_rate_unit.into(),
+            periods: value
+                .charging_schedule_period
+                .into_iter()
+                .map(ChargingSchedulePeriod::from)
+                .collect(),
+            min_charging_rate: value.min_charging_rate.and_then(|rate| rate.to_f32()),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ChargingSchedulePeriod {
+    /// Start of the period in seconds, relative to the start of the schedule
+    pub start_period: i32,
+    /// The limit, expressed in the charging rate unit of the schedule
+    pub limit: f32,
+    pub number_phases: Option<i32>,
+}
+
+impl From<rust_ocpp::v1_6::types::ChargingSchedulePeriod> for ChargingSchedulePeriod {
+    fn from(value: rust_ocpp::v1_6::types::ChargingSchedulePeriod) -> Self {
+        Self {
+            start_period: value.start_period,
+            limit: value.limit.to_f32().unwrap_or_default(),
+            number_phases: value.number_phases,
+        }
+    }
+}
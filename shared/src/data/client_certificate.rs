@@ -0,0 +1,11 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// The certificate a charger authenticated with over mutual TLS (security profile 3)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ClientCertificate {
+    pub common_name: String,
+    /// Hex encoded serial number
+    pub serial_number: String,
+    pub expires_at: DateTime<Utc>,
+}
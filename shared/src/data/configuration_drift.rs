@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ConfigurationDriftReason {
+    /// The value differs from the desired value, and has never been brought in line
+    Differs,
+    /// The value was the desired value before, so it has been changed on the charger itself
+    ChangedOnCharger,
+    /// The charger rejected the desired value
+    Rejected,
+    /// The charger accepted the desired value, but only applies it after a reboot
+    RebootRequired,
+    /// The charger doesn't support the key, even though it reports it
+    NotSupported,
+}
+
+/// A configuration key of a charger that doesn't have the desired value
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ConfigurationDrift {
+    pub key: String,
+    pub desired_value: String,
+    pub actual_value: Option<String>,
+    pub reason: ConfigurationDriftReason,
+}
@@ -0,0 +1,17 @@
+use crate::data::{ConfigurationDrift, Ocpp1_6Configuration};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// The configuration a charger was left with after the desired configuration was pushed on a
+/// boot, along with the keys that were still out of line with it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigurationSnapshot {
+    pub id: Uuid,
+    pub charger_id: String,
+    /// Counts up from 1 for every snapshot of the charger
+    pub version: i32,
+    pub taken_at: DateTime<Utc>,
+    pub configuration: Ocpp1_6Configuration,
+    pub drift: Vec<ConfigurationDrift>,
+}
@@ -0,0 +1,32 @@
+use crate::data::{ChargingProfile, ChargingProfilePurpose};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A charging profile the CSMS has set on a charger, which should be present on the charger
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DesiredChargingProfile {
+    pub charger_id: String,
+    /// The evse the profile applies to, or the whole charger if omitted
+    pub evse_id: Option<Uuid>,
+    pub profile: ChargingProfile,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl DesiredChargingProfile {
+    /// Checks if the profile would be removed by a ClearChargingProfile request with these criteria
+    pub fn matches_clear_criteria(
+        &self,
+        charging_profile_id: Option<i32>,
+        evse_id: Option<Uuid>,
+        purpose: Option<ChargingProfilePurpose>,
+        stack_level: Option<i32>,
+    ) -> bool {
+        if let Some(charging_profile_id) = charging_profile_id {
+            return self.profile.id == charging_profile_id;
+        }
+        evse_id.is_none_or(|evse_id| self.evse_id == Some(evse_id))
+            && purpose.is_none_or(|purpose| self.profile.purpose == purpose)
+            && stack_level.is_none_or(|stack_level| self.profile.stack_level == stack_level)
+    }
+}
@@ -0,0 +1,18 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A request for (part of) a hosted firmware image, chargers often download an image in several
+/// ranges
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FirmwareDownload {
+    pub id: Uuid,
+    pub image_id: Uuid,
+    pub charger_id: String,
+    /// The first byte served
+    pub range_start: u64,
+    /// The last byte served, inclusive
+    pub range_end: u64,
+    pub remote_address: Option<String>,
+    pub downloaded_at: DateTime<Utc>,
+}
@@ -0,0 +1,18 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Grants a single charger access to a hosted firmware image until it expires
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FirmwareDownloadToken {
+    pub token: String,
+    pub image_id: Uuid,
+    pub charger_id: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl FirmwareDownloadToken {
+    pub fn is_expired(&self) -> bool {
+        self.expires_at <= Utc::now()
+    }
+}
@@ -0,0 +1,27 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A firmware file hosted by the csms, so chargers can download it without an external file server
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FirmwareImage {
+    pub id: Uuid,
+    pub vendor: String,
+    pub model: String,
+    pub version: String,
+    pub file_name: String,
+    /// The size of the image in bytes
+    pub size: u64,
+    /// Hex encoded SHA-256 checksum of the image
+    pub sha256: String,
+    /// Hex encoded MD5 checksum of the image, some chargers only verify this
+    pub md5: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl FirmwareImage {
+    pub fn supports(&self, vendor: Option<&str>, model: Option<&str>) -> bool {
+        vendor.is_none_or(|vendor| self.vendor.eq_ignore_ascii_case(vendor))
+            && model.is_none_or(|model| self.model.eq_ignore_ascii_case(model))
+    }
+}
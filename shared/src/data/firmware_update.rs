@@ -0,0 +1,59 @@
+use crate::data::FirmwareUpdateStatus;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FirmwareUpdateStatusChange {
+    pub status: FirmwareUpdateStatus,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// A single attempt at rolling out new firmware to a charger
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FirmwareUpdate {
+    pub id: Uuid,
+    pub charger_id: String,
+    pub location: String,
+    /// The firmware version the charger is expected to report after the update
+    pub target_version: Option<String>,
+    /// The firmware version the charger reported before the update
+    pub previous_version: Option<String>,
+    /// Used in OCPP 2.0.1 to relate status notifications to the update
+    pub ocpp_request_id: Option<i32>,
+    pub status: FirmwareUpdateStatus,
+    pub status_history: Vec<FirmwareUpdateStatusChange>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl FirmwareUpdate {
+    pub fn new(
+        charger_id: &str,
+        location: &str,
+        target_version: Option<String>,
+        previous_version: Option<String>,
+        ocpp_request_id: Option<i32>,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            charger_id: charger_id.to_string(),
+            location: location.to_string(),
+            target_version,
+            previous_version,
+            ocpp_request_id,
+            status: FirmwareUpdateStatus::Requested,
+            status_history: vec![FirmwareUpdateStatusChange {
+                status: FirmwareUpdateStatus::Requested,
+                timestamp: now,
+            }],
+            created_at: now,
+        }
+    }
+
+    pub fn record_status(&mut self, status: FirmwareUpdateStatus, timestamp: DateTime<Utc>) {
+        self.status = status;
+        self.status_history
+            .push(FirmwareUpdateStatusChange { status, timestamp });
+    }
+}
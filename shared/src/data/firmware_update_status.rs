@@ -0,0 +1,76 @@
+use rust_ocpp::v1_6::types::FirmwareStatus;
+use rust_ocpp::v2_0_1::enumerations::firmware_status_enum_type::FirmwareStatusEnumType;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum FirmwareUpdateStatus {
+    /// The charger accepted the update request, but hasn't reported any progress yet
+    Requested,
+    DownloadScheduled,
+    Downloading,
+    DownloadPaused,
+    Downloaded,
+    DownloadFailed,
+    SignatureVerified,
+    InvalidSignature,
+    InstallScheduled,
+    Installing,
+    InstallRebooting,
+    Installed,
+    InstallationFailed,
+    InstallVerificationFailed,
+    /// The charger booted with the expected firmware version
+    Confirmed,
+    /// The charger booted with another firmware version than expected after installing
+    Failed,
+}
+
+impl FirmwareUpdateStatus {
+    /// Idle is not a step of an update, so it has no status
+    pub fn from_ocpp_1_6(status: FirmwareStatus) -> Option<Self> {
+        match status {
+            FirmwareStatus::Downloaded => Some(Self::Downloaded),
+            FirmwareStatus::DownloadFailed => Some(Self::DownloadFailed),
+            FirmwareStatus::Downloading => Some(Self::Downloading),
+            FirmwareStatus::Idle => None,
+            FirmwareStatus::InstallationFailed => Some(Self::InstallationFailed),
+            FirmwareStatus::Installing => Some(Self::Installing),
+            FirmwareStatus::Installed => Some(Self::Installed),
+        }
+    }
+
+    /// Idle is not a step of an update, so it has no status
+    pub fn from_ocpp_2_0_1(status: FirmwareStatusEnumType) -> Option<Self> {
+        match status {
+            FirmwareStatusEnumType::Downloaded => Some(Self::Downloaded),
+            FirmwareStatusEnumType::DownloadFailed => Some(Self::DownloadFailed),
+            FirmwareStatusEnumType::Downloading => Some(Self::Downloading),
+            FirmwareStatusEnumType::DownloadScheduled => Some(Self::DownloadScheduled),
+            FirmwareStatusEnumType::DownloadPaused => Some(Self::DownloadPaused),
+            FirmwareStatusEnumType::Idle => None,
+            FirmwareStatusEnumType::InstallationFailed => Some(Self::InstallationFailed),
+            FirmwareStatusEnumType::Installing => Some(Self::Installing),
+            FirmwareStatusEnumType::Installed => Some(Self::Installed),
+            FirmwareStatusEnumType::InstallRebooting => Some(Self::InstallRebooting),
+            FirmwareStatusEnumType::InstallScheduled => Some(Self::InstallScheduled),
+            FirmwareStatusEnumType::InstallVerificationFailed => {
+                Some(Self::InstallVerificationFailed)
+            }
+            FirmwareStatusEnumType::InvalidSignature => Some(Self::InvalidSignature),
+            FirmwareStatusEnumType::SignatureVerified => Some(Self::SignatureVerified),
+        }
+    }
+
+    /// No further progress is expected once an update reaches one of these
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            Self::DownloadFailed
+                | Self::InvalidSignature
+                | Self::InstallationFailed
+                | Self::InstallVerificationFailed
+                | Self::Confirmed
+                | Self::Failed
+        )
+    }
+}
@@ -0,0 +1,27 @@
+use crate::data::LocalAuthorizationStatus;
+use chrono::{DateTime, Utc};
+use rust_ocpp::v1_6::types::{AuthorizationData, IdTagInfo};
+use serde::{Deserialize, Serialize};
+
+/// An id tag stored on the charger, so it can authorize it while offline
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LocalAuthListEntry {
+    pub id_tag: String,
+    /// Omitting the status removes the id tag from the list in a differential update
+    pub status: Option<LocalAuthorizationStatus>,
+    pub expiry_date: Option<DateTime<Utc>>,
+    pub parent_id_tag: Option<String>,
+}
+
+impl From<LocalAuthListEntry> for AuthorizationData {
+    fn from(entry: LocalAuthListEntry) -> Self {
+        Self {
+            id_tag: entry.id_tag,
+            id_tag_info: entry.status.map(|status| IdTagInfo {
+                expiry_date: entry.expiry_date,
+                parent_id_tag: entry.parent_id_tag,
+                status: status.into(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use rust_ocpp::v1_6::types::AuthorizationStatus;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum LocalAuthorizationStatus {
+    Accepted,
+    Blocked,
+    Expired,
+    Invalid,
+}
+
+impl From<LocalAuthorizationStatus> for AuthorizationStatus {
+    fn from(value: LocalAuthorizationStatus) -> Self {
+        match value {
+            LocalAuthorizationStatus::Accepted => Self::Accepted,
+            LocalAuthorizationStatus::Blocked => Self::Blocked,
+            LocalAuthorizationStatus::Expired => Self::Expired,
+            LocalAuthorizationStatus::Invalid => Self::Invalid,
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use rust_ocpp::v1_6::types::UpdateType;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum LocalListUpdateType {
+    /// Replaces the whole list on the charger
+    Full,
+    /// Adds, updates or removes the given entries
+    Differential,
+}
+
+impl From<LocalListUpdateType> for UpdateType {
+    fn from(value: LocalListUpdateType) -> Self {
+        match value {
+            LocalListUpdateType::Full => Self::Full,
+            LocalListUpdateType::Differential => Self::Differential,
+        }
+    }
+}
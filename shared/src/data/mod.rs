@@ -1,25 +1,68 @@
 pub mod charger_connection_info;
 mod charger_data;
 mod charger_settings;
+pub mod charging_profile;
+pub mod charging_profile_kind;
+pub mod charging_profile_purpose;
+pub mod charging_rate_unit;
+pub mod charging_schedule;
+pub mod charging_schedule_period;
+mod client_certificate;
+mod configuration_drift;
+mod configuration_snapshot;
 pub mod connector_data;
 pub mod connector_status;
 pub mod connector_type;
+pub mod desired_charging_profile;
 pub mod evse_data;
+pub mod firmware_download;
+pub mod firmware_download_token;
+pub mod firmware_image;
+pub mod firmware_update;
+pub mod firmware_update_status;
+pub mod local_auth_list_entry;
+pub mod local_authorization_status;
+pub mod local_list_update_type;
 pub mod metric;
 pub mod ocpp1_6_configuration;
 pub mod phase_metric;
+pub mod recurrency_kind;
 pub mod rfid_scan_session;
+pub mod site;
 pub mod transaction;
+pub mod transaction_meter_value;
 
 pub use self::charger_connection_info::ChargerConnectionInfo;
 pub use self::charger_data::ChargerData;
+pub use self::charger_settings::ChargerSettings;
+pub use self::charging_profile::ChargingProfile;
+pub use self::charging_profile_kind::ChargingProfileKind;
+pub use self::charging_profile_purpose::ChargingProfilePurpose;
+pub use self::charging_rate_unit::ChargingRateUnit;
+pub use self::charging_schedule::ChargingSchedule;
+pub use self::charging_schedule_period::ChargingSchedulePeriod;
+pub use self::client_certificate::ClientCertificate;
+pub use self::configuration_drift::{ConfigurationDrift, ConfigurationDriftReason};
+pub use self::configuration_snapshot::ConfigurationSnapshot;
 pub use self::connector_data::ConnectorData;
 pub use self::connector_status::ConnectorStatus;
 pub use self::connector_type::ConnectorType;
+pub use self::desired_charging_profile::DesiredChargingProfile;
 pub use self::evse_data::EvseData;
+pub use self::firmware_download::FirmwareDownload;
+pub use self::firmware_download_token::FirmwareDownloadToken;
+pub use self::firmware_image::FirmwareImage;
+pub use self::firmware_update::{FirmwareUpdate, FirmwareUpdateStatusChange};
+pub use self::firmware_update_status::FirmwareUpdateStatus;
+pub use self::local_auth_list_entry::LocalAuthListEntry;
+pub use self::local_authorization_status::LocalAuthorizationStatus;
+pub use self::local_list_update_type::LocalListUpdateType;
 pub use self::metric::Metric;
 pub use self::ocpp1_6_configuration::Ocpp1_6Configuration;
 pub use self::ocpp1_6_configuration::Ocpp1_6ConfigurationValue;
 pub use self::phase_metric::PhaseMetric;
+pub use self::recurrency_kind::RecurrencyKind;
 pub use self::rfid_scan_session::RfidScanSession;
+pub use self::site::Site;
 pub use self::transaction::Transaction;
+pub use self::transaction_meter_value::{TransactionMeterValue, downsample_meter_values};
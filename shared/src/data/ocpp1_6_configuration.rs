@@ -97,6 +97,15 @@ impl Ocpp1_6Configuration {
         self.configurations.get(name)
     }
 
+    /// Records a value the charger accepted, keys the charger didn't report before are added as
+    /// writable
+    pub fn set_value(&mut self, name: &str, value: &str) {
+        self.configurations
+            .entry(name.to_string())
+            .or_default()
+            .value = Some(value.to_string());
+    }
+
     pub fn iter(&self) -> Iter<'_, String, Ocpp1_6ConfigurationValue> {
         self.configurations.iter()
     }
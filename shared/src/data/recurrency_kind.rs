@@ -0,0 +1,26 @@
+use rust_ocpp::v1_6::types::RecurrencyKindType;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum RecurrencyKind {
+    Daily,
+    Weekly,
+}
+
+impl From<RecurrencyKindType> for RecurrencyKind {
+    fn from(value: RecurrencyKindType) -> Self {
+        match value {
+            RecurrencyKindType::Daily => Self::Daily,
+            RecurrencyKindType::Weekly => Self::Weekly,
+        }
+    }
+}
+
+impl From<RecurrencyKind> for RecurrencyKindType {
+    fn from(value: RecurrencyKind) -> Self {
+        match value {
+            RecurrencyKind::Daily => Self::Daily,
+            RecurrencyKind::Weekly => Self::Weekly,
+        }
+    }
+}
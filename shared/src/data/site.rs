@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// The default minimum current in amperes, most cars won't charge below 6A
+pub const DEFAULT_MIN_CURRENT_PER_EVSE: f32 = 6.0;
+
+/// A group of chargers sharing the same grid connection
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Site {
+    pub id: Uuid,
+    pub name: String,
+    /// The maximum current in amperes the grid connection can deliver on each phase
+    pub max_current_per_phase: f32,
+    /// The minimum current in amperes an evse with an ongoing transaction is given,
+    /// transactions that can't get this are paused until capacity frees up
+    pub min_current_per_evse: f32,
+    /// The maximum difference in amperes between the most and least loaded phase, some utilities
+    /// penalize asymmetric load
+    pub max_phase_imbalance: Option<f32>,
+    pub charger_ids: Vec<String>,
+}
+
+impl Site {
+    pub fn new(name: &str, max_current_per_phase: f32) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            max_current_per_phase,
+            min_current_per_evse: DEFAULT_MIN_CURRENT_PER_EVSE,
+            max_phase_imbalance: None,
+            charger_ids: Vec::new(),
+        }
+    }
+}
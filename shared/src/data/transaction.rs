@@ -1,3 +1,4 @@
+use crate::data::Metric;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -6,12 +7,21 @@ use uuid::Uuid;
 pub struct Transaction {
     pub id: Uuid,
     pub charger_id: String,
-    pub evse_id: Uuid,
+    /// Unknown for a transaction adopted from the stop of a charger with several evses, the stop
+    /// doesn't say which one was used
+    pub evse_id: Option<Uuid>,
     pub ocpp_transaction_id: String,
     pub start_time: DateTime<Utc>,
     pub end_time: Option<DateTime<Utc>>,
-    // Used for OCPP 1.6
+    /// Used for OCPP 1.6
     pub energy_meter_at_start: Option<i32>,
+    /// The most recent energy register reading, in Wh
+    pub last_energy_meter_reading: Option<Metric<i32>>,
+    /// The energy delivered so far, in Wh
     pub watt_charged: i32,
     pub is_authorized: bool,
+    /// The id tag the charger started the transaction with
+    pub id_tag: Option<String>,
+    /// Set when the transaction was started on a reservation
+    pub ocpp_reservation_id: Option<i32>,
 }
@@ -0,0 +1,159 @@
+use chrono::{DateTime, DurationRound, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::time::Duration;
+use uuid::Uuid;
+
+/// A single sampled value a charger reported during a transaction. Measurand, phase, unit,
+/// context and location are kept as the names used by OCPP, e.g. "Energy.Active.Import.Register"
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TransactionMeterValue {
+    pub id: Uuid,
+    pub transaction_id: Uuid,
+    pub charger_id: String,
+    pub timestamp: DateTime<Utc>,
+    pub measurand: String,
+    pub phase: Option<String>,
+    pub unit: Option<String>,
+    pub context: Option<String>,
+    pub location: Option<String>,
+    pub value: f64,
+}
+
+impl TransactionMeterValue {
+    /// Registers count up over the transaction, everything else is an instantaneous reading
+    pub fn is_register(&self) -> bool {
+        self.measurand.ends_with(".Register")
+    }
+}
+
+/// Reduces the meter values to one value per interval for every measurand, phase, unit and
+/// location. Registers keep the last reading of the interval, other measurands are averaged.
+/// The values are expected to be ordered by timestamp.
+pub fn downsample_meter_values(
+    meter_values: Vec<TransactionMeterValue>,
+    interval: Duration,
+) -> Vec<TransactionMeterValue> {
+    let Ok(interval) = TimeDelta::from_std(interval) else {
+        return meter_values;
+    };
+    type SeriesKey = (
+        DateTime<Utc>,
+        String,
+        Option<String>,
+        Option<String>,
+        Option<String>,
+    );
+
+    let mut buckets: BTreeMap<SeriesKey, (TransactionMeterValue, usize)> = BTreeMap::new();
+    for meter_value in meter_values {
+        let bucket_start = meter_value
+            .timestamp
+            .duration_trunc(interval)
+            .unwrap_or(meter_value.timestamp);
+        let key = (
+            bucket_start,
+            meter_value.measurand.clone(),
+            meter_value.phase.clone(),
+            meter_value.unit.clone(),
+            meter_value.location.clone(),
+        );
+
+        match buckets.get_mut(&key) {
+            Some((bucket, count)) if !meter_value.is_register() => {
+                bucket.value += meter_value.value;
+                *count += 1;
+            }
+            _ => {
+                let mut bucket = meter_value;
+                bucket.timestamp = bucket_start;
+                buckets.insert(key, (bucket, 1));
+            }
+        }
+    }
+
+    buckets
+        .into_values()
+        .map(|(mut meter_value, count)| {
+            meter_value.value /= count as f64;
+            meter_value
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn meter_value(measurand: &str, seconds: i64, value: f64) -> TransactionMeterValue {
+        TransactionMeterValue {
+            id: Uuid::new_v4(),
+            transaction_id: Uuid::nil(),
+            charger_id: "charger".to_string(),
+            timestamp: Utc.timestamp_opt(seconds, 0).unwrap(),
+            measurand: measurand.to_string(),
+            phase: None,
+            unit: None,
+            context: None,
+            location: None,
+            value,
+        }
+    }
+
+    #[test]
+    fn test_empty_input() {
+        let downsampled = downsample_meter_values(vec![], Duration::from_secs(60));
+
+        assert!(downsampled.is_empty());
+    }
+
+    #[test]
+    fn test_single_sample_is_moved_to_its_bucket() {
+        let downsampled = downsample_meter_values(
+            vec![meter_value("Power.Active.Import", 90, 7.0)],
+            Duration::from_secs(60),
+        );
+
+        assert_eq!(downsampled.len(), 1);
+        assert_eq!(downsampled[0].timestamp, Utc.timestamp_opt(60, 0).unwrap());
+        assert_eq!(downsampled[0].value, 7.0);
+    }
+
+    #[test]
+    fn test_samples_on_a_boundary_start_a_new_bucket() {
+        let downsampled = downsample_meter_values(
+            vec![
+                meter_value("Power.Active.Import", 0, 2.0),
+                meter_value("Power.Active.Import", 59, 4.0),
+                meter_value("Power.Active.Import", 60, 10.0),
+                meter_value("Power.Active.Import", 120, 20.0),
+            ],
+            Duration::from_secs(60),
+        );
+
+        let values: Vec<(i64, f64)> = downsampled
+            .iter()
+            .map(|meter_value| (meter_value.timestamp.timestamp(), meter_value.value))
+            .collect();
+        assert_eq!(values, vec![(0, 3.0), (60, 10.0), (120, 20.0)]);
+    }
+
+    #[test]
+    fn test_registers_keep_the_last_reading() {
+        let downsampled = downsample_meter_values(
+            vec![
+                meter_value("Energy.Active.Import.Register", 0, 100.0),
+                meter_value("Energy.Active.Import.Register", 30, 150.0),
+                meter_value("Energy.Active.Import.Register", 60, 200.0),
+            ],
+            Duration::from_secs(60),
+        );
+
+        let values: Vec<f64> = downsampled
+            .iter()
+            .map(|meter_value| meter_value.value)
+            .collect();
+        assert_eq!(values, vec![150.0, 200.0]);
+    }
+}
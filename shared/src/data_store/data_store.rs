@@ -1,10 +1,15 @@
 use crate::data::rfid_scan_session::RfidScanSession;
-use crate::data::{ChargerConnectionInfo, ChargerData, Transaction};
+use crate::data::{
+    ChargerConnectionInfo, ChargerData, ConfigurationSnapshot, DesiredChargingProfile,
+    FirmwareDownload, FirmwareDownloadToken, FirmwareImage, FirmwareUpdate, Metric, Site,
+    Transaction, TransactionMeterValue,
+};
 use chrono::{DateTime, Utc};
 use std::error::Error;
 use std::fmt::Debug;
 use uuid::Uuid;
 
+#[cfg_attr(feature = "mock", mockall::automock)]
 #[async_trait::async_trait]
 pub trait DataStore: Send + Sync + Debug {
     async fn get_charger_data_by_id(
@@ -58,10 +63,25 @@ pub trait DataStore: Send + Sync + Debug {
         charger_id: &str,
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>>;
 
+    /// Allocates the next ocpp transaction id of the charger from a sequence starting at 1, so ids
+    /// are never handed out twice
+    async fn next_ocpp_transaction_id(
+        &self,
+        charger_id: &str,
+    ) -> Result<i32, Box<dyn Error + Send + Sync + 'static>>;
+
+    /// Moves the sequence of `next_ocpp_transaction_id` past an id the charger assigned itself
+    async fn reserve_ocpp_transaction_id(
+        &self,
+        charger_id: &str,
+        ocpp_transaction_id: i32,
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>>;
+
+    /// Fails when the charger already has a transaction with the same ocpp transaction id
     async fn create_transaction(
         &self,
         charger_id: &str,
-        evse_id: Uuid,
+        evse_id: Option<Uuid>,
         ocpp_transaction_id: &str,
         start_time: chrono::DateTime<chrono::Utc>,
         is_authorized: bool,
@@ -80,11 +100,11 @@ pub trait DataStore: Send + Sync + Debug {
         end_time: chrono::DateTime<chrono::Utc>,
     ) -> Result<Option<Transaction>, Box<dyn Error + Send + Sync + 'static>>;
 
-    async fn update_transaction_watt_charged(
+    async fn update_transaction_delivered_energy(
         &self,
-        charger_id: &str,
-        ocpp_transaction_id: &str,
+        transaction_id: Uuid,
         watt_charged: i32,
+        last_energy_meter_reading: &Metric<i32>,
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>>;
 
     async fn update_transaction_is_authorized(
@@ -99,6 +119,25 @@ pub trait DataStore: Send + Sync + Debug {
         meter_start: i32,
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>>;
 
+    async fn update_transaction_id_tag(
+        &self,
+        transaction_id: Uuid,
+        id_tag: &str,
+        ocpp_reservation_id: Option<i32>,
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>>;
+
+    async fn update_transaction_start_time(
+        &self,
+        transaction_id: Uuid,
+        start_time: DateTime<Utc>,
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>>;
+
+    async fn update_transaction_end_time(
+        &self,
+        transaction_id: Uuid,
+        end_time: DateTime<Utc>,
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>>;
+
     async fn get_transaction(
         &self,
         transaction_id: Uuid,
@@ -106,6 +145,7 @@ pub trait DataStore: Send + Sync + Debug {
 
     async fn get_transaction_by_ocpp_id(
         &self,
+        charger_id: &str,
         transaction_ocpp_id: &str,
     ) -> Result<Option<Transaction>, Box<dyn Error + Send + Sync + 'static>>;
 
@@ -130,4 +170,141 @@ pub trait DataStore: Send + Sync + Debug {
         &self,
         session_id: Uuid,
     ) -> Result<Option<RfidScanSession>, Box<dyn Error + Send + Sync + 'static>>;
+
+    async fn save_desired_charging_profile(
+        &self,
+        profile: &DesiredChargingProfile,
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>>;
+
+    async fn get_desired_charging_profiles(
+        &self,
+        charger_id: &str,
+    ) -> Result<Vec<DesiredChargingProfile>, Box<dyn Error + Send + Sync + 'static>>;
+
+    async fn delete_desired_charging_profile(
+        &self,
+        charger_id: &str,
+        evse_id: Option<Uuid>,
+        charging_profile_id: i32,
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>>;
+
+    async fn save_site(&self, site: &Site) -> Result<(), Box<dyn Error + Send + Sync + 'static>>;
+
+    async fn get_site(
+        &self,
+        site_id: Uuid,
+    ) -> Result<Option<Site>, Box<dyn Error + Send + Sync + 'static>>;
+
+    async fn get_sites(&self) -> Result<Vec<Site>, Box<dyn Error + Send + Sync + 'static>>;
+
+    async fn get_site_by_charger_id(
+        &self,
+        charger_id: &str,
+    ) -> Result<Option<Site>, Box<dyn Error + Send + Sync + 'static>>;
+
+    async fn delete_site(
+        &self,
+        site_id: Uuid,
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>>;
+
+    async fn save_firmware_update(
+        &self,
+        firmware_update: &FirmwareUpdate,
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>>;
+
+    /// Returns the updates of a charger, the most recent first
+    async fn get_firmware_updates(
+        &self,
+        charger_id: &str,
+    ) -> Result<Vec<FirmwareUpdate>, Box<dyn Error + Send + Sync + 'static>>;
+
+    /// Saves the metadata of an image, its data has to be saved with `save_firmware_image_data`
+    /// first
+    async fn save_firmware_image(
+        &self,
+        image: &FirmwareImage,
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>>;
+
+    /// Writes `data` to the image starting at `offset`, images are written in chunks while they
+    /// are uploaded as they can be large
+    async fn save_firmware_image_data(
+        &self,
+        image_id: Uuid,
+        offset: u64,
+        data: &[u8],
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>>;
+
+    /// Removes the data of an image whose upload was aborted
+    async fn delete_firmware_image_data(
+        &self,
+        image_id: Uuid,
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>>;
+
+    async fn get_firmware_image(
+        &self,
+        image_id: Uuid,
+    ) -> Result<Option<FirmwareImage>, Box<dyn Error + Send + Sync + 'static>>;
+
+    async fn get_firmware_images(
+        &self,
+    ) -> Result<Vec<FirmwareImage>, Box<dyn Error + Send + Sync + 'static>>;
+
+    /// Reads `length` bytes of the image starting at `offset`, images are read in chunks as they
+    /// can be large
+    async fn get_firmware_image_data(
+        &self,
+        image_id: Uuid,
+        offset: u64,
+        length: u64,
+    ) -> Result<Option<Vec<u8>>, Box<dyn Error + Send + Sync + 'static>>;
+
+    async fn save_firmware_download_token(
+        &self,
+        token: &FirmwareDownloadToken,
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>>;
+
+    async fn get_firmware_download_token(
+        &self,
+        token: &str,
+    ) -> Result<Option<FirmwareDownloadToken>, Box<dyn Error + Send + Sync + 'static>>;
+
+    async fn save_firmware_download(
+        &self,
+        download: &FirmwareDownload,
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>>;
+
+    /// Returns the downloads of an image, the most recent first
+    async fn get_firmware_downloads(
+        &self,
+        image_id: Uuid,
+    ) -> Result<Vec<FirmwareDownload>, Box<dyn Error + Send + Sync + 'static>>;
+
+    async fn save_transaction_meter_values(
+        &self,
+        meter_values: &[TransactionMeterValue],
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>>;
+
+    /// Returns the meter values of a transaction, ordered by timestamp
+    async fn get_transaction_meter_values(
+        &self,
+        transaction_id: Uuid,
+    ) -> Result<Vec<TransactionMeterValue>, Box<dyn Error + Send + Sync + 'static>>;
+
+    async fn save_configuration_snapshot(
+        &self,
+        snapshot: &ConfigurationSnapshot,
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>>;
+
+    /// Returns the snapshot with the highest version of a charger
+    async fn get_latest_configuration_snapshot(
+        &self,
+        charger_id: &str,
+    ) -> Result<Option<ConfigurationSnapshot>, Box<dyn Error + Send + Sync + 'static>>;
+
+    /// Returns the latest snapshot of every charger that is out of line with its desired
+    /// configuration, only considering the given charger when one is passed
+    async fn get_configuration_drift(
+        &self,
+        charger_id: Option<String>,
+    ) -> Result<Vec<ConfigurationSnapshot>, Box<dyn Error + Send + Sync + 'static>>;
 }
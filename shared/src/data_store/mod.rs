@@ -3,3 +3,6 @@ mod mongo_data_store;
 
 pub use self::data_store::DataStore;
 pub use self::mongo_data_store::MongoDataStore;
+
+#[cfg(feature = "mock")]
+pub use self::data_store::MockDataStore;
@@ -1,13 +1,22 @@
-use crate::data::{ChargerConnectionInfo, ChargerData, RfidScanSession, Transaction};
+use crate::data::{
+    ChargerConnectionInfo, ChargerData, ConfigurationSnapshot, DesiredChargingProfile,
+    FirmwareDownload, FirmwareDownloadToken, FirmwareImage, FirmwareUpdate, Metric,
+    RfidScanSession, Site, Transaction, TransactionMeterValue,
+};
 use crate::data_store::DataStore;
 use chrono::{DateTime, Utc};
-use mongodb::Client;
-use mongodb::options::DriverInfo;
+use mongodb::bson::{Document, doc};
+use mongodb::options::{DriverInfo, IndexOptions, ReturnDocument};
+use mongodb::{Client, Collection, IndexModel};
 use std::error::Error;
 use std::fmt::Debug;
 use tracing::info;
 use uuid::Uuid;
 
+const DATABASE: &str = "ocpp-csms-server";
+/// The field of a charger's sequence document holding the last ocpp transaction id handed out
+const LAST_OCPP_TRANSACTION_ID: &str = "last_ocpp_transaction_id";
+
 #[derive(Debug, Clone)]
 pub struct MongoDataStore {
     client: Client,
@@ -23,12 +32,31 @@ impl MongoDataStore {
             .version(Some(env!("CARGO_PKG_VERSION").to_string()))
             .build();
         client.append_metadata(driver_info)?;
-        let database = client.database("ocpp-csms-server");
+        let database = client.database(DATABASE);
 
         info!("connected to MongoDB database \"{}\"", database.name());
 
+        // Ocpp transaction ids are only unique per charger, overlapping ids would merge the data
+        // of separate transactions
+        database
+            .collection::<Transaction>("transactions")
+            .create_index(
+                IndexModel::builder()
+                    .keys(doc! { "charger_id": 1, "ocpp_transaction_id": 1 })
+                    .options(IndexOptions::builder().unique(true).build())
+                    .build(),
+            )
+            .await?;
+
         Ok(Self { client })
     }
+
+    /// One document per charger, keyed by the charger id
+    fn ocpp_transaction_id_sequences(&self) -> Collection<Document> {
+        self.client
+            .database(DATABASE)
+            .collection("ocpp_transaction_id_sequences")
+    }
 }
 
 #[async_trait::async_trait]
@@ -104,10 +132,42 @@ impl DataStore for MongoDataStore {
         todo!()
     }
 
+    async fn next_ocpp_transaction_id(
+        &self,
+        charger_id: &str,
+    ) -> Result<i32, Box<dyn Error + Send + Sync + 'static>> {
+        let sequence = self
+            .ocpp_transaction_id_sequences()
+            .find_one_and_update(
+                doc! { "_id": charger_id },
+                doc! { "$inc": { LAST_OCPP_TRANSACTION_ID: 1 } },
+            )
+            .upsert(true)
+            .return_document(ReturnDocument::After)
+            .await?
+            .ok_or("The ocpp transaction id sequence was not created")?;
+        Ok(sequence.get_i32(LAST_OCPP_TRANSACTION_ID)?)
+    }
+
+    async fn reserve_ocpp_transaction_id(
+        &self,
+        charger_id: &str,
+        ocpp_transaction_id: i32,
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.ocpp_transaction_id_sequences()
+            .update_one(
+                doc! { "_id": charger_id },
+                doc! { "$max": { LAST_OCPP_TRANSACTION_ID: ocpp_transaction_id } },
+            )
+            .upsert(true)
+            .await?;
+        Ok(())
+    }
+
     async fn create_transaction(
         &self,
         charger_id: &str,
-        evse_id: Uuid,
+        evse_id: Option<Uuid>,
         ocpp_transaction_id: &str,
         start_time: DateTime<Utc>,
         is_authorized: bool,
@@ -132,11 +192,11 @@ impl DataStore for MongoDataStore {
         todo!()
     }
 
-    async fn update_transaction_watt_charged(
+    async fn update_transaction_delivered_energy(
         &self,
-        charger_id: &str,
-        ocpp_transaction_id: &str,
+        transaction_id: Uuid,
         watt_charged: i32,
+        last_energy_meter_reading: &Metric<i32>,
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         todo!()
     }
@@ -157,6 +217,31 @@ impl DataStore for MongoDataStore {
         todo!()
     }
 
+    async fn update_transaction_id_tag(
+        &self,
+        transaction_id: Uuid,
+        id_tag: &str,
+        ocpp_reservation_id: Option<i32>,
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        todo!()
+    }
+
+    async fn update_transaction_start_time(
+        &self,
+        transaction_id: Uuid,
+        start_time: DateTime<Utc>,
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        todo!()
+    }
+
+    async fn update_transaction_end_time(
+        &self,
+        transaction_id: Uuid,
+        end_time: DateTime<Utc>,
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        todo!()
+    }
+
     async fn get_transaction(
         &self,
         transaction_id: Uuid,
@@ -166,6 +251,7 @@ impl DataStore for MongoDataStore {
 
     async fn get_transaction_by_ocpp_id(
         &self,
+        charger_id: &str,
         transaction_ocpp_id: &str,
     ) -> Result<Option<Transaction>, Box<dyn Error + Send + Sync + 'static>> {
         todo!()
@@ -200,4 +286,178 @@ impl DataStore for MongoDataStore {
     ) -> Result<Option<RfidScanSession>, Box<dyn Error + Send + Sync + 'static>> {
         todo!()
     }
+
+    async fn save_desired_charging_profile(
+        &self,
+        profile: &DesiredChargingProfile,
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        todo!()
+    }
+
+    async fn get_desired_charging_profiles(
+        &self,
+        charger_id: &str,
+    ) -> Result<Vec<DesiredChargingProfile>, Box<dyn Error + Send + Sync + 'static>> {
+        todo!()
+    }
+
+    async fn delete_desired_charging_profile(
+        &self,
+        charger_id: &str,
+        evse_id: Option<Uuid>,
+        charging_profile_id: i32,
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        todo!()
+    }
+
+    async fn save_site(&self, site: &Site) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        todo!()
+    }
+
+    async fn get_site(
+        &self,
+        site_id: Uuid,
+    ) -> Result<Option<Site>, Box<dyn Error + Send + Sync + 'static>> {
+        todo!()
+    }
+
+    async fn get_sites(&self) -> Result<Vec<Site>, Box<dyn Error + Send + Sync + 'static>> {
+        todo!()
+    }
+
+    async fn get_site_by_charger_id(
+        &self,
+        charger_id: &str,
+    ) -> Result<Option<Site>, Box<dyn Error + Send + Sync + 'static>> {
+        todo!()
+    }
+
+    async fn delete_site(
+        &self,
+        site_id: Uuid,
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        todo!()
+    }
+
+    async fn save_firmware_update(
+        &self,
+        firmware_update: &FirmwareUpdate,
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        todo!()
+    }
+
+    async fn get_firmware_updates(
+        &self,
+        charger_id: &str,
+    ) -> Result<Vec<FirmwareUpdate>, Box<dyn Error + Send + Sync + 'static>> {
+        todo!()
+    }
+
+    async fn save_firmware_image(
+        &self,
+        image: &FirmwareImage,
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        todo!()
+    }
+
+    async fn save_firmware_image_data(
+        &self,
+        image_id: Uuid,
+        offset: u64,
+        data: &[u8],
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        todo!()
+    }
+
+    async fn delete_firmware_image_data(
+        &self,
+        image_id: Uuid,
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        todo!()
+    }
+
+    async fn get_firmware_image(
+        &self,
+        image_id: Uuid,
+    ) -> Result<Option<FirmwareImage>, Box<dyn Error + Send + Sync + 'static>> {
+        todo!()
+    }
+
+    async fn get_firmware_images(
+        &self,
+    ) -> Result<Vec<FirmwareImage>, Box<dyn Error + Send + Sync + 'static>> {
+        todo!()
+    }
+
+    async fn get_firmware_image_data(
+        &self,
+        image_id: Uuid,
+        offset: u64,
+        length: u64,
+    ) -> Result<Option<Vec<u8>>, Box<dyn Error + Send + Sync + 'static>> {
+        todo!()
+    }
+
+    async fn save_firmware_download_token(
+        &self,
+        token: &FirmwareDownloadToken,
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        todo!()
+    }
+
+    async fn get_firmware_download_token(
+        &self,
+        token: &str,
+    ) -> Result<Option<FirmwareDownloadToken>, Box<dyn Error + Send + Sync + 'static>> {
+        todo!()
+    }
+
+    async fn save_firmware_download(
+        &self,
+        download: &FirmwareDownload,
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        todo!()
+    }
+
+    async fn get_firmware_downloads(
+        &self,
+        image_id: Uuid,
+    ) -> Result<Vec<FirmwareDownload>, Box<dyn Error + Send + Sync + 'static>> {
+        todo!()
+    }
+
+    async fn save_transaction_meter_values(
+        &self,
+        meter_values: &[TransactionMeterValue],
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        todo!()
+    }
+
+    async fn get_transaction_meter_values(
+        &self,
+        transaction_id: Uuid,
+    ) -> Result<Vec<TransactionMeterValue>, Box<dyn Error + Send + Sync + 'static>> {
+        todo!()
+    }
+
+    async fn save_configuration_snapshot(
+        &self,
+        snapshot: &ConfigurationSnapshot,
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        todo!()
+    }
+
+    async fn get_latest_configuration_snapshot(
+        &self,
+        charger_id: &str,
+    ) -> Result<Option<ConfigurationSnapshot>, Box<dyn Error + Send + Sync + 'static>> {
+        todo!()
+    }
+
+    async fn get_configuration_drift(
+        &self,
+        charger_id: Option<String>,
+    ) -> Result<Vec<ConfigurationSnapshot>, Box<dyn Error + Send + Sync + 'static>> {
+        todo!()
+    }
 }